@@ -1,4 +1,4 @@
-use syn::{DeriveInput, Field, LitStr, Path, Result};
+use syn::{DeriveInput, Field, LitStr, Path, Result, Type, Variant, spanned::Spanned};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum RenameAll {
@@ -6,6 +6,14 @@ pub(crate) enum RenameAll {
     None,
 }
 
+/// Column name resolution requested with `lookup = "..."`, mirroring `ColumnLookup`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Lookup {
+    Exact,
+    CaseInsensitive,
+    Identifier,
+}
+
 pub(crate) struct ContainerAttrs {
     /// Effective field-name conversion.
     pub(crate) rename_all: RenameAll,
@@ -13,6 +21,10 @@ pub(crate) struct ContainerAttrs {
     /// Used to reject `positional` + explicit `rename_all`.
     pub(crate) rename_all_explicit: bool,
     pub(crate) positional: bool,
+    /// Match enum variant names ignoring case. Only used by `FromCell`.
+    pub(crate) case_insensitive: bool,
+    /// Column lookup mode applied to the row plan context. Only used by `FromRow`.
+    pub(crate) lookup: Option<Lookup>,
     pub(crate) crate_path: Path,
     pub(crate) crate_path_explicit: bool,
 }
@@ -23,6 +35,8 @@ impl Default for ContainerAttrs {
             rename_all: RenameAll::ScreamingSnakeCase,
             rename_all_explicit: false,
             positional: false,
+            case_insensitive: false,
+            lookup: None,
             crate_path: syn::parse_str("::snowflake_connector_rs").expect("default path"),
             crate_path_explicit: false,
         }
@@ -32,6 +46,15 @@ impl Default for ContainerAttrs {
 #[derive(Default)]
 pub(crate) struct FieldAttrs {
     pub(crate) rename: Option<String>,
+    pub(crate) skip: bool,
+    /// Fall back to `Default::default()` for a missing column or SQL `NULL`.
+    pub(crate) default: bool,
+    /// Decode the field as a nested `FromRow` sharing the row plan.
+    pub(crate) flatten: bool,
+    /// Module providing `Plan`, `build_plan`, and `from_cell_with_plan` for the field.
+    pub(crate) with: Option<Path>,
+    /// Intermediate `FromCell` type the field is converted from with `TryFrom`.
+    pub(crate) try_from: Option<Type>,
 }
 
 pub(crate) fn parse_container_attrs(input: &DeriveInput) -> Result<ContainerAttrs> {
@@ -68,6 +91,28 @@ pub(crate) fn parse_container_attrs(input: &DeriveInput) -> Result<ContainerAttr
 
                 positional_seen = true;
                 out.positional = true;
+            } else if meta.path.is_ident("case_insensitive") {
+                if out.case_insensitive {
+                    return Err(meta.error("duplicate `case_insensitive`"));
+                }
+
+                out.case_insensitive = true;
+            } else if meta.path.is_ident("lookup") {
+                if out.lookup.is_some() {
+                    return Err(meta.error("duplicate `lookup`"));
+                }
+
+                let value: LitStr = meta.value()?.parse()?;
+                out.lookup = Some(match value.value().as_str() {
+                    "exact" => Lookup::Exact,
+                    "case_insensitive" => Lookup::CaseInsensitive,
+                    "identifier" => Lookup::Identifier,
+                    _ => {
+                        return Err(meta.error(
+                            "only `lookup = \"exact\"`, `\"case_insensitive\"`, or `\"identifier\"` is supported",
+                        ));
+                    }
+                });
             } else if meta.path.is_ident("crate") {
                 if out.crate_path_explicit {
                     return Err(meta.error("duplicate `crate`"));
@@ -96,6 +141,44 @@ pub(crate) fn parse_container_attrs(input: &DeriveInput) -> Result<ContainerAttr
         ));
     }
 
+    if out.positional && out.lookup.is_some() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`positional` cannot be combined with `lookup`",
+        ));
+    }
+
+    Ok(out)
+}
+
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    pub(crate) rename: Option<String>,
+}
+
+pub(crate) fn parse_variant_attrs(variant: &Variant) -> Result<VariantAttrs> {
+    let mut out = VariantAttrs::default();
+
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("snowflake") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                if out.rename.is_some() {
+                    return Err(meta.error("duplicate `rename`"));
+                }
+
+                let value: LitStr = meta.value()?.parse()?;
+                out.rename = Some(value.value());
+            } else {
+                return Err(meta.error("unknown variant attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
     Ok(out)
 }
 
@@ -115,6 +198,40 @@ pub(crate) fn parse_field_attrs(field: &Field) -> Result<FieldAttrs> {
 
                 let value: LitStr = meta.value()?.parse()?;
                 out.rename = Some(value.value());
+            } else if meta.path.is_ident("skip") {
+                if out.skip {
+                    return Err(meta.error("duplicate `skip`"));
+                }
+
+                out.skip = true;
+            } else if meta.path.is_ident("default") {
+                if out.default {
+                    return Err(meta.error("duplicate `default`"));
+                }
+
+                out.default = true;
+            } else if meta.path.is_ident("flatten") {
+                if out.flatten {
+                    return Err(meta.error("duplicate `flatten`"));
+                }
+
+                out.flatten = true;
+            } else if meta.path.is_ident("with") {
+                if out.with.is_some() {
+                    return Err(meta.error("duplicate `with`"));
+                }
+
+                let value: LitStr = meta.value()?.parse()?;
+                let path: Path = value.parse().map_err(|e| meta.error(e.to_string()))?;
+                out.with = Some(path);
+            } else if meta.path.is_ident("try_from") {
+                if out.try_from.is_some() {
+                    return Err(meta.error("duplicate `try_from`"));
+                }
+
+                let value: LitStr = meta.value()?.parse()?;
+                let ty: Type = value.parse().map_err(|e| meta.error(e.to_string()))?;
+                out.try_from = Some(ty);
             } else if meta.path.is_ident("positional") {
                 return Err(meta.error(
                     "field-level `positional` is not supported; use container-level `#[snowflake(positional)]` instead",
@@ -126,5 +243,49 @@ pub(crate) fn parse_field_attrs(field: &Field) -> Result<FieldAttrs> {
         })?;
     }
 
+    validate_field_attrs(field, &out)?;
+
     Ok(out)
 }
+
+fn validate_field_attrs(field: &Field, attrs: &FieldAttrs) -> Result<()> {
+    let conflict = |a: &str, b: &str| {
+        Err(syn::Error::new(
+            field.span(),
+            format!("`{a}` cannot be combined with `{b}`"),
+        ))
+    };
+
+    if attrs.skip {
+        for (set, name) in [
+            (attrs.rename.is_some(), "rename"),
+            (attrs.default, "default"),
+            (attrs.flatten, "flatten"),
+            (attrs.with.is_some(), "with"),
+            (attrs.try_from.is_some(), "try_from"),
+        ] {
+            if set {
+                return conflict("skip", name);
+            }
+        }
+    }
+
+    if attrs.flatten {
+        for (set, name) in [
+            (attrs.rename.is_some(), "rename"),
+            (attrs.default, "default"),
+            (attrs.with.is_some(), "with"),
+            (attrs.try_from.is_some(), "try_from"),
+        ] {
+            if set {
+                return conflict("flatten", name);
+            }
+        }
+    }
+
+    if attrs.with.is_some() && attrs.try_from.is_some() {
+        return conflict("with", "try_from");
+    }
+
+    Ok(())
+}
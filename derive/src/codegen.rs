@@ -1,81 +1,287 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::input::{FieldInfo, FieldLookup, FromRowDerive, StructShape};
+use crate::{
+    attrs::Lookup,
+    input::{CellAdapter, FieldDecode, FieldLookup, FromRowDerive, StructShape},
+};
 
 pub(crate) fn expand(model: &FromRowDerive) -> TokenStream2 {
     let crate_path = &model.crate_path;
     let struct_ident = &model.struct_ident;
 
-    let plan_field_tys = model.fields.iter().map(|field| {
+    let mut adapters = Vec::new();
+    let mut plan_field_tys = Vec::new();
+    let mut plan_field_inits = Vec::new();
+    let mut values = Vec::new();
+    let mut bounds = Vec::new();
+
+    // A struct with a lifetime parameter borrows from the row, so it decodes through the `*Ref` traits and its
+    // fields may hold `&'a str` and friends.
+    let row_lifetime = model.row_lifetime.as_ref();
+    let (row_trait, cell_trait, row_method, get_method) = match row_lifetime {
+        Some(lifetime) => (
+            quote! { #crate_path::FromRowRef<#lifetime> },
+            quote! { #crate_path::FromCellRef<#lifetime> },
+            quote! { from_row_ref_with_plan },
+            quote! { get_ref_with_plan },
+        ),
+        None => (
+            quote! { #crate_path::FromRow },
+            quote! { #crate_path::FromCell },
+            quote! { from_row_with_plan },
+            quote! { get_with_plan },
+        ),
+    };
+    let cell_plan = |ty: &TokenStream2| match row_lifetime {
+        Some(lifetime) => quote! { #crate_path::CellRefPlan<#lifetime, #ty> },
+        None => quote! { #crate_path::CellPlan<#ty> },
+    };
+
+    for (index, field) in model.fields.iter().enumerate() {
+        let plan_index = syn::Index::from(plan_field_tys.len());
         let ty = &field.ty;
-        quote! { #crate_path::CellPlan<#ty> }
+        match &field.decode {
+            FieldDecode::Skip => {
+                if field.generic {
+                    bounds.push(quote! { #ty: ::core::default::Default });
+                }
+                values.push(quote! { ::core::default::Default::default() });
+            }
+            FieldDecode::Flatten => {
+                if field.generic {
+                    bounds.push(quote! { #ty: #row_trait });
+                }
+                plan_field_tys.push(quote! { <#ty as #row_trait>::Plan });
+                plan_field_inits.push(quote! { <#ty as #row_trait>::build_plan(ctx)? });
+                values.push(quote! {
+                    <#ty as #row_trait>::#row_method(row, &plan.#plan_index)?
+                });
+            }
+            FieldDecode::Cell {
+                lookup,
+                adapter,
+                default,
+            } => {
+                let cell_ty = match adapter {
+                    Some(adapter) => {
+                        let adapter_ident = format_ident!("__SnowflakeFromRowField{}", index);
+                        adapters.push(adapter_impl(&adapter_ident, ty, adapter, crate_path));
+                        quote! { #adapter_ident }
+                    }
+                    None => quote! { #ty },
+                };
+                let unwrap = adapter.as_ref().map(|_| quote! { .0 });
+
+                if *default {
+                    if field.generic {
+                        bounds.push(quote! { ::core::option::Option<#ty>: #cell_trait });
+                        bounds.push(quote! { #ty: ::core::default::Default });
+                    }
+                    let cell_plan_ty = cell_plan(&quote! { ::core::option::Option<#cell_ty> });
+                    plan_field_tys.push(quote! { ::core::option::Option<#cell_plan_ty> });
+                    plan_field_inits.push(match lookup {
+                        FieldLookup::Name(name) => quote! {
+                            <#cell_plan_ty>::by_name_if_present(ctx, #name)?
+                        },
+                        FieldLookup::Position(pos) => quote! {
+                            <#cell_plan_ty>::by_position_if_present(ctx, #pos)?
+                        },
+                    });
+                    values.push(quote! {
+                        match &plan.#plan_index {
+                            ::core::option::Option::Some(cell_plan) => match row.#get_method(cell_plan)? {
+                                ::core::option::Option::Some(value) => value #unwrap,
+                                ::core::option::Option::None => ::core::default::Default::default(),
+                            },
+                            ::core::option::Option::None => ::core::default::Default::default(),
+                        }
+                    });
+                } else {
+                    if field.generic {
+                        bounds.push(quote! { #ty: #cell_trait });
+                    }
+                    let cell_plan_ty = cell_plan(&cell_ty);
+                    plan_field_tys.push(cell_plan_ty.clone());
+                    plan_field_inits.push(match lookup {
+                        FieldLookup::Name(name) => quote! {
+                            <#cell_plan_ty>::by_name(ctx, #name)?
+                        },
+                        FieldLookup::Position(pos) => quote! {
+                            <#cell_plan_ty>::by_position(ctx, #pos)?
+                        },
+                    });
+                    values.push(quote! { row.#get_method(&plan.#plan_index)? #unwrap });
+                }
+            }
+        }
+    }
+
+    let row_body = row_body(model, &values);
+    let set_lookup = model.lookup.map(|lookup| {
+        let variant = match lookup {
+            Lookup::Exact => quote! { Exact },
+            Lookup::CaseInsensitive => quote! { CaseInsensitive },
+            Lookup::Identifier => quote! { Identifier },
+        };
+        quote! { let ctx = ctx.with_column_lookup(#crate_path::ColumnLookup::#variant); }
     });
-    let plan_field_inits = model
-        .fields
-        .iter()
-        .map(|field| plan_field_init(field, crate_path));
-    let row_body = row_body(model);
 
-    quote! {
-        const _: () = {
-            impl #crate_path::FromRow for #struct_ident {
+    let mut generics = model.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for bound in &bounds {
+            where_clause.predicates.push(syn::parse_quote! { #bound });
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let decode_impl = match row_lifetime {
+        Some(lifetime) => quote! {
+            impl #impl_generics #crate_path::FromRowRef<#lifetime> for #struct_ident #ty_generics #where_clause {
                 type Plan = ( #(#plan_field_tys,)* );
 
                 fn build_plan(
                     ctx: #crate_path::RowPlanContext<'_>,
                 ) -> #crate_path::decode::PlanBuildResult<Self::Plan> {
+                    #set_lookup
                     ::core::result::Result::Ok(( #(#plan_field_inits,)* ))
                 }
 
-                fn from_row_with_plan(
-                    row: #crate_path::RowRef<'_>,
+                fn from_row_ref_with_plan(
+                    row: #crate_path::RowRef<#lifetime>,
                     plan: &Self::Plan,
                 ) -> #crate_path::decode::RowDecodeResult<Self> {
                     #row_body
                 }
             }
+        },
+        None => {
+            // Owned rows can also be produced by `ResultTable::rows_ref` or flattened into a borrowed row.
+            let mut ref_generics = generics.clone();
+            ref_generics
+                .params
+                .insert(0, syn::parse_quote! { '__snowflake_row });
+            let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+
+            quote! {
+                impl #impl_generics #crate_path::FromRow for #struct_ident #ty_generics #where_clause {
+                    type Plan = ( #(#plan_field_tys,)* );
+
+                    fn build_plan(
+                        ctx: #crate_path::RowPlanContext<'_>,
+                    ) -> #crate_path::decode::PlanBuildResult<Self::Plan> {
+                        #set_lookup
+                        ::core::result::Result::Ok(( #(#plan_field_inits,)* ))
+                    }
+
+                    fn from_row_with_plan(
+                        row: #crate_path::RowRef<'_>,
+                        plan: &Self::Plan,
+                    ) -> #crate_path::decode::RowDecodeResult<Self> {
+                        #row_body
+                    }
+                }
+
+                impl #ref_impl_generics #crate_path::FromRowRef<'__snowflake_row> for #struct_ident #ty_generics
+                #where_clause
+                {
+                    type Plan = <Self as #crate_path::FromRow>::Plan;
+
+                    fn build_plan(
+                        ctx: #crate_path::RowPlanContext<'_>,
+                    ) -> #crate_path::decode::PlanBuildResult<Self::Plan> {
+                        <Self as #crate_path::FromRow>::build_plan(ctx)
+                    }
+
+                    fn from_row_ref_with_plan(
+                        row: #crate_path::RowRef<'__snowflake_row>,
+                        plan: &Self::Plan,
+                    ) -> #crate_path::decode::RowDecodeResult<Self> {
+                        <Self as #crate_path::FromRow>::from_row_with_plan(row, plan)
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        const _: () = {
+            #(#adapters)*
+
+            #decode_impl
         };
     }
 }
 
-fn plan_field_init(field: &FieldInfo, crate_path: &syn::Path) -> TokenStream2 {
-    let ty = &field.ty;
-    match &field.lookup {
-        FieldLookup::Name(name) => quote! {
-            #crate_path::CellPlan::<#ty>::by_name(ctx, #name)?
-        },
-        FieldLookup::Position(pos) => {
-            let pos = *pos;
-            quote! {
-                #crate_path::CellPlan::<#ty>::by_position(ctx, #pos)?
+/// A `FromCell` wrapper around the field type that routes decoding through a `with` module or a `try_from` type.
+/// It is `pub` only because it appears in the public `Plan` type; it is unnameable outside the `const _` block.
+fn adapter_impl(
+    adapter_ident: &syn::Ident,
+    field_ty: &syn::Type,
+    adapter: &CellAdapter,
+    crate_path: &syn::Path,
+) -> TokenStream2 {
+    let (plan_ty, build_plan, decode) = match adapter {
+        CellAdapter::With(module) => (
+            quote! { #module::Plan },
+            quote! { #module::build_plan(ctx) },
+            quote! { #module::from_cell_with_plan(raw, plan).map(Self) },
+        ),
+        CellAdapter::TryFrom(raw_ty) => {
+            let raw_name = quote! { #raw_ty }.to_string().replace(' ', "");
+            let reason = format!("conversion from `{raw_name}` failed");
+            (
+                quote! { <#raw_ty as #crate_path::FromCell>::Plan },
+                quote! { <#raw_ty as #crate_path::FromCell>::build_plan(ctx) },
+                quote! {
+                    let value = <#raw_ty as #crate_path::FromCell>::from_cell_with_plan(raw, plan)?;
+                    <#field_ty as ::core::convert::TryFrom<#raw_ty>>::try_from(value)
+                        .map(Self)
+                        .map_err(|error| {
+                            #crate_path::decode::CellConversionError::builder(#reason)
+                                .source(error)
+                                .build()
+                        })
+                },
+            )
+        }
+    };
+
+    quote! {
+        #[doc(hidden)]
+        pub struct #adapter_ident(#field_ty);
+
+        impl #crate_path::FromCell for #adapter_ident {
+            type Plan = #plan_ty;
+
+            fn build_plan(
+                ctx: #crate_path::CellPlanContext<'_>,
+            ) -> #crate_path::decode::PlanBuildResult<Self::Plan> {
+                #build_plan
+            }
+
+            fn from_cell_with_plan(
+                raw: ::core::option::Option<&str>,
+                plan: &Self::Plan,
+            ) -> #crate_path::decode::CellDecodeResult<Self> {
+                #decode
             }
         }
     }
 }
 
-fn row_body(model: &FromRowDerive) -> TokenStream2 {
+fn row_body(model: &FromRowDerive, values: &[TokenStream2]) -> TokenStream2 {
     match model.shape {
         StructShape::Named => {
-            let assigns = model.fields.iter().enumerate().map(|(index, field)| {
+            let assigns = model.fields.iter().zip(values).map(|(field, value)| {
                 let field_ident = field.field_ident.as_ref().expect("named");
-                let value = decode_field_value(index);
                 quote! { #field_ident: #value }
             });
             quote! { ::core::result::Result::Ok(Self { #(#assigns),* }) }
         }
         StructShape::Tuple => {
-            let assigns = model
-                .fields
-                .iter()
-                .enumerate()
-                .map(|(index, _)| decode_field_value(index));
-            quote! { ::core::result::Result::Ok(Self ( #(#assigns),* )) }
+            quote! { ::core::result::Result::Ok(Self ( #(#values),* )) }
         }
     }
 }
-
-fn decode_field_value(index: usize) -> TokenStream2 {
-    let index = syn::Index::from(index);
-    quote! { row.get_with_plan(&plan.#index)? }
-}
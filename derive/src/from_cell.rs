@@ -0,0 +1,196 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, Path, Result, spanned::Spanned};
+
+use crate::{
+    attrs::{parse_container_attrs, parse_variant_attrs},
+    input::validate_generics,
+    naming::{apply_rename_all, logical_ident_name},
+};
+
+pub(crate) struct FromCellDerive {
+    enum_ident: Ident,
+    crate_path: Path,
+    case_insensitive: bool,
+    /// The enum's `repr` is unsigned and 64 bits wide, so discriminants may exceed `i64::MAX`.
+    check_discriminants: bool,
+    variants: Vec<EnumVariant>,
+}
+
+struct EnumVariant {
+    ident: Ident,
+    name: String,
+}
+
+pub(crate) fn analyze(input: DeriveInput) -> Result<FromCellDerive> {
+    validate_generics(&input, "FromCell")?;
+
+    let container = parse_container_attrs(&input)?;
+    if container.lookup.is_some() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`lookup` is only supported by FromRow",
+        ));
+    }
+    if container.positional {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`positional` cannot be applied to an enum",
+        ));
+    }
+
+    let data = match &input.data {
+        Data::Enum(e) => e,
+        Data::Struct(s) => {
+            return Err(syn::Error::new(
+                s.struct_token.span,
+                "FromCell can only be derived for fieldless enums",
+            ));
+        }
+        Data::Union(u) => {
+            return Err(syn::Error::new(
+                u.union_token.span,
+                "FromCell can only be derived for fieldless enums",
+            ));
+        }
+    };
+
+    let check_discriminants = match enum_repr(&input)? {
+        Some(repr) if repr == "i128" || repr == "u128" => {
+            return Err(syn::Error::new(
+                repr.span(),
+                format!(
+                    "FromCell discriminants must fit in i64; `#[repr({repr})]` is not supported"
+                ),
+            ));
+        }
+        Some(repr) => repr == "u64" || repr == "usize",
+        None => false,
+    };
+
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "FromCell does not support empty enums",
+        ));
+    }
+
+    let mut variants: Vec<EnumVariant> = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.fields.span(),
+                "FromCell only supports fieldless enum variants",
+            ));
+        }
+
+        let variant_attrs = parse_variant_attrs(variant)?;
+        let written = variant_attrs.rename.unwrap_or_else(|| {
+            let raw = logical_ident_name(&variant.ident);
+            apply_rename_all(&raw, container.rename_all)
+        });
+        // The plan compares case-insensitive names against this lowercase form, so fold once here.
+        let name = if container.case_insensitive {
+            written.to_ascii_lowercase()
+        } else {
+            written.clone()
+        };
+        if variants.iter().any(|existing| existing.name == name) {
+            return Err(syn::Error::new(
+                variant.ident.span(),
+                format!("variant name `{written}` is already used by another variant"),
+            ));
+        }
+
+        variants.push(EnumVariant {
+            ident: variant.ident.clone(),
+            name,
+        });
+    }
+
+    Ok(FromCellDerive {
+        enum_ident: input.ident,
+        crate_path: container.crate_path,
+        case_insensitive: container.case_insensitive,
+        check_discriminants,
+        variants,
+    })
+}
+
+/// The integer type named by the enum's `#[repr(...)]`, if any.
+fn enum_repr(input: &DeriveInput) -> Result<Option<Ident>> {
+    let mut repr = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if ident.to_string().starts_with(['i', 'u']) {
+                    repr = Some(ident.clone());
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr)
+}
+
+pub(crate) fn expand(model: &FromCellDerive) -> TokenStream2 {
+    let crate_path = &model.crate_path;
+    let enum_ident = &model.enum_ident;
+    let case_insensitive = model.case_insensitive;
+
+    let entries = model.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let name = &variant.name;
+        quote! { (#name, Self::#ident as i64) }
+    });
+    // `as i64` would wrap these silently, so reject out-of-range discriminants at compile time instead.
+    let discriminant_checks = model.check_discriminants.then(|| {
+        let asserts = model.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            let message = format!("the discriminant of `{ident}` does not fit in i64");
+            quote! {
+                ::core::assert!(
+                    #enum_ident::#ident as u64 <= ::core::primitive::i64::MAX as u64,
+                    #message
+                );
+            }
+        });
+        quote! {
+            const _: () = {
+                #(#asserts)*
+            };
+        }
+    });
+    let arms = model.variants.iter().enumerate().map(|(index, variant)| {
+        let ident = &variant.ident;
+        quote! { #index => Self::#ident, }
+    });
+
+    quote! {
+        #discriminant_checks
+        const _: () = {
+            impl #crate_path::FromCell for #enum_ident {
+                type Plan = #crate_path::decode::EnumPlan;
+
+                fn build_plan(
+                    ctx: #crate_path::CellPlanContext<'_>,
+                ) -> #crate_path::decode::PlanBuildResult<Self::Plan> {
+                    #crate_path::decode::EnumPlan::new::<Self>(ctx, &[ #(#entries),* ], #case_insensitive)
+                }
+
+                fn from_cell_with_plan(
+                    raw: ::core::option::Option<&str>,
+                    plan: &Self::Plan,
+                ) -> #crate_path::decode::CellDecodeResult<Self> {
+                    ::core::result::Result::Ok(match plan.variant_index(raw)? {
+                        #(#arms)*
+                        _ => ::core::unreachable!(),
+                    })
+                }
+            }
+        };
+    }
+}
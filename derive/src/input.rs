@@ -1,13 +1,19 @@
-use syn::{Data, DeriveInput, Fields, Ident, Path, Result, spanned::Spanned};
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Fields, Generics, Ident, Lifetime, Path, Result, spanned::Spanned};
 
 use crate::{
-    attrs::{ContainerAttrs, parse_container_attrs, parse_field_attrs},
+    attrs::{ContainerAttrs, FieldAttrs, Lookup, parse_container_attrs, parse_field_attrs},
     naming::{apply_rename_all, logical_ident_name},
 };
 
 pub(crate) struct FromRowDerive {
     pub(crate) struct_ident: Ident,
+    pub(crate) generics: Generics,
+    /// The struct's lifetime parameter, if any. Such a struct borrows from the row and only implements `FromRowRef`.
+    pub(crate) row_lifetime: Option<Lifetime>,
     pub(crate) crate_path: Path,
+    pub(crate) lookup: Option<Lookup>,
     pub(crate) shape: StructShape,
     pub(crate) fields: Vec<FieldInfo>,
 }
@@ -22,22 +28,48 @@ pub(crate) enum FieldLookup {
     Position(usize),
 }
 
+pub(crate) enum CellAdapter {
+    With(Path),
+    TryFrom(Box<syn::Type>),
+}
+
+pub(crate) enum FieldDecode {
+    /// Not read from the row; filled with `Default::default()`.
+    Skip,
+    /// Decoded as a nested `FromRow` against the same row.
+    Flatten,
+    Cell {
+        lookup: FieldLookup,
+        adapter: Option<CellAdapter>,
+        default: bool,
+    },
+}
+
 pub(crate) struct FieldInfo {
     pub(crate) field_ident: Option<Ident>,
     pub(crate) ty: syn::Type,
-    pub(crate) lookup: FieldLookup,
+    pub(crate) decode: FieldDecode,
+    /// Whether the field type mentions one of the struct's type or const parameters, so its decode bound must be
+    /// added to the generated impl.
+    pub(crate) generic: bool,
 }
 
 pub(crate) fn analyze(input: DeriveInput) -> Result<FromRowDerive> {
-    validate_generics(&input)?;
+    let row_lifetime = row_lifetime(&input)?;
 
     let container = parse_container_attrs(&input)?;
+    if container.case_insensitive {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`case_insensitive` is only supported by FromCell; use `lookup = \"case_insensitive\"` on FromRow",
+        ));
+    }
     let data = match &input.data {
         Data::Struct(s) => s,
         Data::Enum(e) => {
             return Err(syn::Error::new(
                 e.enum_token.span,
-                "FromRow does not support enums",
+                "FromRow does not support enums; derive FromCell to decode an enum from a single column",
             ));
         }
         Data::Union(u) => {
@@ -71,19 +103,87 @@ pub(crate) fn analyze(input: DeriveInput) -> Result<FromRowDerive> {
         }
     };
 
+    if fields
+        .iter()
+        .all(|field| matches!(field.decode, FieldDecode::Skip))
+    {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "FromRow requires at least one field that is not skipped",
+        ));
+    }
+
+    let type_params: Vec<&Ident> = input
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .chain(input.generics.const_params().map(|param| &param.ident))
+        .collect();
+    let mut fields = fields;
+    for field in &mut fields {
+        field.generic = mentions_any(field.ty.to_token_stream(), &type_params);
+
+        if let FieldDecode::Cell {
+            adapter: Some(adapter),
+            ..
+        } = &field.decode
+        {
+            let borrows = row_lifetime.as_ref().is_some_and(|lifetime| {
+                mentions_any(field.ty.to_token_stream(), &[&lifetime.ident])
+            });
+            if field.generic || borrows {
+                let name = match adapter {
+                    CellAdapter::With(_) => "with",
+                    CellAdapter::TryFrom(_) => "try_from",
+                };
+                return Err(syn::Error::new(
+                    field.ty.span(),
+                    format!(
+                        "`{name}` cannot be applied to a field whose type uses a generic or lifetime parameter"
+                    ),
+                ));
+            }
+        }
+    }
+
     Ok(FromRowDerive {
         struct_ident: input.ident,
+        generics: input.generics,
+        row_lifetime,
         crate_path: container.crate_path,
+        lookup: container.lookup,
         shape,
         fields,
     })
 }
 
-fn validate_generics(input: &DeriveInput) -> Result<()> {
+/// A row type may borrow from the result table through a single lifetime parameter.
+fn row_lifetime(input: &DeriveInput) -> Result<Option<Lifetime>> {
+    let mut lifetimes = input.generics.lifetimes();
+    let first = lifetimes.next().map(|param| param.lifetime.clone());
+    if let Some(extra) = lifetimes.next() {
+        return Err(syn::Error::new(
+            extra.span(),
+            "FromRow supports at most one lifetime parameter",
+        ));
+    }
+    Ok(first)
+}
+
+/// Whether any identifier in `tokens` is one of `idents`. Paths such as `T::Assoc` or `Vec<T>` count as mentions.
+fn mentions_any(tokens: TokenStream2, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
+pub(crate) fn validate_generics(input: &DeriveInput, derive: &str) -> Result<()> {
     if let Some(lifetime) = input.generics.lifetimes().next() {
         return Err(syn::Error::new(
             lifetime.span(),
-            "FromRow does not support lifetime parameters",
+            format!("{derive} does not support lifetime parameters"),
         ));
     }
 
@@ -92,7 +192,7 @@ fn validate_generics(input: &DeriveInput) -> Result<()> {
     {
         return Err(syn::Error::new(
             input.generics.span(),
-            "FromRow does not support generic parameters",
+            format!("{derive} does not support generic parameters"),
         ));
     }
 
@@ -101,38 +201,52 @@ fn validate_generics(input: &DeriveInput) -> Result<()> {
 
 fn parse_named(named: &syn::FieldsNamed, container: &ContainerAttrs) -> Result<Vec<FieldInfo>> {
     let mut out = Vec::new();
-    for (i, field) in named.named.iter().enumerate() {
-        if is_phantom_data(&field.ty) {
-            return Err(syn::Error::new(
-                field.ty.span(),
-                "FromRow does not support PhantomData fields",
-            ));
-        }
-
+    let mut position = 0;
+    for field in &named.named {
         let field_attrs = parse_field_attrs(field)?;
-        let field_rename = field_attrs.rename;
+        reject_phantom_data(field, &field_attrs)?;
         let ident = field.ident.clone().expect("named");
 
-        let lookup = if container.positional {
-            if field_rename.is_some() {
+        let decode = if field_attrs.skip {
+            FieldDecode::Skip
+        } else if field_attrs.flatten {
+            if container.positional {
                 return Err(syn::Error::new(
                     field.span(),
-                    "container `positional` cannot be combined with field `rename`",
+                    "container `positional` cannot be combined with field `flatten`",
                 ));
             }
-            FieldLookup::Position(i)
+            FieldDecode::Flatten
         } else {
-            let name = field_rename.unwrap_or_else(|| {
-                let raw = logical_ident_name(&ident);
-                apply_rename_all(&raw, container.rename_all)
-            });
-            FieldLookup::Name(name)
+            let lookup = if container.positional {
+                if field_attrs.rename.is_some() {
+                    return Err(syn::Error::new(
+                        field.span(),
+                        "container `positional` cannot be combined with field `rename`",
+                    ));
+                }
+                position += 1;
+                FieldLookup::Position(position - 1)
+            } else {
+                let name = field_attrs.rename.unwrap_or_else(|| {
+                    let raw = logical_ident_name(&ident);
+                    apply_rename_all(&raw, container.rename_all)
+                });
+                FieldLookup::Name(name)
+            };
+            cell_decode(
+                lookup,
+                field_attrs.with,
+                field_attrs.try_from,
+                field_attrs.default,
+            )
         };
 
         out.push(FieldInfo {
             field_ident: Some(ident),
             ty: field.ty.clone(),
-            lookup,
+            decode,
+            generic: false,
         });
     }
 
@@ -149,34 +263,83 @@ fn parse_unnamed(
             "`rename_all` cannot be applied to a tuple struct (implicit `positional`)",
         ));
     }
+    if container.lookup.is_some() {
+        return Err(syn::Error::new(
+            unnamed.paren_token.span.span(),
+            "`lookup` cannot be applied to a tuple struct (implicit `positional`)",
+        ));
+    }
 
     let mut out = Vec::new();
-    for (i, field) in unnamed.unnamed.iter().enumerate() {
-        if is_phantom_data(&field.ty) {
-            return Err(syn::Error::new(
-                field.ty.span(),
-                "FromRow does not support PhantomData fields",
-            ));
-        }
-
+    let mut position = 0;
+    for field in &unnamed.unnamed {
         let field_attrs = parse_field_attrs(field)?;
+        reject_phantom_data(field, &field_attrs)?;
         if field_attrs.rename.is_some() {
             return Err(syn::Error::new(
                 field.span(),
                 "`rename` cannot be applied to tuple struct fields",
             ));
         }
+        if field_attrs.flatten {
+            return Err(syn::Error::new(
+                field.span(),
+                "`flatten` cannot be applied to tuple struct fields",
+            ));
+        }
+
+        let decode = if field_attrs.skip {
+            FieldDecode::Skip
+        } else {
+            position += 1;
+            cell_decode(
+                FieldLookup::Position(position - 1),
+                field_attrs.with,
+                field_attrs.try_from,
+                field_attrs.default,
+            )
+        };
 
         out.push(FieldInfo {
             field_ident: None,
             ty: field.ty.clone(),
-            lookup: FieldLookup::Position(i),
+            decode,
+            generic: false,
         });
     }
 
     Ok(out)
 }
 
+fn cell_decode(
+    lookup: FieldLookup,
+    with: Option<Path>,
+    try_from: Option<syn::Type>,
+    default: bool,
+) -> FieldDecode {
+    let adapter = match (with, try_from) {
+        (Some(module), _) => Some(CellAdapter::With(module)),
+        (None, Some(raw)) => Some(CellAdapter::TryFrom(Box::new(raw))),
+        (None, None) => None,
+    };
+    FieldDecode::Cell {
+        lookup,
+        adapter,
+        default,
+    }
+}
+
+/// `PhantomData` carries no column, so it is only accepted when the field is skipped.
+fn reject_phantom_data(field: &syn::Field, attrs: &FieldAttrs) -> Result<()> {
+    if !attrs.skip && is_phantom_data(&field.ty) {
+        return Err(syn::Error::new(
+            field.ty.span(),
+            "FromRow does not support PhantomData fields",
+        ));
+    }
+    Ok(())
+}
+
 fn is_phantom_data(ty: &syn::Type) -> bool {
     match ty {
         syn::Type::Path(type_path) => type_path
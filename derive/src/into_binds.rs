@@ -0,0 +1,231 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, Member, Path, Result, spanned::Spanned};
+
+use crate::{
+    attrs::{ContainerAttrs, FieldAttrs, RenameAll, parse_container_attrs, parse_field_attrs},
+    input::validate_generics,
+    naming::{apply_rename_all, logical_ident_name},
+};
+
+pub(crate) struct IntoBindsDerive {
+    struct_ident: Ident,
+    crate_path: Path,
+    mode: BindMode,
+    fields: Vec<BindField>,
+}
+
+enum BindMode {
+    Named,
+    Positional,
+}
+
+struct BindField {
+    member: Member,
+    name: Option<String>,
+}
+
+pub(crate) fn analyze(input: DeriveInput) -> Result<IntoBindsDerive> {
+    validate_generics(&input, "IntoBinds")?;
+
+    let container = parse_container_attrs(&input)?;
+    if container.lookup.is_some() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`lookup` is only supported by FromRow",
+        ));
+    }
+    if container.case_insensitive {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`case_insensitive` is only supported by FromCell",
+        ));
+    }
+    let data = match &input.data {
+        Data::Struct(s) => s,
+        Data::Enum(e) => {
+            return Err(syn::Error::new(
+                e.enum_token.span,
+                "IntoBinds does not support enums",
+            ));
+        }
+        Data::Union(u) => {
+            return Err(syn::Error::new(
+                u.union_token.span,
+                "IntoBinds does not support unions",
+            ));
+        }
+    };
+
+    let (mode, fields) = match &data.fields {
+        Fields::Named(named) if named.named.is_empty() => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "IntoBinds does not support empty structs",
+            ));
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.is_empty() => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "IntoBinds does not support empty structs",
+            ));
+        }
+        Fields::Named(named) => parse_named(named, &container)?,
+        Fields::Unnamed(unnamed) => (BindMode::Positional, parse_unnamed(unnamed, &container)?),
+        Fields::Unit => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "IntoBinds does not support unit structs",
+            ));
+        }
+    };
+
+    Ok(IntoBindsDerive {
+        struct_ident: input.ident,
+        crate_path: container.crate_path,
+        mode,
+        fields,
+    })
+}
+
+fn parse_named(
+    named: &syn::FieldsNamed,
+    container: &ContainerAttrs,
+) -> Result<(BindMode, Vec<BindField>)> {
+    // Placeholder names are written by the caller in the SQL text, so unlike `FromRow` the default is to keep field
+    // names as they are.
+    let rename_all = if container.rename_all_explicit {
+        container.rename_all
+    } else {
+        RenameAll::None
+    };
+
+    let mut out = Vec::new();
+    for field in &named.named {
+        let field_attrs = parse_field_attrs(field)?;
+        reject_decode_only(field, &field_attrs)?;
+        if field_attrs.skip {
+            continue;
+        }
+
+        let ident = field.ident.clone().expect("named");
+        let name = if container.positional {
+            if field_attrs.rename.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "container `positional` cannot be combined with field `rename`",
+                ));
+            }
+            None
+        } else {
+            Some(field_attrs.rename.unwrap_or_else(|| {
+                let raw = logical_ident_name(&ident);
+                apply_rename_all(&raw, rename_all)
+            }))
+        };
+
+        out.push(BindField {
+            member: Member::Named(ident),
+            name,
+        });
+    }
+
+    let mode = if container.positional {
+        BindMode::Positional
+    } else {
+        BindMode::Named
+    };
+    Ok((mode, out))
+}
+
+fn parse_unnamed(
+    unnamed: &syn::FieldsUnnamed,
+    container: &ContainerAttrs,
+) -> Result<Vec<BindField>> {
+    if container.rename_all_explicit {
+        return Err(syn::Error::new(
+            unnamed.paren_token.span.span(),
+            "`rename_all` cannot be applied to a tuple struct (implicit `positional`)",
+        ));
+    }
+
+    let mut out = Vec::new();
+    for (i, field) in unnamed.unnamed.iter().enumerate() {
+        let field_attrs = parse_field_attrs(field)?;
+        reject_decode_only(field, &field_attrs)?;
+        if field_attrs.rename.is_some() {
+            return Err(syn::Error::new(
+                field.span(),
+                "`rename` cannot be applied to tuple struct fields",
+            ));
+        }
+        if field_attrs.skip {
+            continue;
+        }
+
+        out.push(BindField {
+            member: Member::Unnamed(i.into()),
+            name: None,
+        });
+    }
+
+    Ok(out)
+}
+
+/// Rejects field attributes that only describe how `FromRow` reads a column.
+fn reject_decode_only(field: &syn::Field, attrs: &FieldAttrs) -> Result<()> {
+    for (set, name) in [
+        (attrs.default, "default"),
+        (attrs.flatten, "flatten"),
+        (attrs.with.is_some(), "with"),
+        (attrs.try_from.is_some(), "try_from"),
+    ] {
+        if set {
+            return Err(syn::Error::new(
+                field.span(),
+                format!("IntoBinds does not support `{name}`"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn expand(model: &IntoBindsDerive) -> TokenStream2 {
+    let crate_path = &model.crate_path;
+    let struct_ident = &model.struct_ident;
+
+    let binds_ty = match model.mode {
+        BindMode::Named => quote! { #crate_path::NamedBinds },
+        BindMode::Positional => quote! { #crate_path::PositionalBinds },
+    };
+    let pushes = model.fields.iter().map(|field| {
+        let member = &field.member;
+        let value = quote! { ::core::clone::Clone::clone(&self.#member) };
+        match &field.name {
+            Some(name) => quote! { binds.insert(#name, #value); },
+            None => quote! { binds.push(#value); },
+        }
+    });
+
+    let body = if model.fields.is_empty() {
+        quote! { #binds_ty::new() }
+    } else {
+        quote! {
+            let mut binds = #binds_ty::new();
+            #(#pushes)*
+            binds
+        }
+    };
+
+    quote! {
+        const _: () = {
+            impl #crate_path::IntoBinds for #struct_ident {
+                type Binds = #binds_ty;
+
+                fn to_binds(&self) -> Self::Binds {
+                    #body
+                }
+            }
+        };
+    }
+}
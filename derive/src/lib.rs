@@ -1,7 +1,9 @@
 //! Derive macros for `snowflake-connector-rs`.
 //!
-//! Enable the `derive` feature on `snowflake-connector-rs` to use `#[derive(FromRow)]` on your row types.
-//! The macro resolves named fields against exact raw result labels from the Snowflake schema.
+//! Enable the `derive` feature on `snowflake-connector-rs` to use `#[derive(FromRow)]` on your row types,
+//! `#[derive(FromCell)]` on enums stored in a single column, and `#[derive(IntoBinds)]` on the structs you bind with
+//! `Statement::bind_struct`.
+//! `FromRow` resolves named fields against exact raw result labels from the Snowflake schema.
 //!
 //! ```rust,ignore
 //! use snowflake_connector_rs::FromRow;
@@ -16,7 +18,9 @@
 
 mod attrs;
 mod codegen;
+mod from_cell;
 mod input;
+mod into_binds;
 mod naming;
 
 use proc_macro::TokenStream;
@@ -30,8 +34,9 @@ use syn::{DeriveInput, parse_macro_input};
 /// the macro converts each logical field name to `SCREAMING_SNAKE_CASE` at compile time, so `created_at` looks up
 /// `CREATED_AT`. Raw identifier prefixes such as `r#type` are stripped before the lookup name is finalized.
 /// The conversion folds ASCII case only: a non-ASCII character is copied through unchanged rather than uppercased,
-/// so it will not match Snowflake's Unicode-aware label casing. Fields whose name contains non-ASCII characters
-/// must use `#[snowflake(rename = "...")]` to specify the raw label explicitly.
+/// so with the default exact lookup it will not match Snowflake's Unicode-aware label casing. Fields whose name
+/// contains non-ASCII characters must either use `#[snowflake(rename = "...")]` to specify the raw label explicitly
+/// or pick a folding `lookup` mode.
 ///
 /// `#[snowflake(rename = "...")]` bypasses container-level renaming and uses the provided raw label verbatim.
 /// `#[snowflake(rename_all = "none")]` keeps the logical field name unchanged.
@@ -46,10 +51,18 @@ use syn::{DeriveInput, parse_macro_input};
 ///   results that intentionally keep labels in lowercase or mixed case.
 /// - `positional`: decode every field by ordinal instead of by label on named structs.
 ///   Tuple structs already decode by position automatically.
+/// - `lookup = "..."`: how lookup names are matched against raw labels, set on the row plan context so that
+///   `flatten` fields without their own `lookup` inherit it. Without the attribute, the context's mode is used,
+///   which is exact matching unless a hand-written caller changed it.
+///   - `"exact"`: match the raw label exactly.
+///   - `"case_insensitive"`: match ignoring case, comparing Unicode uppercase forms. Labels that differ only in case
+///     raise `AmbiguousColumn`.
+///   - `"identifier"`: resolve each name as Snowflake resolves identifiers. An unquoted name folds to Unicode
+///     uppercase, and a name written in double quotes, such as `rename = "\"name\""`, matches exactly.
 /// - `crate = "::path"`: override the crate path used in generated code.
 ///
-/// `rename_all` cannot be combined with `positional`; positional decoding does not use field names.
-/// `rename_all` also cannot be applied to tuple structs, which are implicitly positional.
+/// `rename_all` and `lookup` cannot be combined with `positional`; positional decoding does not use field names.
+/// Neither can be applied to tuple structs, which are implicitly positional.
 ///
 /// ```rust,ignore
 /// #[derive(snowflake_connector_rs::FromRow)]
@@ -60,6 +73,17 @@ use syn::{DeriveInput, parse_macro_input};
 /// ```
 ///
 /// ```rust,ignore
+/// // A dbt model exposing `"order_id"` (quoted, lowercase) next to an unquoted `CUSTOMER_ID`.
+/// #[derive(snowflake_connector_rs::FromRow)]
+/// #[snowflake(lookup = "identifier", rename_all = "none")]
+/// struct DbtOrder {
+///     #[snowflake(rename = "\"order_id\"")]
+///     order_id: i64,
+///     customer_id: i64,
+/// }
+/// ```
+///
+/// ```rust,ignore
 /// #[derive(snowflake_connector_rs::FromRow)]
 /// #[snowflake(positional)]
 /// struct PositionalRow {
@@ -82,6 +106,20 @@ use syn::{DeriveInput, parse_macro_input};
 /// # Field attributes
 ///
 /// - `rename = "..."`: use the exact raw result label for this field.
+/// - `default`: use `Default::default()` when the column is missing from the result or the cell is SQL `NULL`.
+/// - `skip`: do not read a column for this field; it is always `Default::default()`. On positional structs, a skipped
+///   field does not consume a column position.
+/// - `flatten`: decode the field as another `FromRow` struct against the same row, for example a set of audit
+///   columns shared by several row types. Not available with positional decoding.
+/// - `with = "module"`: decode through a custom adapter instead of the field type's `FromCell` implementation. The
+///   module provides the same items as `FromCell`, with the field type as the decoded value:
+///   `type Plan`, `fn build_plan(CellPlanContext<'_>) -> PlanBuildResult<Plan>`, and
+///   `fn from_cell_with_plan(Option<&str>, &Plan) -> CellDecodeResult<FieldType>`.
+/// - `try_from = "RawType"`: decode the column as `RawType`, then convert with `TryFrom<RawType>`. A conversion error
+///   becomes a cell decode error whose source is the `TryFrom` error.
+///
+/// `skip` and `flatten` cannot be combined with other field attributes, and `with` cannot be combined with
+/// `try_from`. `default` combines with `rename`, `with`, and `try_from`.
 ///
 /// ```rust,ignore
 /// #[derive(snowflake_connector_rs::FromRow)]
@@ -91,10 +129,59 @@ use syn::{DeriveInput, parse_macro_input};
 /// }
 /// ```
 ///
+/// ```rust,ignore
+/// #[derive(snowflake_connector_rs::FromRow)]
+/// struct AuditColumns {
+///     created_by: String,
+///     updated_by: Option<String>,
+/// }
+///
+/// #[derive(snowflake_connector_rs::FromRow)]
+/// struct AccountRow {
+///     #[snowflake(try_from = "i64")]
+///     id: AccountId,
+///     #[snowflake(flatten)]
+///     audit: AuditColumns,
+///     #[snowflake(default)]
+///     tags: String,
+///     #[snowflake(skip)]
+///     cached_balance: Option<f64>,
+/// }
+/// ```
+///
+/// # Generics and borrowed rows
+///
+/// Type and const parameters are allowed. The generated impl adds the bound each field needs, such as `T: FromCell`
+/// for a cell field, `T: FromRow` for a `flatten` field, and `T: Default` for a `default` or `skip` field. `with` and
+/// `try_from` are not available on fields whose type uses a generic parameter.
+///
+/// A struct with one lifetime parameter borrows from the result table. It implements `FromRowRef<'a>` instead of
+/// `FromRow`, so its `&'a str` fields point into the table's storage; read it with `ResultTable::rows_ref`. Structs
+/// without a lifetime implement both traits.
+///
+/// ```rust,ignore
+/// #[derive(snowflake_connector_rs::FromRow)]
+/// struct Page<T> {
+///     total: i64,
+///     #[snowflake(flatten)]
+///     item: T,
+/// }
+///
+/// #[derive(snowflake_connector_rs::FromRow)]
+/// struct NameRow<'a> {
+///     id: i64,
+///     name: &'a str,
+/// }
+///
+/// for row in table.rows_ref::<NameRow<'_>>()? {
+///     println!("{}", row?.name);
+/// }
+/// ```
+///
 /// # SQL NULL
 ///
-/// Use `Option<T>` when a projected column may carry SQL `NULL`. Missing columns still raise `MissingColumn`;
-/// if you need a non-`Option` default, project it explicitly in SQL.
+/// Use `Option<T>` when a projected column may carry SQL `NULL`. Missing columns still raise `MissingColumn` unless
+/// the field is marked `#[snowflake(default)]`.
 ///
 /// ```rust,ignore
 /// #[derive(snowflake_connector_rs::FromRow)]
@@ -109,7 +196,7 @@ use syn::{DeriveInput, parse_macro_input};
 /// The generated implementation validates the schema while building the decode plan, then propagates cell decode
 /// errors while reading rows:
 ///
-/// - `MissingColumn` for required named lookups, including `Option<T>` fields.
+/// - `MissingColumn` for required named lookups, including `Option<T>` fields but not `default` fields.
 /// - `AmbiguousColumn` when the schema contains duplicate raw labels.
 /// - `ColumnCountMismatch` when required positional fields exceed the schema.
 /// - `IncompatibleColumnType` when a column's Snowflake type cannot be decoded as the field's Rust type. This is
@@ -127,3 +214,106 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
         Err(error) => error.to_compile_error().into(),
     }
 }
+
+/// Derives `snowflake_connector_rs::IntoBinds` for a struct, so that it can be passed to `Statement::bind_struct`.
+///
+/// Each field is cloned into one bind value, so every field type must implement `IntoBind` and `Clone`.
+///
+/// # Bind names
+///
+/// Named-field structs produce `NamedBinds` keyed by field name. Because the placeholders are written in the SQL
+/// text, the field name is used as-is by default: `user_id` binds `:user_id`. Raw identifier prefixes such as
+/// `r#type` are stripped.
+///
+/// With `#[snowflake(positional)]`, or on a tuple struct, the derive produces `PositionalBinds` in field
+/// declaration order instead.
+///
+/// ```rust,ignore
+/// #[derive(snowflake_connector_rs::IntoBinds)]
+/// struct NewUser {
+///     id: i64,
+///     #[snowflake(rename = "user_name")]
+///     name: String,
+///     #[snowflake(skip)]
+///     cached_display: String,
+/// }
+///
+/// let stmt = Statement::new("INSERT INTO users (id, name) VALUES (:id, :user_name)").bind_struct(&user);
+/// ```
+///
+/// # Container attributes
+///
+/// - `rename_all = "SCREAMING_SNAKE_CASE"`: convert field names at compile time, as `FromRow` does by default.
+/// - `rename_all = "none"`: use each logical field name as-is. This is the default.
+/// - `positional`: bind every field by position instead of by name on named structs.
+/// - `crate = "::path"`: override the crate path used in generated code.
+///
+/// The same combinations are rejected as for `FromRow`: `rename_all` cannot be combined with `positional` or
+/// applied to tuple structs.
+///
+/// # Field attributes
+///
+/// - `rename = "..."`: use the given placeholder name for this field.
+/// - `skip`: leave this field out of the binds. Positional binds after a skipped field shift down by one.
+#[proc_macro_derive(IntoBinds, attributes(snowflake))]
+pub fn derive_into_binds(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = into_binds::analyze(input).map(|model| into_binds::expand(&model));
+
+    match expanded {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Derives `snowflake_connector_rs::FromCell` for a fieldless enum, such as a status column.
+///
+/// The enum decodes from either kind of column, chosen when the decode plan is built:
+///
+/// - `TEXT`: the cell must equal a variant name. Names follow the same rules as `FromRow` field names, so `InReview`
+///   matches `IN_REVIEW` by default.
+/// - `FIXED` with scale 0: the cell must equal a variant's discriminant (`Self::Variant as i64`). Discriminants must
+///   fit in `i64`: `#[repr(i128)]` and `#[repr(u128)]` are rejected, and with `#[repr(u64)]` or `#[repr(usize)]` a
+///   larger discriminant is a compile error.
+///
+/// Other column types fail plan building with `IncompatibleColumnType`. A value that matches no variant is a cell
+/// conversion error, and SQL `NULL` needs an `Option<Enum>` field like any other type.
+///
+/// ```rust,ignore
+/// #[derive(Debug, PartialEq, snowflake_connector_rs::FromCell)]
+/// #[snowflake(case_insensitive)]
+/// enum OrderStatus {
+///     Open,
+///     InReview,
+///     #[snowflake(rename = "done")]
+///     Closed = 9,
+/// }
+///
+/// #[derive(snowflake_connector_rs::FromRow)]
+/// struct OrderRow {
+///     id: i64,
+///     status: OrderStatus,
+/// }
+/// ```
+///
+/// # Container attributes
+///
+/// - `rename_all = "SCREAMING_SNAKE_CASE"`: convert variant names at compile time. This is the default.
+/// - `rename_all = "none"`: match each variant name as written.
+/// - `case_insensitive`: compare names ignoring ASCII case. Two variants whose names differ only in case are
+///   rejected.
+/// - `crate = "::path"`: override the crate path used in generated code.
+///
+/// # Variant attributes
+///
+/// - `rename = "..."`: match this exact text instead of the converted variant name.
+#[proc_macro_derive(FromCell, attributes(snowflake))]
+pub fn derive_from_cell(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = from_cell::analyze(input).map(|model| from_cell::expand(&model));
+
+    match expanded {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
@@ -19,6 +19,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
     run_access_by_name_and_index().await?;
     run_take_values_out().await?;
     run_json_conversion().await?;
+    run_pretty_table().await?;
     Ok(())
 }
 
@@ -163,6 +164,26 @@ async fn run_json_conversion() -> Result<()> {
     Ok(())
 }
 
+async fn run_pretty_table() -> Result<()> {
+    println!("\n== scenario: print a result as a text table ==");
+    let session = connect(PRODUCT_RESULT).await;
+
+    let table = session.query(SAMPLE_QUERY).await?.collect_table().await?;
+
+    // `Display` renders every row with the defaults; `pretty()` adjusts widths, row limits, and NULL text.
+    println!("{table}");
+    println!(
+        "{}",
+        table
+            .pretty()
+            .with_max_column_width(12)
+            .with_max_rows(10)
+            .with_null_text("-")
+    );
+
+    Ok(())
+}
+
 async fn connect(query_response: &'static str) -> Session {
     let base_url = spawn_mock_snowflake(vec![LOGIN_OK, query_response]);
 
@@ -1,7 +1,16 @@
-use http::Method;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::{Method, StatusCode};
 use reqwest::{Url, header::USER_AGENT};
+use tokio::time::sleep;
 
-use crate::{Result, error::ConfigError};
+use crate::{
+    Result, RetryPolicy,
+    config::{is_retryable_request_error, is_retryable_status, retry_after, send_request},
+    error::ConfigError,
+    observer::{ObserverHandle, RetryEvent, RetryOperation},
+};
 
 pub(crate) const DEFAULT_USER_AGENT: &str =
     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
@@ -9,15 +18,32 @@ pub(crate) const DEFAULT_USER_AGENT: &str =
 /// The connector's authority to talk to one trusted Snowflake origin.
 ///
 /// It represents what every Snowflake API request has in common regardless of endpoint: the origin it is addressed
-/// to and the connector it originates from.
+/// to, the connector it originates from, how transient failures are retried, and who observes the traffic.
 pub(crate) struct ApiContext {
     http: reqwest::Client,
     base_url: Url,
+    retry: RetryPolicy,
+    observer: ObserverHandle,
 }
 
 impl ApiContext {
     pub(crate) fn new(http: reqwest::Client, base_url: Url) -> Self {
-        Self { http, base_url }
+        Self {
+            http,
+            base_url,
+            retry: RetryPolicy::default(),
+            observer: ObserverHandle::default(),
+        }
+    }
+
+    pub(crate) fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub(crate) fn with_observer(mut self, observer: ObserverHandle) -> Self {
+        self.observer = observer;
+        self
     }
 
     pub(crate) fn resolve(&self, relative: &str) -> Result<Url> {
@@ -32,11 +58,116 @@ impl ApiContext {
             .header(USER_AGENT, DEFAULT_USER_AGENT)
     }
 
+    /// Send the request produced by `build` under the retry policy and return the final status and body.
+    ///
+    /// `build` is called once per attempt with `true` for retries. Retryable statuses and connection failures are
+    /// retried until the policy is exhausted, after which the last status or error is returned as is. With the
+    /// `tracing` feature, each retry emits a debug event and the retry count is recorded on the current span's
+    /// `retries` field when it declares one.
+    pub(crate) async fn send_with_retry(
+        &self,
+        operation: RetryOperation,
+        mut build: impl FnMut(bool) -> reqwest::RequestBuilder,
+    ) -> reqwest::Result<(StatusCode, Bytes)> {
+        let mut retry = self.retry.start();
+        loop {
+            let outcome = async {
+                let response = send_request(build(retry.retries() > 0)).await?;
+                let status = response.status();
+                let retry_after = retry_after(response.headers());
+                let body = response.bytes().await?;
+                Ok::<_, reqwest::Error>((status, retry_after, body))
+            }
+            .await;
+
+            let delay = match outcome {
+                Ok((status, retry_after, body)) => {
+                    if !is_retryable_status(status) {
+                        record_retries(retry.retries());
+                        return Ok((status, body));
+                    }
+                    match retry.next_delay(retry_after) {
+                        Some(delay) => {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(
+                                status = status.as_u16(),
+                                retry = retry.retries(),
+                                delay_ms = delay.as_millis() as u64,
+                                "retrying Snowflake request after transient status"
+                            );
+                            self.notify_retry(operation, retry.retries(), delay, Some(status));
+                            delay
+                        }
+                        None => {
+                            record_retries(retry.retries());
+                            return Ok((status, body));
+                        }
+                    }
+                }
+                Err(error) if is_retryable_request_error(&error) => match retry.next_delay(None) {
+                    Some(delay) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(
+                            error = %error,
+                            retry = retry.retries(),
+                            delay_ms = delay.as_millis() as u64,
+                            "retrying Snowflake request after connection failure"
+                        );
+                        self.notify_retry(operation, retry.retries(), delay, None);
+                        delay
+                    }
+                    None => {
+                        record_retries(retry.retries());
+                        return Err(error);
+                    }
+                },
+                Err(error) => {
+                    record_retries(retry.retries());
+                    return Err(error);
+                }
+            };
+            sleep(delay).await;
+        }
+    }
+
+    pub(crate) fn notify_retry(
+        &self,
+        operation: RetryOperation,
+        retry: u32,
+        delay: Duration,
+        status: Option<StatusCode>,
+    ) {
+        self.observer.notify(|observer| {
+            observer.on_retry(&RetryEvent {
+                operation,
+                retry,
+                delay,
+                status: status.map(|status| status.as_u16()),
+            })
+        });
+    }
+
     pub(crate) fn base_url(&self) -> &Url {
         &self.base_url
     }
+
+    pub(crate) fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    pub(crate) fn observer(&self) -> &ObserverHandle {
+        &self.observer
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn record_retries(retries: u32) {
+    tracing::Span::current().record("retries", retries);
 }
 
+#[cfg(not(feature = "tracing"))]
+fn record_retries(_retries: u32) {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,8 +6,12 @@ use reqwest::{Url, header::ACCEPT};
 use crate::{
     ApiContext, Result,
     error::{NetworkError, classify_request_error},
+    observer::RetryOperation,
 };
 
+#[cfg(feature = "external-browser-sso")]
+use crate::config::send_request;
+
 #[cfg(feature = "external-browser-sso")]
 use super::wire::{
     AuthenticatorRequest, ClientEnvironment, ExternalBrowserChallenge, parse_authenticator_response,
@@ -41,24 +45,33 @@ impl AuthApiClient {
         }
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "snowflake.login",
+            skip_all,
+            fields(status = tracing::field::Empty, retries = tracing::field::Empty)
+        )
+    )]
     pub(crate) async fn login(&self, request: LoginRequest<'_>) -> Result<LoginSession> {
         let url = self.api.resolve("session/v1/login-request")?;
 
-        let response = self
-            .post(url, LOGIN_REQUEST_ACCEPT)
-            .query(&request.query)
-            .json(&request.body)
-            .send()
+        let (status, body) = self
+            .api
+            .send_with_retry(RetryOperation::Login, |_| {
+                self.post(url.clone(), LOGIN_REQUEST_ACCEPT)
+                    .query(&request.query)
+                    .json(&request.body)
+            })
             .await
             .map_err(classify_request_error)?;
-
-        let status = response.status();
-        let body = response.text().await.map_err(classify_request_error)?;
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", status.as_u16());
         if !status.is_success() {
-            return Err(NetworkError::http_status(status.as_u16(), body.as_bytes()).into());
+            return Err(NetworkError::http_status(status.as_u16(), &body).into());
         }
 
-        parse_login_response(&body)
+        parse_login_response(&String::from_utf8_lossy(&body))
     }
 
     #[cfg(feature = "external-browser-sso")]
@@ -69,10 +82,7 @@ impl AuthApiClient {
         let url = self.api.resolve("session/authenticator-request")?;
 
         let body = request.into_body(ClientEnvironment::auth_defaults(self.request_timeout));
-        let response = self
-            .post(url, AUTHENTICATOR_REQUEST_ACCEPT)
-            .json(&body)
-            .send()
+        let response = send_request(self.post(url, AUTHENTICATOR_REQUEST_ACCEPT).json(&body))
             .await
             .map_err(classify_request_error)?;
 
@@ -103,7 +113,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        ErrorKind,
+        ErrorKind, RetryPolicy,
         api_context::DEFAULT_USER_AGENT,
         auth::wire::{LoginBody, LoginCredentialWire, LoginData, LoginQuery, LoginRequest},
         test_support::http::{base_url, read_http_request, write_json_response},
@@ -221,6 +231,37 @@ mod tests {
         server.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn login_retries_transient_status() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            for (status, body) in [
+                (StatusCode::SERVICE_UNAVAILABLE, "unavailable"),
+                (
+                    StatusCode::OK,
+                    r#"{"success":true,"data":{"token":"session-token"}}"#,
+                ),
+            ] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let _ = read_http_request(&mut socket).await.unwrap();
+                write_json_response(&mut socket, status, body)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let client = AuthApiClient::new(Arc::new(
+            ApiContext::new(reqwest::Client::new(), base_url(addr)).with_retry_policy(
+                RetryPolicy::new().with_initial_backoff(Duration::from_millis(10)),
+            ),
+        ));
+        let session = client.login(sample_login_request()).await.unwrap();
+
+        assert_eq!(session.token, "session-token");
+        server.await.unwrap();
+    }
+
     #[tokio::test]
     async fn login_invalid_json_is_protocol_error() {
         let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
@@ -1,17 +1,23 @@
+use std::sync::Arc;
+
 use crate::{
     Result,
     error::{AuthError, ProtocolError},
+    statement::wire::response::{WireParameter, session_timezone},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct LoginSession {
     pub(crate) token: String,
+    /// The session `TIMEZONE` parameter, when the login response reports it.
+    pub(crate) timezone: Option<Arc<str>>,
 }
 
 #[derive(serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct LoginResponseData {
     token: Option<String>,
+    parameters: Option<Vec<WireParameter>>,
 }
 
 #[derive(serde::Deserialize)]
@@ -35,7 +41,9 @@ pub(crate) fn parse_login_response(body: &str) -> Result<LoginSession> {
         .token
         .ok_or_else(|| ProtocolError::missing_field("data.token"))?;
 
-    Ok(LoginSession { token })
+    let timezone = data.parameters.as_deref().and_then(session_timezone);
+
+    Ok(LoginSession { token, timezone })
 }
 
 #[cfg(test)]
@@ -51,11 +59,33 @@ mod tests {
         assert_eq!(
             session,
             LoginSession {
-                token: "session-token".to_string()
+                token: "session-token".to_string(),
+                timezone: None,
             }
         );
     }
 
+    #[test]
+    fn parse_login_response_keeps_session_timezone_parameter() {
+        let session = parse_login_response(
+            r#"{"success":true,"data":{"token":"session-token","parameters":[{"name":"AUTOCOMMIT","value":true},{"name":"TIMEZONE","value":"Asia/Tokyo"}]}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(session.timezone.as_deref(), Some("Asia/Tokyo"));
+    }
+
+    #[test]
+    fn parse_login_response_skips_parameters_without_a_value() {
+        let session = parse_login_response(
+            r#"{"success":true,"data":{"token":"session-token","parameters":[{"name":"CLIENT_PREFETCH_THREADS"},{"value":1},{"name":"TIMEZONE","value":"UTC"}]}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(session.token, "session-token");
+        assert_eq!(session.timezone.as_deref(), Some("UTC"));
+    }
+
     #[test]
     fn parse_login_response_missing_data_is_protocol_error() {
         let err = parse_login_response(r#"{"success":true}"#).unwrap_err();
@@ -7,6 +7,7 @@ use bytes::Bytes;
 
 use crate::{
     Result, ResultTable,
+    observer::ObserverHandle,
     result_table::Schema,
     rowset::{self, parser},
     statement::parse_query_response,
@@ -95,7 +96,14 @@ pub async fn parse_remote_chunk_result_table_async_with_workload(
             Some(uncompressed_bytes.unwrap_or(body.len()))
         },
     };
-    parser::parse_remote_chunk_result_table_async(schema, query_id, body, workload, None)
-        .await
-        .map_err(crate::Error::from)
+    parser::parse_remote_chunk_result_table_async(
+        schema,
+        query_id,
+        body,
+        workload,
+        None,
+        &ObserverHandle::default(),
+    )
+    .await
+    .map_err(crate::Error::from)
 }
@@ -0,0 +1,158 @@
+//! `snowsql-rs`: an interactive SQL shell for Snowflake built on `snowflake-connector-rs`.
+//!
+//! Connections are read from the Snowflake CLI's `connections.toml`; command-line options override individual
+//! settings. See `snowsql-rs --help`.
+
+mod profile;
+mod shell;
+mod split;
+
+use std::{env, error::Error, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+
+use snowflake_connector_rs::Client;
+
+use crate::{
+    profile::{Authenticator, BrowserLaunch, Connection, Connections},
+    shell::{OutputMode, Shell},
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "snowsql-rs",
+    version,
+    about = "Interactive SQL shell for Snowflake"
+)]
+struct Args {
+    /// Connection to use from the connections file.
+    #[arg(short = 'c', long, env = "SNOWFLAKE_DEFAULT_CONNECTION_NAME")]
+    connection: Option<String>,
+    /// Connections file. Defaults to `$SNOWFLAKE_HOME/connections.toml` or `~/.snowflake/connections.toml`.
+    #[arg(long, value_name = "PATH")]
+    connections_file: Option<PathBuf>,
+
+    /// Account identifier, such as `myorg-myaccount`.
+    #[arg(short = 'a', long)]
+    account: Option<String>,
+    /// Login name.
+    #[arg(short = 'u', long)]
+    user: Option<String>,
+    /// Authentication method. Defaults to `snowflake_jwt` when a private key is configured, else `snowflake`.
+    #[arg(long, value_enum)]
+    authenticator: Option<Authenticator>,
+    /// Private key for `snowflake_jwt` authentication.
+    #[arg(long, value_name = "PATH")]
+    private_key_file: Option<PathBuf>,
+    /// Whether `externalbrowser` authentication opens the login URL (`auto`) or only prints it (`manual`).
+    #[arg(long, value_enum)]
+    browser_launch: Option<BrowserLaunch>,
+    /// Session settings; each overrides the connection's.
+    #[arg(short = 'w', long)]
+    warehouse: Option<String>,
+    #[arg(short = 'd', long)]
+    database: Option<String>,
+    #[arg(short = 's', long)]
+    schema: Option<String>,
+    #[arg(short = 'r', long)]
+    role: Option<String>,
+
+    /// Run these statements and exit.
+    #[arg(short = 'q', long, conflicts_with = "file")]
+    query: Option<String>,
+    /// Run the statements in this file and exit.
+    #[arg(short = 'f', long, value_name = "PATH")]
+    file: Option<PathBuf>,
+    /// How results are printed.
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputMode::Table)]
+    output: OutputMode,
+    /// Rows shown per result in table output. Defaults to all rows.
+    #[arg(long)]
+    max_rows: Option<usize>,
+    /// Widest a column may be in table output before it is truncated.
+    #[arg(long, default_value_t = 40)]
+    max_width: usize,
+}
+
+impl Args {
+    /// Load the selected connection and apply command-line overrides.
+    fn connection(&self) -> Result<(String, Connection), Box<dyn Error>> {
+        let name = self.connection.as_deref().unwrap_or("default");
+        let path = self
+            .connections_file
+            .clone()
+            .or_else(profile::default_connections_file);
+        // Without an explicit file or connection name, a missing default file or `default` entry just means "use
+        // the flags".
+        let explicit = self.connections_file.is_some() || self.connection.is_some();
+        let mut connections = match path {
+            Some(path) if explicit || path.exists() => profile::load_connections(&path)?,
+            _ => Connections::new(),
+        };
+        let mut connection = if explicit || connections.contains_key(name) {
+            profile::take_connection(&mut connections, name)?
+        } else {
+            Connection::default()
+        };
+
+        let overrides = [
+            (&mut connection.account, &self.account),
+            (&mut connection.user, &self.user),
+            (&mut connection.warehouse, &self.warehouse),
+            (&mut connection.database, &self.database),
+            (&mut connection.schema, &self.schema),
+            (&mut connection.role, &self.role),
+        ];
+        for (setting, flag) in overrides {
+            if flag.is_some() {
+                setting.clone_from(flag);
+            }
+        }
+        if self.authenticator.is_some() {
+            connection.authenticator = self.authenticator;
+        }
+        if self.private_key_file.is_some() {
+            connection
+                .private_key_file
+                .clone_from(&self.private_key_file);
+        }
+        if self.browser_launch.is_some() {
+            connection.browser_launch = self.browser_launch;
+        }
+
+        Ok((name.to_string(), connection))
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+    match run(args).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let (name, connection) = args.connection()?;
+    let client = Client::new(connection.client_config()?)?;
+    let session = client.create_session().await?;
+    let mut shell = Shell::new(session, args.output, args.max_rows, args.max_width);
+
+    if let Some(query) = &args.query {
+        return shell.run_script(query).await;
+    }
+    if let Some(file) = &args.file {
+        return shell.run_file(file).await;
+    }
+
+    eprintln!(
+        "snowsql-rs {} connected as {}. Type !help for commands.",
+        env!("CARGO_PKG_VERSION"),
+        connection.user.as_deref().unwrap_or_default()
+    );
+    shell.interactive(&format!("{name}> ")).await
+}
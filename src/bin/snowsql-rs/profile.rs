@@ -0,0 +1,302 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fs,
+    net::{IpAddr, Ipv4Addr},
+    num::NonZeroU16,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+use url::Url;
+
+use snowflake_connector_rs::{
+    AuthConfig, BrowserLaunchMode, ClientConfig, EndpointConfig, ExternalBrowserConfig,
+    KeyPairConfig, PasswordConfig, SessionConfig,
+};
+
+/// `AUTHENTICATOR` values, named as in the other Snowflake clients.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(try_from = "String")]
+pub(crate) enum Authenticator {
+    /// Username and password, with an optional MFA passcode.
+    #[value(name = "snowflake")]
+    Snowflake,
+    /// Key-pair (JWT) authentication.
+    #[value(name = "snowflake_jwt")]
+    SnowflakeJwt,
+    /// An OAuth access token.
+    #[value(name = "oauth")]
+    OAuth,
+    /// Browser-based SSO.
+    #[value(name = "externalbrowser")]
+    ExternalBrowser,
+}
+
+impl TryFrom<String> for Authenticator {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value, true).map_err(|_| {
+            format!(
+                "unknown authenticator `{value}` (expected snowflake, snowflake_jwt, oauth, or externalbrowser)"
+            )
+        })
+    }
+}
+
+/// Whether external-browser SSO opens the login URL itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum BrowserLaunch {
+    Auto,
+    Manual,
+}
+
+impl From<BrowserLaunch> for BrowserLaunchMode {
+    fn from(launch: BrowserLaunch) -> Self {
+        match launch {
+            BrowserLaunch::Auto => BrowserLaunchMode::Auto,
+            BrowserLaunch::Manual => BrowserLaunchMode::Manual,
+        }
+    }
+}
+
+/// One named connection from `connections.toml`.
+///
+/// Keys follow the Snowflake CLI's `connections.toml`, so an existing file can be reused; keys this shell does not
+/// understand are ignored.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub(crate) struct Connection {
+    pub(crate) account: Option<String>,
+    pub(crate) user: Option<String>,
+    pub(crate) authenticator: Option<Authenticator>,
+    pub(crate) password: Option<String>,
+    pub(crate) passcode: Option<String>,
+    #[serde(alias = "private_key_path")]
+    pub(crate) private_key_file: Option<PathBuf>,
+    #[serde(alias = "private_key_passphrase")]
+    pub(crate) private_key_file_pwd: Option<String>,
+    pub(crate) token: Option<String>,
+    pub(crate) token_file_path: Option<PathBuf>,
+    pub(crate) host: Option<String>,
+    pub(crate) warehouse: Option<String>,
+    pub(crate) database: Option<String>,
+    pub(crate) schema: Option<String>,
+    pub(crate) role: Option<String>,
+    pub(crate) browser_launch: Option<BrowserLaunch>,
+    /// Selects manual-redirect SSO: the port Snowflake embeds in the redirect URL that is pasted back.
+    pub(crate) redirect_port: Option<NonZeroU16>,
+    /// Port for the local SSO callback listener. Defaults to an OS-assigned port.
+    pub(crate) callback_port: Option<u16>,
+}
+
+/// `$SNOWFLAKE_HOME/connections.toml`, or `~/.snowflake/connections.toml` when `SNOWFLAKE_HOME` is unset.
+pub(crate) fn default_connections_file() -> Option<PathBuf> {
+    match env::var_os("SNOWFLAKE_HOME") {
+        Some(home) => Some(PathBuf::from(home).join("connections.toml")),
+        None => dirs::home_dir().map(|home| home.join(".snowflake").join("connections.toml")),
+    }
+}
+
+/// Named connections, as read from a connections file.
+pub(crate) type Connections = BTreeMap<String, Connection>;
+
+/// Read every connection in the TOML file at `path`.
+pub(crate) fn load_connections(path: &Path) -> Result<Connections, Box<dyn Error>> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("cannot read connections file {}: {e}", path.display()))?;
+    toml::from_str(&text).map_err(|e| format!("{}: {e}", path.display()).into())
+}
+
+/// Remove the connection called `name`, or report which names exist.
+pub(crate) fn take_connection(
+    connections: &mut Connections,
+    name: &str,
+) -> Result<Connection, Box<dyn Error>> {
+    connections.remove(name).ok_or_else(|| {
+        let known = connections.keys().cloned().collect::<Vec<_>>().join(", ");
+        format!("no connection named `{name}` (found: {known})").into()
+    })
+}
+
+impl Connection {
+    /// Build the client configuration, reading key and token files and falling back to the `SNOWFLAKE_PASSWORD` and
+    /// `PRIVATE_KEY_PASSPHRASE` environment variables for secrets missing from the profile.
+    pub(crate) fn client_config(&self) -> Result<ClientConfig, Box<dyn Error>> {
+        let account = self.account.as_deref().ok_or("no account configured")?;
+        let user = self.user.as_deref().ok_or("no user configured")?;
+
+        let mut config = ClientConfig::new(user, account, self.auth_config()?)
+            .with_session(self.session_config());
+        if let Some(host) = &self.host {
+            let url = Url::parse(&format!("https://{host}"))
+                .map_err(|e| format!("invalid host `{host}`: {e}"))?;
+            config = config.with_endpoint(EndpointConfig::custom_base_url(url));
+        }
+        Ok(config)
+    }
+
+    fn auth_config(&self) -> Result<AuthConfig, Box<dyn Error>> {
+        let authenticator = self
+            .authenticator
+            .unwrap_or(if self.private_key_file.is_some() {
+                Authenticator::SnowflakeJwt
+            } else {
+                Authenticator::Snowflake
+            });
+
+        match authenticator {
+            Authenticator::Snowflake => {
+                let password = self
+                    .password
+                    .clone()
+                    .or_else(|| env::var("SNOWFLAKE_PASSWORD").ok())
+                    .ok_or("no password configured; set `password` or SNOWFLAKE_PASSWORD")?;
+                let mut config = PasswordConfig::new(password);
+                if let Some(passcode) = &self.passcode {
+                    config = config.with_passcode(passcode);
+                }
+                Ok(AuthConfig::password(config))
+            }
+            Authenticator::SnowflakeJwt => {
+                let path = self
+                    .private_key_file
+                    .as_deref()
+                    .ok_or("key-pair authentication needs `private_key_file`")?;
+                let pem = fs::read_to_string(path)
+                    .map_err(|e| format!("cannot read private key {}: {e}", path.display()))?;
+                let passphrase = self
+                    .private_key_file_pwd
+                    .clone()
+                    .or_else(|| env::var("PRIVATE_KEY_PASSPHRASE").ok());
+                let key = match passphrase {
+                    Some(passphrase) => KeyPairConfig::from_encrypted_pem(pem, passphrase),
+                    None => KeyPairConfig::from_pem(pem),
+                };
+                Ok(AuthConfig::key_pair(key))
+            }
+            Authenticator::OAuth => {
+                let token = match (&self.token, &self.token_file_path) {
+                    (Some(token), _) => token.clone(),
+                    (None, Some(path)) => fs::read_to_string(path)
+                        .map_err(|e| format!("cannot read token file {}: {e}", path.display()))?
+                        .trim()
+                        .to_string(),
+                    (None, None) => {
+                        return Err(
+                            "OAuth authentication needs `token` or `token_file_path`".into()
+                        );
+                    }
+                };
+                Ok(AuthConfig::oauth(token))
+            }
+            Authenticator::ExternalBrowser => {
+                let launch = self.browser_launch.unwrap_or(BrowserLaunch::Auto).into();
+                let browser = match self.redirect_port {
+                    Some(port) => ExternalBrowserConfig::manual_redirect(launch, port),
+                    None => ExternalBrowserConfig::callback_listener(
+                        launch,
+                        IpAddr::V4(Ipv4Addr::LOCALHOST),
+                        self.callback_port.unwrap_or(0),
+                    ),
+                };
+                Ok(AuthConfig::external_browser(browser))
+            }
+        }
+    }
+
+    fn session_config(&self) -> SessionConfig {
+        let mut session = SessionConfig::new();
+        if let Some(warehouse) = &self.warehouse {
+            session = session.with_warehouse(warehouse);
+        }
+        if let Some(database) = &self.database {
+            session = session.with_database(database);
+        }
+        if let Some(schema) = &self.schema {
+            session = session.with_schema(schema);
+        }
+        if let Some(role) = &self.role {
+            session = session.with_role(role);
+        }
+        session
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONNECTIONS: &str = r#"
+        [dev]
+        account = "xy12345"
+        user = "ALICE"
+        authenticator = "EXTERNALBROWSER"
+        browser_launch = "manual"
+        redirect_port = 3037
+        warehouse = "DEV_WH"
+        port = 443
+
+        [ci]
+        account = "xy12345"
+        user = "CI"
+        private_key_path = "/nonexistent/rsa_key.p8"
+    "#;
+
+    fn parse(text: &str) -> Connections {
+        toml::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn reads_snowflake_cli_connections_and_ignores_unknown_keys() {
+        let mut connections = parse(CONNECTIONS);
+        let dev = take_connection(&mut connections, "dev").unwrap();
+
+        assert_eq!(dev.account.as_deref(), Some("xy12345"));
+        assert_eq!(dev.authenticator, Some(Authenticator::ExternalBrowser));
+        assert_eq!(dev.browser_launch, Some(BrowserLaunch::Manual));
+        assert_eq!(dev.redirect_port, NonZeroU16::new(3037));
+        assert_eq!(dev.warehouse.as_deref(), Some("DEV_WH"));
+        assert!(dev.client_config().is_ok());
+
+        let ci = take_connection(&mut connections, "ci").unwrap();
+        assert_eq!(
+            ci.private_key_file.as_deref(),
+            Some(Path::new("/nonexistent/rsa_key.p8"))
+        );
+    }
+
+    #[test]
+    fn reports_missing_connections_and_settings() {
+        let mut connections = parse(CONNECTIONS);
+        let err = take_connection(&mut connections, "prod").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no connection named `prod` (found: ci, dev)"
+        );
+
+        let err = toml::from_str::<Connections>("[x]\nauthenticator = \"kerberos\"\n").unwrap_err();
+        assert!(err.to_string().contains("unknown authenticator `kerberos`"));
+
+        let no_account = Connection {
+            user: Some("ALICE".to_string()),
+            ..Connection::default()
+        };
+        assert_eq!(
+            no_account.client_config().unwrap_err().to_string(),
+            "no account configured"
+        );
+
+        // A private key file implies key-pair authentication.
+        let ci = take_connection(&mut connections, "ci").unwrap();
+        assert!(
+            ci.client_config()
+                .unwrap_err()
+                .to_string()
+                .starts_with("cannot read private key /nonexistent/rsa_key.p8")
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    error::Error,
+    fs,
+    io::Write as _,
+    path::{Path, PathBuf},
+    pin::pin,
+    time::Instant,
+};
+
+use clap::ValueEnum;
+use rustyline::{DefaultEditor, error::ReadlineError};
+
+use snowflake_connector_rs::{CsvOptions, ResultCursor, Session};
+
+use crate::split::StatementSplitter;
+
+/// How query results are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum OutputMode {
+    /// A bordered text table.
+    Table,
+    /// CSV with a header row.
+    Csv,
+    /// One JSON object per row (NDJSON).
+    Json,
+}
+
+const HELP: &str = "\
+Statements end with `;` and may span lines. Commands:
+  !source <file>           run the statements in <file> (alias: !load)
+  !output table|csv|json   change how results are printed
+  !help                    show this help
+  !quit                    exit (alias: !exit, Ctrl-D)
+Ctrl-C cancels the running query or clears the current input.";
+
+pub(crate) struct Shell {
+    session: Session,
+    output: OutputMode,
+    max_rows: Option<usize>,
+    max_width: usize,
+}
+
+impl Shell {
+    pub(crate) fn new(
+        session: Session,
+        output: OutputMode,
+        max_rows: Option<usize>,
+        max_width: usize,
+    ) -> Self {
+        Self {
+            session,
+            output,
+            max_rows,
+            max_width,
+        }
+    }
+
+    /// Run every statement in `text`, stopping at the first failure.
+    pub(crate) async fn run_script(&mut self, text: &str) -> Result<(), Box<dyn Error>> {
+        let mut splitter = StatementSplitter::new();
+        let mut statements = splitter.push(text);
+        statements.extend(splitter.finish());
+        for statement in statements {
+            self.execute(statement).await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn run_file(&mut self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        self.run_script(&text).await
+    }
+
+    /// Read statements and commands from the terminal until `!quit` or end of input.
+    pub(crate) async fn interactive(&mut self, prompt: &str) -> Result<(), Box<dyn Error>> {
+        let mut editor = DefaultEditor::new()?;
+        let history = history_file();
+        if let Some(path) = &history {
+            // A missing history file is expected on first use.
+            let _ = editor.load_history(path);
+        }
+
+        let continuation = format!("{:>1$} ", "->", prompt.len().saturating_sub(1));
+        let mut splitter = StatementSplitter::new();
+        loop {
+            let current_prompt = if splitter.is_empty() {
+                prompt
+            } else {
+                &continuation
+            };
+            let line = match tokio::task::block_in_place(|| editor.readline(current_prompt)) {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => {
+                    splitter.clear();
+                    continue;
+                }
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(e.into()),
+            };
+            if !line.trim().is_empty() {
+                let _ = editor.add_history_entry(line.as_str());
+            }
+
+            if splitter.is_empty() && line.trim_start().starts_with('!') {
+                match self.command(line.trim()).await {
+                    Ok(Flow::Continue) => {}
+                    Ok(Flow::Quit) => break,
+                    Err(e) => eprintln!("error: {e}"),
+                }
+                continue;
+            }
+
+            for statement in splitter.push(&format!("{line}\n")) {
+                if let Err(e) = self.execute(statement).await {
+                    eprintln!("error: {e}");
+                }
+            }
+        }
+
+        if let Some(path) = &history {
+            if let Some(dir) = path.parent() {
+                let _ = fs::create_dir_all(dir);
+            }
+            let _ = editor.save_history(path);
+        }
+        Ok(())
+    }
+
+    async fn command(&mut self, line: &str) -> Result<Flow, Box<dyn Error>> {
+        let line = line.trim_end_matches(';');
+        let (name, arg) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(name, arg)| (name, arg.trim()));
+        match name {
+            "!quit" | "!exit" | "!q" => return Ok(Flow::Quit),
+            "!help" => println!("{HELP}"),
+            "!source" | "!load" if !arg.is_empty() => self.run_file(Path::new(arg)).await?,
+            "!output" => {
+                self.output = OutputMode::from_str(arg, true).map_err(|_| {
+                    format!("unknown output mode `{arg}` (expected table, csv, or json)")
+                })?;
+            }
+            _ => return Err(format!("unknown command `{line}`; try !help").into()),
+        }
+        Ok(Flow::Continue)
+    }
+
+    /// Run one statement and print its result, cancelling it on Ctrl-C.
+    async fn execute(&mut self, statement: String) -> Result<(), Box<dyn Error>> {
+        let started = Instant::now();
+        let handle = self.session.query_handle(statement)?;
+        let canceller = handle.canceller();
+        let mut execution = pin!(handle.execute());
+        let cursor = tokio::select! {
+            result = &mut execution => result,
+            _ = tokio::signal::ctrl_c() => {
+                eprintln!("cancelling query {}", canceller.request_id());
+                canceller.cancel().await?;
+                execution.await
+            }
+        };
+        let cursor = match cursor {
+            Ok(cursor) => cursor,
+            Err(e) if e.is_cancelled() => return Err("query cancelled".into()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let query_id = cursor.query_id().to_string();
+        let rows = tokio::select! {
+            result = self.print(cursor) => result?,
+            _ = tokio::signal::ctrl_c() => return Err("output interrupted".into()),
+        };
+        eprintln!(
+            "{rows} row{} in {:.3}s (query {query_id})",
+            if rows == 1 { "" } else { "s" },
+            started.elapsed().as_secs_f64()
+        );
+        Ok(())
+    }
+
+    async fn print(&self, cursor: ResultCursor) -> Result<u64, Box<dyn Error>> {
+        let rows = match self.output {
+            OutputMode::Table => {
+                let table = cursor.collect_table().await?;
+                let mut pretty = table.pretty().with_max_column_width(self.max_width);
+                if let Some(max_rows) = self.max_rows {
+                    pretty = pretty.with_max_rows(max_rows);
+                }
+                let mut stdout = std::io::stdout().lock();
+                write!(stdout, "{pretty}")?;
+                stdout.flush()?;
+                table.row_count() as u64
+            }
+            OutputMode::Csv => {
+                cursor
+                    .write_csv(tokio::io::stdout(), CsvOptions::new())
+                    .await?
+            }
+            OutputMode::Json => cursor.write_ndjson(tokio::io::stdout()).await?,
+        };
+        Ok(rows)
+    }
+}
+
+enum Flow {
+    Continue,
+    Quit,
+}
+
+fn history_file() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".snowflake").join("snowsql-rs_history"))
+}
@@ -0,0 +1,182 @@
+/// Lexical context of the character being scanned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum State {
+    Code,
+    SingleQuoted,
+    DoubleQuoted,
+    DollarQuoted,
+    LineComment,
+    BlockComment,
+}
+
+/// Accumulates SQL text and splits it into statements on `;`.
+///
+/// Semicolons inside `'...'` / `"..."` literals, `$$...$$` blocks, and `--`, `//`, or `/* */` comments do not end a
+/// statement. Statements that contain nothing but whitespace and comments are dropped, since Snowflake rejects them.
+#[derive(Debug)]
+pub(crate) struct StatementSplitter {
+    buf: String,
+    scanned: usize,
+    state: State,
+    has_code: bool,
+}
+
+impl Default for StatementSplitter {
+    fn default() -> Self {
+        Self {
+            buf: String::new(),
+            scanned: 0,
+            state: State::Code,
+            has_code: false,
+        }
+    }
+}
+
+impl StatementSplitter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `text` and return every statement it completes, trimmed and without the trailing `;`.
+    pub(crate) fn push(&mut self, text: &str) -> Vec<String> {
+        self.buf.push_str(text);
+
+        let bytes = self.buf.as_bytes();
+        let mut statements = Vec::new();
+        let mut start = 0;
+        let mut i = self.scanned;
+        while i < bytes.len() {
+            let b = bytes[i];
+            let next = bytes.get(i + 1).copied();
+            match self.state {
+                State::Code => match (b, next) {
+                    (b';', _) => {
+                        if self.has_code {
+                            statements.push(self.buf[start..i].trim().to_string());
+                        }
+                        start = i + 1;
+                        self.has_code = false;
+                    }
+                    (b'\'', _) => {
+                        self.state = State::SingleQuoted;
+                        self.has_code = true;
+                    }
+                    (b'"', _) => {
+                        self.state = State::DoubleQuoted;
+                        self.has_code = true;
+                    }
+                    (b'$', Some(b'$')) => {
+                        self.state = State::DollarQuoted;
+                        self.has_code = true;
+                        i += 1;
+                    }
+                    (b'-', Some(b'-')) | (b'/', Some(b'/')) => {
+                        self.state = State::LineComment;
+                        i += 1;
+                    }
+                    (b'/', Some(b'*')) => {
+                        self.state = State::BlockComment;
+                        i += 1;
+                    }
+                    _ => self.has_code |= !b.is_ascii_whitespace(),
+                },
+                State::SingleQuoted => match b {
+                    b'\\' => i += 1,
+                    b'\'' => self.state = State::Code,
+                    _ => {}
+                },
+                State::DoubleQuoted => {
+                    if b == b'"' {
+                        self.state = State::Code;
+                    }
+                }
+                State::DollarQuoted => {
+                    if (b, next) == (b'$', Some(b'$')) {
+                        self.state = State::Code;
+                        i += 1;
+                    }
+                }
+                State::LineComment => {
+                    if b == b'\n' {
+                        self.state = State::Code;
+                    }
+                }
+                State::BlockComment => {
+                    if (b, next) == (b'*', Some(b'/')) {
+                        self.state = State::Code;
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        self.buf.drain(..start);
+        self.scanned = self.buf.len();
+        statements
+    }
+
+    /// Returns `true` when no partial statement is pending.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.buf.trim().is_empty()
+    }
+
+    /// Take the pending text as a final statement, as if it ended with `;`.
+    pub(crate) fn finish(&mut self) -> Option<String> {
+        let statement = self.has_code.then(|| self.buf.trim().to_string());
+        *self = Self::default();
+        statement
+    }
+
+    /// Discard the pending text.
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_semicolons_outside_literals_and_comments() {
+        let mut splitter = StatementSplitter::new();
+
+        let statements = splitter.push(
+            "SELECT 'a;b', \"c;d\" FROM t; -- trailing; comment\n\
+             CREATE FUNCTION f() RETURNS INT AS $$ 1; $$; /* x; */ SELECT 'it''s; fine';",
+        );
+
+        assert_eq!(
+            statements,
+            [
+                "SELECT 'a;b', \"c;d\" FROM t",
+                "-- trailing; comment\nCREATE FUNCTION f() RETURNS INT AS $$ 1; $$",
+                "/* x; */ SELECT 'it''s; fine'",
+            ]
+        );
+        assert!(splitter.is_empty());
+    }
+
+    #[test]
+    fn keeps_partial_statements_across_lines() {
+        let mut splitter = StatementSplitter::new();
+
+        assert!(splitter.push("SELECT 'one\n").is_empty());
+        assert!(!splitter.is_empty());
+        assert_eq!(splitter.push("two;' AS s;\n"), ["SELECT 'one\ntwo;' AS s"]);
+        assert!(splitter.push("SELECT 2\n").is_empty());
+        assert_eq!(splitter.finish().as_deref(), Some("SELECT 2"));
+        assert!(splitter.is_empty());
+    }
+
+    #[test]
+    fn drops_comment_only_and_empty_statements() {
+        let mut splitter = StatementSplitter::new();
+
+        assert!(splitter.push(";; -- nothing here;\n").is_empty());
+        assert_eq!(splitter.finish(), None);
+        assert!(splitter.push("/* just a comment */\n").is_empty());
+        assert_eq!(splitter.finish(), None);
+    }
+}
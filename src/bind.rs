@@ -2,6 +2,7 @@
 //!
 //! Most callers reach for one of the typed wrappers.
 //! [`RawBind`] is the escape hatch when none of those fit (e.g. binding a `DECFLOAT`).
+//! Implement [`ToBind`] to bind your own types directly.
 //!
 //! # Example
 //!
@@ -23,6 +24,6 @@
 //! ```
 
 pub use crate::statement::bind::{
-    Binary, BindName, BindType, Integer, IntoBind, IntoBindNullable, RawBind, Time, TimestampLtz,
-    TimestampNtz, TimestampTz,
+    Binary, BindName, BindType, BindValueRef, Integer, IntoBind, IntoBindNullable, RawBind, Time,
+    TimestampLtz, TimestampNtz, TimestampTz, ToBind,
 };
@@ -2,7 +2,9 @@ use std::{fmt, sync::Arc};
 
 use crate::{
     ApiContext, ClientConfig, ClientLoginConfig, QueryExecutionPolicy, Result, Session,
-    auth::login, runtime::QueryRuntime, session::SessionAuth,
+    auth::login,
+    runtime::QueryRuntime,
+    session::{SessionAuth, SessionParameters},
 };
 
 #[derive(Clone)]
@@ -44,11 +46,16 @@ impl Client {
         let prepared = config.prepare()?;
 
         let http = prepared.shared.http;
+        let observer = prepared.shared.observer;
         let shared = Arc::new(ClientShared {
-            api: Arc::new(ApiContext::new(http.clone(), prepared.shared.base_url)),
+            api: Arc::new(
+                ApiContext::new(http.clone(), prepared.shared.base_url)
+                    .with_retry_policy(prepared.shared.retry)
+                    .with_observer(observer.clone()),
+            ),
             chunk_http: http,
             query: prepared.shared.query,
-            runtime: QueryRuntime::new(),
+            runtime: QueryRuntime::new().with_observer(observer),
         });
 
         Ok(Self {
@@ -65,11 +72,18 @@ impl Client {
     ///
     /// Returns `ErrorKind::Config`, `ErrorKind::Auth`, `ErrorKind::Network`, `ErrorKind::Timeout`, `ErrorKind::Protocol`, or
     /// `ErrorKind::Internal` depending on how session establishment fails.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "snowflake.create_session", skip_all, err(Display))
+    )]
     pub async fn create_session(&self) -> Result<Session> {
-        let session_token = login(&self.inner.login, Arc::clone(&self.inner.shared.api)).await?;
+        let login = login(&self.inner.login, Arc::clone(&self.inner.shared.api)).await?;
         Ok(Session {
             shared: Arc::clone(&self.inner.shared),
-            auth: Arc::new(SessionAuth { session_token }),
+            auth: Arc::new(SessionAuth {
+                session_token: login.token,
+            }),
+            parameters: Arc::new(SessionParameters::new(login.timezone)),
         })
     }
 }
@@ -2,7 +2,27 @@ use std::{collections::HashMap, fmt, num::NonZeroUsize, time::Duration};
 
 use url::Url;
 
-use crate::{AuthConfig, Result, error::ConfigError, session::QueryOptions};
+use crate::{
+    AuthConfig, Result,
+    error::ConfigError,
+    observer::{ObserverHandle, QueryObserver},
+    session::QueryOptions,
+};
+
+mod retry;
+#[cfg(feature = "ocsp")]
+mod revocation;
+mod tls;
+
+pub use retry::RetryPolicy;
+pub(crate) use retry::{
+    is_retryable_request_error, is_retryable_status, retry_after, send_request,
+};
+pub(crate) use tls::TlsSettings;
+pub use tls::{ClientIdentity, TlsVersion};
+
+#[cfg(feature = "ocsp")]
+pub use revocation::{RevocationConfig, RevocationMode};
 
 /// Top-level configuration for a [`Client`](crate::Client).
 #[derive(Clone, Debug)]
@@ -14,6 +34,7 @@ pub struct ClientConfig {
     query: QueryConfig,
     endpoint: EndpointConfig,
     transport: TransportConfig,
+    observer: ObserverHandle,
 }
 
 /// Server-side session context sent to Snowflake at login time.
@@ -71,10 +92,13 @@ pub enum EndpointConfig {
 
 /// HTTP transport-layer options.
 ///
-/// Configures how requests are physically delivered to Snowflake, independent of which endpoint they target.
+/// Configures how requests are physically delivered to Snowflake, independent of which endpoint they target. The same
+/// settings apply to Snowflake API requests and to result chunk downloads from cloud storage.
 #[derive(Default, Clone, Debug)]
 pub struct TransportConfig {
     proxy: Option<ProxyConfig>,
+    tls: TlsSettings,
+    retry: RetryPolicy,
 }
 
 /// Configuration for an HTTP proxy used by [`TransportConfig`].
@@ -115,6 +139,7 @@ impl ClientConfig {
             query: QueryConfig::default(),
             endpoint: EndpointConfig::default(),
             transport: TransportConfig::default(),
+            observer: ObserverHandle::default(),
         }
     }
 
@@ -138,6 +163,12 @@ impl ClientConfig {
         self
     }
 
+    /// Registers a [`QueryObserver`] that receives query-lifecycle events from every session of the client.
+    pub fn with_observer(mut self, observer: impl QueryObserver) -> Self {
+        self.observer = ObserverHandle::new(observer);
+        self
+    }
+
     /// Compile this public builder input into the internal model held by `Client`.
     ///
     /// # Errors
@@ -157,6 +188,8 @@ impl ClientConfig {
             shared: PreparedClientShared {
                 http,
                 base_url,
+                retry: self.transport.retry,
+                observer: self.observer,
                 query: self.query.into(),
             },
         })
@@ -260,6 +293,8 @@ pub(crate) struct PreparedClientConfig {
 pub(crate) struct PreparedClientShared {
     pub(crate) http: reqwest::Client,
     pub(crate) base_url: Url,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) observer: ObserverHandle,
     pub(crate) query: QueryExecutionPolicy,
 }
 
@@ -429,8 +464,56 @@ impl TransportConfig {
         self
     }
 
+    /// Sets how transient HTTP failures are retried. See [`RetryPolicy`] for the defaults.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Trusts the certificates in `pem` in addition to the platform's root store.
+    ///
+    /// `pem` may contain several certificates. Use this when TLS is intercepted by a corporate proxy with a private
+    /// CA, or when a test server presents a self-signed certificate. May be called more than once.
+    pub fn with_root_certificates(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.tls.root_certificates_pem.push(pem.into());
+        self
+    }
+
+    /// Presents `identity` when the server requests a client certificate.
+    pub fn with_client_identity(mut self, identity: ClientIdentity) -> Self {
+        self.tls.client_identity = Some(identity);
+        self
+    }
+
+    /// Refuses to negotiate TLS versions older than `version`. Defaults to TLS 1.2.
+    pub fn with_tls_min_version(mut self, version: TlsVersion) -> Self {
+        self.tls.min_version = Some(version);
+        self
+    }
+
+    /// Pins server public keys by the SHA-256 digest of their DER-encoded `SubjectPublicKeyInfo`.
+    ///
+    /// Pinning is applied on top of regular certificate verification: the chain must still be trusted, and at least
+    /// one certificate in it must carry a pinned key. Pins accumulate across calls.
+    pub fn with_spki_pins(mut self, pins: impl IntoIterator<Item = [u8; 32]>) -> Self {
+        self.tls.spki_pins.extend(pins);
+        self
+    }
+
+    /// Checks server certificates for revocation over OCSP. See [`RevocationConfig`].
+    #[cfg(feature = "ocsp")]
+    pub fn with_revocation_check(mut self, revocation: RevocationConfig) -> Self {
+        self.tls.revocation = Some(revocation);
+        self
+    }
+
     pub(crate) fn build_http_client(&self) -> Result<reqwest::Client> {
-        let builder = reqwest::ClientBuilder::new().gzip(true).use_rustls_tls();
+        let builder = reqwest::ClientBuilder::new().gzip(true);
+        let builder = if self.tls.is_customized() {
+            builder.tls_backend_preconfigured(self.tls.build_rustls_config(self.proxy.as_ref())?)
+        } else {
+            builder.use_rustls_tls()
+        };
 
         // Disable idle connection pooling: S3 (used for query-result chunks via presigned URLs) closes idle keep-alive
         // connections aggressively, and a reused-but-closed connection surfaces as hyper `Error(IncompleteMessage)`.
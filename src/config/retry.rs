@@ -0,0 +1,267 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use http::{HeaderMap, StatusCode, header::RETRY_AFTER};
+
+const DEFAULT_MAX_RETRIES: u32 = 7;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(16);
+const DEFAULT_TOTAL_BUDGET: Duration = Duration::from_secs(300);
+
+/// Retry behavior for transient HTTP failures, set with
+/// [`TransportConfig::with_retry_policy`](crate::TransportConfig::with_retry_policy).
+///
+/// Applies to login, statement submit, result polling, query abort, and result chunk downloads. A failure is
+/// transient when the server answers 408, 429, or 5xx, or when the connection fails before a response arrives.
+/// Statement submits are retried with the original `requestId` and a `retry=true` marker, so Snowflake executes the
+/// statement at most once.
+///
+/// The delay before retry `n` (starting at 0) is `initial_backoff * 2^n`, capped at `max_backoff`. With jitter
+/// enabled, a random value between half and all of that delay is used instead. A `Retry-After` response header
+/// replaces the computed delay. No retry is attempted once the time since the first attempt plus the next delay
+/// would exceed the total budget; per-request deadlines such as the query response timeout still apply.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    total_budget: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            total_budget: DEFAULT_TOTAL_BUDGET,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The default policy: up to 7 retries, backoff from 1s doubling to 16s with jitter, within a 300s budget.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A policy that never retries.
+    pub fn disabled() -> Self {
+        Self::default().with_max_retries(0)
+    }
+
+    /// Sets the maximum number of retries after the first attempt. Defaults to `7`.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry. Defaults to `1s`.
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the upper bound for the exponential backoff. Defaults to `16s`.
+    pub fn with_max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Sets the time budget across all attempts of one request, measured from the first attempt. Defaults to
+    /// `300s`.
+    pub fn with_total_budget(mut self, budget: Duration) -> Self {
+        self.total_budget = budget;
+        self
+    }
+
+    /// Enables or disables randomizing backoff delays. Enabled by default so that many clients recovering from the
+    /// same outage do not retry in lockstep.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Start tracking retries for one logical request.
+    pub(crate) fn start(&self) -> RetryState<'_> {
+        RetryState {
+            policy: self,
+            retries: 0,
+            started_at: Instant::now(),
+        }
+    }
+
+    fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1_u32.checked_shl(retry).unwrap_or(u32::MAX);
+        let delay = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if self.jitter {
+            let half = delay / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            delay
+        }
+    }
+}
+
+/// Retry bookkeeping for one logical request under a [`RetryPolicy`].
+pub(crate) struct RetryState<'a> {
+    policy: &'a RetryPolicy,
+    retries: u32,
+    started_at: Instant,
+}
+
+impl RetryState<'_> {
+    /// The delay before the next attempt, or `None` when the policy allows no further retry.
+    ///
+    /// `retry_after` is the server-requested delay, if any, and takes precedence over the computed backoff.
+    pub(crate) fn next_delay(&mut self, retry_after: Option<Duration>) -> Option<Duration> {
+        if self.retries >= self.policy.max_retries {
+            return None;
+        }
+        let delay = retry_after.unwrap_or_else(|| self.policy.backoff(self.retries));
+        if self.started_at.elapsed().saturating_add(delay) > self.policy.total_budget {
+            return None;
+        }
+        self.retries += 1;
+        Some(delay)
+    }
+
+    /// The number of retries granted so far.
+    pub(crate) fn retries(&self) -> u32 {
+        self.retries
+    }
+}
+
+/// Whether an HTTP status indicates a transient failure worth retrying.
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error()
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || status == StatusCode::REQUEST_TIMEOUT
+}
+
+/// Whether a request error happened before a usable response arrived and the request may be sent again.
+///
+/// Timeouts are excluded: they already consumed the request's time budget, and the caller reports them as such.
+pub(crate) fn is_retryable_request_error(error: &reqwest::Error) -> bool {
+    !error.is_timeout() && (error.is_connect() || error.is_request() || error.is_body())
+}
+
+/// Send `request`, sending it once more when a fail-closed revocation check rejected the handshake only because the
+/// OCSP responder was still being queried and that query has since finished.
+///
+/// This resend does not count against the [`RetryPolicy`]: it completes the connection attempt the policy sees as one.
+pub(crate) async fn send_request(
+    request: reqwest::RequestBuilder,
+) -> reqwest::Result<reqwest::Response> {
+    #[cfg(feature = "ocsp")]
+    if let Some(resend) = request.try_clone() {
+        return match request.send().await {
+            Err(error) if super::revocation::wait_for_pending_check(&error).await => {
+                resend.send().await
+            }
+            result => result,
+        };
+    }
+    request.send().await
+}
+
+/// The delay requested by a `Retry-After` header, given either in seconds or as an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = httpdate::parse_http_date(value).ok()?;
+    Some(at.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    fn delays(policy: &RetryPolicy) -> Vec<Duration> {
+        let mut state = policy.start();
+        std::iter::from_fn(|| state.next_delay(None)).collect()
+    }
+
+    #[test]
+    fn backoff_doubles_then_caps_without_jitter() {
+        assert_eq!(
+            delays(&RetryPolicy::new().with_jitter(false)),
+            [1, 2, 4, 8, 16, 16, 16].map(Duration::from_secs)
+        );
+    }
+
+    #[test]
+    fn jittered_backoff_stays_within_half_to_full_delay() {
+        let policy = RetryPolicy::new();
+        for (retry, delay) in delays(&policy).into_iter().enumerate() {
+            let full = RetryPolicy::new().with_jitter(false).backoff(retry as u32);
+            assert!(delay >= full / 2 && delay <= full, "{delay:?} vs {full:?}");
+        }
+    }
+
+    #[test]
+    fn disabled_policy_never_retries() {
+        assert!(RetryPolicy::disabled().start().next_delay(None).is_none());
+    }
+
+    #[test]
+    fn total_budget_stops_retries_before_it_is_exceeded() {
+        let policy = RetryPolicy::new()
+            .with_jitter(false)
+            .with_total_budget(Duration::from_secs(10));
+        assert_eq!(delays(&policy), [1, 2, 4, 8].map(Duration::from_secs));
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff_and_counts_as_retry() {
+        let policy = RetryPolicy::new().with_jitter(false).with_max_retries(1);
+        let mut state = policy.start();
+        assert_eq!(
+            state.next_delay(Some(Duration::from_secs(3))),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(state.retries(), 1);
+        assert_eq!(state.next_delay(None), None);
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_http_dates() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("5"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(5)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn retryable_status_codes() {
+        assert!(is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_retryable_status(StatusCode::GATEWAY_TIMEOUT));
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::REQUEST_TIMEOUT));
+
+        assert!(!is_retryable_status(StatusCode::OK));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
+        assert!(!is_retryable_status(StatusCode::FORBIDDEN));
+    }
+}
@@ -0,0 +1,666 @@
+use std::{
+    error::Error as StdError,
+    fmt, io,
+    path::PathBuf,
+    sync::{Arc, OnceLock},
+    time::{Duration, SystemTime},
+};
+
+use der::{Decode as _, Encode as _};
+use rustls::{
+    CertificateError, DigitallySignedStruct, OtherError, SignatureScheme,
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    crypto::CryptoProvider,
+    pki_types::{CertificateDer, ServerName, UnixTime},
+};
+use tokio::runtime::Handle;
+use url::Url;
+use x509_cert::Certificate;
+
+mod cache;
+mod fetch;
+mod ocsp;
+
+use cache::ResponseCache;
+use ocsp::{CertId, Status};
+
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How certificate revocation is checked during the TLS handshake.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RevocationMode {
+    /// No revocation check; only the regular chain verification applies.
+    #[default]
+    Disabled,
+    /// Reject revoked certificates, but accept a certificate whose status cannot be determined (responder
+    /// unreachable, no responder URL, invalid response, or a responder query still in flight).
+    FailOpen,
+    /// Reject revoked certificates and certificates whose status cannot be determined.
+    FailClosed,
+}
+
+/// OCSP revocation checking for [`TransportConfig`](crate::TransportConfig).
+///
+/// After the regular chain verification succeeds, each certificate in the server's chain whose issuer is also
+/// available (sent by the server or added with
+/// [`TransportConfig::with_root_certificates`](crate::TransportConfig::with_root_certificates)) is checked against
+/// a stapled response or the response cache. The end-entity certificate must always be checkable.
+///
+/// The handshake never waits for a responder. When neither a stapled nor a cached response is available, the
+/// responder named in the certificate's Authority Information Access extension is queried in the background through
+/// the transport's proxy, and the handshake proceeds with the status undetermined. In
+/// [`FailClosed`](RevocationMode::FailClosed) mode the connector then waits for that query, for at most the
+/// [request timeout](Self::with_request_timeout), and sends the request once more on a new connection. This resend is
+/// independent of the transport's [`RetryPolicy`](crate::RetryPolicy).
+///
+/// Responses are cached in memory for as long as they are valid, shared by every client built from this
+/// configuration or its clones. With [`with_cache_path`](Self::with_cache_path) they are also persisted, so later
+/// processes can skip the responder.
+#[derive(Clone, Debug)]
+pub struct RevocationConfig {
+    mode: RevocationMode,
+    cache_path: Option<PathBuf>,
+    responder_url: Option<Url>,
+    request_timeout: Duration,
+    cache: Arc<OnceLock<Arc<ResponseCache>>>,
+}
+
+impl RevocationConfig {
+    /// A configuration checking revocation in `mode`, without a persistent cache or responder override.
+    pub fn new(mode: RevocationMode) -> Self {
+        Self {
+            mode,
+            cache_path: None,
+            responder_url: None,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Persists OCSP responses to a JSON file at `path`, creating parent directories as needed.
+    pub fn with_cache_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_path = Some(path.into());
+        self.cache = Arc::default();
+        self
+    }
+
+    /// Sends every OCSP request to `url` instead of the responder named in the certificate.
+    ///
+    /// Useful with a local responder, or a certificate authority whose certificates lack an OCSP URL.
+    pub fn with_responder_url(mut self, url: Url) -> Self {
+        self.responder_url = Some(url);
+        self
+    }
+
+    /// Bounds each request to the OCSP responder, from connecting to reading the response. Defaults to 10 seconds.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.mode != RevocationMode::Disabled
+    }
+
+    /// Wrap `inner` so that a successful chain verification is followed by the revocation check.
+    ///
+    /// `http` is used to query responders; it must not itself check revocation.
+    pub(crate) fn wrap_verifier(
+        &self,
+        inner: Arc<dyn ServerCertVerifier>,
+        extra_roots: &[CertificateDer<'_>],
+        provider: Arc<CryptoProvider>,
+        http: reqwest::Client,
+    ) -> Arc<dyn ServerCertVerifier> {
+        if !self.is_enabled() {
+            return inner;
+        }
+        let cache = self
+            .cache
+            .get_or_init(|| Arc::new(ResponseCache::load(self.cache_path.clone())));
+        Arc::new(RevocationCheckingVerifier {
+            inner,
+            fail_closed: self.mode == RevocationMode::FailClosed,
+            responder_url: self.responder_url.clone(),
+            request_timeout: self.request_timeout,
+            extra_issuers: extra_roots
+                .iter()
+                .filter_map(|root| Certificate::from_der(root).ok())
+                .collect(),
+            provider,
+            cache: Arc::clone(cache),
+            http,
+        })
+    }
+}
+
+enum Outcome {
+    Good,
+    Revoked,
+    Undetermined,
+    /// Undetermined while the responder is queried for the listed `CertID`s.
+    Pending(Vec<Vec<u8>>),
+}
+
+#[derive(Debug)]
+struct RevocationCheckingVerifier {
+    inner: Arc<dyn ServerCertVerifier>,
+    fail_closed: bool,
+    responder_url: Option<Url>,
+    request_timeout: Duration,
+    extra_issuers: Vec<Certificate>,
+    provider: Arc<CryptoProvider>,
+    cache: Arc<ResponseCache>,
+    http: reqwest::Client,
+}
+
+impl RevocationCheckingVerifier {
+    fn check_chain(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        stapled: &[u8],
+        now: UnixTime,
+    ) -> Outcome {
+        let Ok(chain) = std::iter::once(end_entity)
+            .chain(intermediates)
+            .map(|cert| Certificate::from_der(cert))
+            .collect::<der::Result<Vec<_>>>()
+        else {
+            return Outcome::Undetermined;
+        };
+        let now = Duration::from_secs(now.as_secs());
+
+        let mut undetermined = false;
+        let mut pending = Vec::new();
+        for (index, cert) in chain.iter().enumerate() {
+            let tbs = &cert.tbs_certificate;
+            if tbs.issuer == tbs.subject {
+                continue;
+            }
+            let issuer = chain[index + 1..]
+                .iter()
+                .chain(&self.extra_issuers)
+                .find(|candidate| candidate.tbs_certificate.subject == tbs.issuer);
+            let outcome = match issuer {
+                Some(issuer) => {
+                    let stapled = if index == 0 { stapled } else { &[] };
+                    self.check(cert, issuer, stapled, now)
+                }
+                // Intermediates issued by a platform root cannot be checked without that root.
+                None if index > 0 => continue,
+                None => Outcome::Undetermined,
+            };
+            match outcome {
+                Outcome::Good => {}
+                Outcome::Revoked => return Outcome::Revoked,
+                Outcome::Undetermined => undetermined = true,
+                Outcome::Pending(cert_ids) => pending.extend(cert_ids),
+            }
+        }
+        if undetermined {
+            Outcome::Undetermined
+        } else if !pending.is_empty() {
+            Outcome::Pending(pending)
+        } else {
+            Outcome::Good
+        }
+    }
+
+    fn check(
+        &self,
+        cert: &Certificate,
+        issuer: &Certificate,
+        stapled: &[u8],
+        now: Duration,
+    ) -> Outcome {
+        let Ok(cert_id) = CertId::new(cert, issuer) else {
+            return Outcome::Undetermined;
+        };
+        let Ok(key) = cert_id.to_der() else {
+            return Outcome::Undetermined;
+        };
+        let validate = |response: &[u8]| {
+            ocsp::validate_response(response, &cert_id, issuer, &self.provider, now)
+        };
+
+        if !stapled.is_empty() {
+            match validate(stapled) {
+                Ok(Status::Good) => return Outcome::Good,
+                Ok(Status::Revoked) => return Outcome::Revoked,
+                Ok(Status::Unknown) | Err(_) => {}
+            }
+        }
+        if let Some(cached) = self.cache.get(&key) {
+            match validate(&cached) {
+                Ok(Status::Good) => return Outcome::Good,
+                Ok(Status::Revoked) => return Outcome::Revoked,
+                Ok(Status::Unknown) | Err(_) => self.cache.remove(&key),
+            }
+        }
+
+        let url = match &self.responder_url {
+            Some(url) => url.clone(),
+            None => match ocsp::responder_url(cert).and_then(|url| Url::parse(&url).ok()) {
+                Some(url) => url,
+                None => return Outcome::Undetermined,
+            },
+        };
+        if self.spawn_fetch(&key, cert_id, issuer.clone(), url) {
+            Outcome::Pending(vec![key])
+        } else {
+            Outcome::Undetermined
+        }
+    }
+
+    /// Query the responder on the current runtime and cache a usable answer for later handshakes. Returns whether a
+    /// query for `key` is now in flight, started here or by an earlier handshake.
+    ///
+    /// Without a runtime (a handshake driven outside tokio) nothing is fetched and the status stays undetermined.
+    fn spawn_fetch(&self, key: &[u8], cert_id: CertId, issuer: Certificate, url: Url) -> bool {
+        let Ok(runtime) = Handle::try_current() else {
+            return false;
+        };
+        let Ok(request) = ocsp::encode_request(&cert_id) else {
+            return false;
+        };
+        if !self.cache.start_fetch(key) {
+            return true;
+        }
+        let pending = PendingFetch {
+            cache: Arc::clone(&self.cache),
+            key: key.to_vec(),
+        };
+        let http = self.http.clone();
+        let provider = Arc::clone(&self.provider);
+        let timeout = self.request_timeout;
+        runtime.spawn(async move {
+            let Ok(response) = fetch::post_ocsp_request(&http, url, request, timeout).await else {
+                return;
+            };
+            let now = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default();
+            if let Ok(Status::Good | Status::Revoked) =
+                ocsp::validate_response(&response, &cert_id, &issuer, &provider, now)
+            {
+                pending.cache.insert(pending.key.clone(), response);
+            }
+        });
+        true
+    }
+}
+
+/// The error a fail-closed handshake is rejected with while the responder is still being queried, so that the
+/// request can be sent again once the answer is cached. See [`wait_for_pending_check`].
+#[derive(Clone, Debug)]
+struct PendingRevocationCheck {
+    cache: Arc<ResponseCache>,
+    cert_ids: Vec<Vec<u8>>,
+    timeout: Duration,
+}
+
+impl fmt::Display for PendingRevocationCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("certificate revocation status is unknown while the OCSP responder is queried")
+    }
+}
+
+impl StdError for PendingRevocationCheck {}
+
+impl PendingRevocationCheck {
+    /// The pending check a handshake failed with, found anywhere in `error`'s source chain.
+    fn find(error: &reqwest::Error) -> Option<Self> {
+        let mut source: Option<&(dyn StdError + 'static)> = Some(error);
+        while let Some(error) = source {
+            if let Some(rustls::Error::InvalidCertificate(CertificateError::Other(OtherError(
+                other,
+            )))) = error.downcast_ref::<rustls::Error>()
+                && let Some(pending) = other.downcast_ref::<Self>()
+            {
+                return Some(pending.clone());
+            }
+            // The connector nests the rustls error in `io::Error`s, whose `source` skips the error they wrap.
+            source = match error.downcast_ref::<io::Error>() {
+                Some(io) => io.get_ref().map(|inner| inner as &(dyn StdError + 'static)),
+                None => error.source(),
+            };
+        }
+        None
+    }
+}
+
+/// Wait for the responder queries a fail-closed handshake behind `error` was rejected for, for at most the configured
+/// request timeout. Returns `true` when they finished, so that the request is worth sending once more.
+pub(crate) async fn wait_for_pending_check(error: &reqwest::Error) -> bool {
+    let Some(pending) = PendingRevocationCheck::find(error) else {
+        return false;
+    };
+    pending
+        .cache
+        .wait_for_fetches(&pending.cert_ids, pending.timeout)
+        .await
+}
+
+/// Clears the in-flight mark when a fetch task finishes or is dropped with its runtime.
+struct PendingFetch {
+    cache: Arc<ResponseCache>,
+    key: Vec<u8>,
+}
+
+impl Drop for PendingFetch {
+    fn drop(&mut self) {
+        self.cache.finish_fetch(&self.key);
+    }
+}
+
+impl ServerCertVerifier for RevocationCheckingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        )?;
+
+        match self.check_chain(end_entity, intermediates, ocsp_response, now) {
+            Outcome::Good => Ok(verified),
+            Outcome::Revoked => Err(rustls::Error::InvalidCertificate(CertificateError::Revoked)),
+            Outcome::Pending(cert_ids) if self.fail_closed => {
+                Err(rustls::Error::InvalidCertificate(CertificateError::Other(
+                    OtherError(Arc::new(PendingRevocationCheck {
+                        cache: Arc::clone(&self.cache),
+                        cert_ids,
+                        timeout: self.request_timeout,
+                    })),
+                )))
+            }
+            Outcome::Undetermined if self.fail_closed => Err(rustls::Error::InvalidCertificate(
+                CertificateError::UnknownRevocationStatus,
+            )),
+            Outcome::Undetermined | Outcome::Pending(_) => Ok(verified),
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read as _, Write as _},
+        net::TcpListener,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::SystemTime,
+    };
+
+    use super::*;
+    use crate::{
+        TransportConfig,
+        config::send_request,
+        test_support::tls::{
+            CA_KEY, CA_PEM, SERVER_PEM, cert_der, https_get, spawn_https_server,
+            spawn_https_server_for,
+        },
+    };
+
+    fn server_cert_id() -> CertId {
+        CertId::new(
+            &Certificate::from_der(&cert_der(SERVER_PEM)).unwrap(),
+            &Certificate::from_der(&cert_der(CA_PEM)).unwrap(),
+        )
+        .unwrap()
+    }
+
+    /// Serve the same signed response for the fixture server certificate to every request, counting requests.
+    fn spawn_ocsp_responder(status: Status) -> (Url, Arc<AtomicUsize>) {
+        let cert_id = server_cert_id();
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let body = ocsp::signed_test_response(&cert_id, status, now - day, Some(now + day), CA_KEY);
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut request = Vec::new();
+                let mut buf = [0_u8; 1024];
+                while let Ok(n @ 1..) = stream.read(&mut buf) {
+                    request.extend_from_slice(&buf[..n]);
+                    if request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        break;
+                    }
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                let head = format!(
+                    "HTTP/1.0 200 OK\r\ncontent-type: application/ocsp-response\r\ncontent-length: {}\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.write_all(&body);
+            }
+        });
+        (url, requests)
+    }
+
+    /// A URL nothing listens on.
+    fn unreachable_responder() -> Url {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        drop(listener);
+        url
+    }
+
+    fn revocation(mode: RevocationMode, responder: Url) -> RevocationConfig {
+        RevocationConfig::new(mode).with_responder_url(responder)
+    }
+
+    fn transport(revocation: &RevocationConfig) -> TransportConfig {
+        TransportConfig::new()
+            .with_root_certificates(CA_PEM)
+            .with_revocation_check(revocation.clone())
+    }
+
+    /// Wait for the responder queries started by handshakes under `revocation` to finish.
+    async fn fetches_settled(revocation: &RevocationConfig) {
+        while revocation
+            .cache
+            .get()
+            .is_some_and(|cache| cache.is_fetching())
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[test]
+    fn disabled_mode_keeps_default_tls_setup() {
+        let transport = TransportConfig::new()
+            .with_revocation_check(RevocationConfig::new(RevocationMode::Disabled));
+        assert!(!transport.tls.is_customized());
+    }
+
+    #[tokio::test]
+    async fn good_status_is_accepted_when_fail_closed_once_fetched() {
+        let (responder, requests) = spawn_ocsp_responder(Status::Good);
+        let revocation = revocation(RevocationMode::FailClosed, responder);
+
+        // The handshake does not wait for the responder, so a plain send on the first connection has no status yet.
+        let (addr, server) = spawn_https_server(false).await;
+        let err = https_get(transport(&revocation), addr).await.unwrap_err();
+        assert!(err.is_connect(), "{err:?}");
+        server.await.unwrap();
+        fetches_settled(&revocation).await;
+
+        // A separately built client reuses the response fetched on behalf of the first one.
+        let (addr, server) = spawn_https_server(false).await;
+        let body = https_get(transport(&revocation), addr).await.unwrap();
+        assert_eq!(body, "ok");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn fail_closed_request_waits_for_the_first_fetch_and_is_sent_again() {
+        let (responder, requests) = spawn_ocsp_responder(Status::Good);
+        let revocation = revocation(RevocationMode::FailClosed, responder);
+        let client = transport(&revocation)
+            .with_retry_policy(crate::RetryPolicy::disabled())
+            .build_http_client()
+            .unwrap();
+
+        let (addr, server) = spawn_https_server_for(false, 2).await;
+        let url = Url::parse(&format!("https://localhost:{}/", addr.port())).unwrap();
+        let response = send_request(client.get(url)).await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "ok");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn fail_closed_request_is_not_sent_again_when_the_fetch_fails() {
+        let revocation = revocation(RevocationMode::FailClosed, unreachable_responder());
+        let client = transport(&revocation).build_http_client().unwrap();
+
+        let (addr, server) = spawn_https_server_for(false, 2).await;
+        let url = Url::parse(&format!("https://localhost:{}/", addr.port())).unwrap();
+        let err = send_request(client.get(url)).await.unwrap_err();
+        assert!(err.is_connect(), "{err:?}");
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn revoked_status_is_rejected_when_fail_open() {
+        let (responder, _) = spawn_ocsp_responder(Status::Revoked);
+        let revocation = revocation(RevocationMode::FailOpen, responder);
+
+        let (addr, server) = spawn_https_server(false).await;
+        let body = https_get(transport(&revocation), addr).await.unwrap();
+        assert_eq!(body, "ok");
+        server.await.unwrap();
+        fetches_settled(&revocation).await;
+
+        let (addr, server) = spawn_https_server(false).await;
+        let err = https_get(transport(&revocation), addr).await.unwrap_err();
+        assert!(err.is_connect(), "{err:?}");
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn unknown_status_follows_mode() {
+        let (responder, requests) = spawn_ocsp_responder(Status::Unknown);
+        let fail_closed = revocation(RevocationMode::FailClosed, responder.clone());
+        for _ in 0..2 {
+            let (addr, server) = spawn_https_server(false).await;
+            let err = https_get(transport(&fail_closed), addr).await.unwrap_err();
+            assert!(err.is_connect(), "{err:?}");
+            server.await.unwrap();
+            fetches_settled(&fail_closed).await;
+        }
+        // An unknown status is not cached, so every handshake asks again.
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        let (addr, server) = spawn_https_server(false).await;
+        let fail_open = revocation(RevocationMode::FailOpen, responder);
+        let body = https_get(transport(&fail_open), addr).await.unwrap();
+        assert_eq!(body, "ok");
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn unreachable_responder_follows_mode() {
+        let (addr, server) = spawn_https_server(false).await;
+        let fail_open = revocation(RevocationMode::FailOpen, unreachable_responder());
+        let body = https_get(transport(&fail_open), addr).await.unwrap();
+        assert_eq!(body, "ok");
+        server.await.unwrap();
+
+        let fail_closed = revocation(RevocationMode::FailClosed, unreachable_responder());
+        for _ in 0..2 {
+            let (addr, server) = spawn_https_server(false).await;
+            let err = https_get(transport(&fail_closed), addr).await.unwrap_err();
+            assert!(err.is_connect(), "{err:?}");
+            server.await.unwrap();
+            fetches_settled(&fail_closed).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn responder_is_queried_through_the_proxy() {
+        // The stand-in responder answers any request, including the absolute-form one a proxy receives.
+        let (proxy, requests) = spawn_ocsp_responder(Status::Good);
+        let revocation = revocation(RevocationMode::FailClosed, unreachable_responder());
+        let http = transport(&revocation)
+            .tls
+            .responder_client(Some(&crate::ProxyConfig::new(proxy)))
+            .unwrap();
+        let request = ocsp::encode_request(&server_cert_id()).unwrap();
+        let response = fetch::post_ocsp_request(
+            &http,
+            unreachable_responder(),
+            request,
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+        assert!(!response.is_empty());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn persisted_response_is_reused_while_responder_is_down() {
+        let cache_path = std::env::temp_dir().join(format!(
+            "snowflake-connector-rs-ocsp-{}.json",
+            uuid::Uuid::new_v4()
+        ));
+        let (responder, requests) = spawn_ocsp_responder(Status::Good);
+        let revocation = RevocationConfig::new(RevocationMode::FailOpen)
+            .with_responder_url(responder)
+            .with_cache_path(&cache_path);
+        let (addr, server) = spawn_https_server(false).await;
+        assert_eq!(https_get(transport(&revocation), addr).await.unwrap(), "ok");
+        server.await.unwrap();
+        fetches_settled(&revocation).await;
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let (addr, server) = spawn_https_server(false).await;
+        let revocation = RevocationConfig::new(RevocationMode::FailClosed)
+            .with_responder_url(unreachable_responder())
+            .with_cache_path(&cache_path);
+        assert_eq!(https_get(transport(&revocation), addr).await.unwrap(), "ok");
+        server.await.unwrap();
+        std::fs::remove_file(cache_path).unwrap();
+    }
+}
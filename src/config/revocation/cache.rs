@@ -0,0 +1,190 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+use tokio::sync::Notify;
+
+/// OCSP responses keyed by the DER-encoded `CertID` they answer, optionally mirrored to a JSON file.
+///
+/// Entries are stored as received and re-validated on every lookup, so a stale or tampered file can only cause a
+/// refetch, never a false "good". The cache also tracks which `CertID`s have a responder fetch in flight, so
+/// concurrent handshakes for the same certificate query the responder once.
+#[derive(Debug)]
+pub(super) struct ResponseCache {
+    entries: Mutex<HashMap<Vec<u8>, Vec<u8>>>,
+    fetching: Mutex<HashSet<Vec<u8>>>,
+    fetch_finished: Notify,
+    path: Option<PathBuf>,
+}
+
+impl ResponseCache {
+    /// Start from the contents of `path` when given. A missing or unreadable file yields an empty cache.
+    pub(super) fn load(path: Option<PathBuf>) -> Self {
+        let entries = path
+            .as_deref()
+            .and_then(|path| read_entries(path).ok())
+            .unwrap_or_default();
+        Self {
+            entries: Mutex::new(entries),
+            fetching: Mutex::default(),
+            fetch_finished: Notify::new(),
+            path,
+        }
+    }
+
+    pub(super) fn get(&self, cert_id: &[u8]) -> Option<Vec<u8>> {
+        self.lock().get(cert_id).cloned()
+    }
+
+    pub(super) fn insert(&self, cert_id: Vec<u8>, response: Vec<u8>) {
+        let mut entries = self.lock();
+        entries.insert(cert_id, response);
+        self.persist(&entries);
+    }
+
+    pub(super) fn remove(&self, cert_id: &[u8]) {
+        let mut entries = self.lock();
+        if entries.remove(cert_id).is_some() {
+            self.persist(&entries);
+        }
+    }
+
+    /// Mark a fetch for `cert_id` as started. Returns `false` when one is already in flight.
+    pub(super) fn start_fetch(&self, cert_id: &[u8]) -> bool {
+        self.fetching_lock().insert(cert_id.to_vec())
+    }
+
+    pub(super) fn finish_fetch(&self, cert_id: &[u8]) {
+        self.fetching_lock().remove(cert_id);
+        self.fetch_finished.notify_waiters();
+    }
+
+    /// Wait up to `timeout` for the fetches of `cert_ids` to finish. Returns `false` when the timeout elapsed first.
+    pub(super) async fn wait_for_fetches(&self, cert_ids: &[Vec<u8>], timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, async {
+            loop {
+                let mut finished = std::pin::pin!(self.fetch_finished.notified());
+                // Register before checking, so a fetch finishing in between still wakes this task.
+                finished.as_mut().enable();
+                if cert_ids.iter().all(|id| !self.fetching_lock().contains(id)) {
+                    return;
+                }
+                finished.await;
+            }
+        })
+        .await
+        .is_ok()
+    }
+
+    #[cfg(test)]
+    pub(super) fn is_fetching(&self) -> bool {
+        !self.fetching_lock().is_empty()
+    }
+
+    fn fetching_lock(&self) -> std::sync::MutexGuard<'_, HashSet<Vec<u8>>> {
+        self.fetching.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Vec<u8>, Vec<u8>>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Best effort: the cache is an optimization, so a failed write never fails the handshake.
+    fn persist(&self, entries: &HashMap<Vec<u8>, Vec<u8>>) {
+        if let Some(path) = &self.path {
+            let _ = write_entries(path, entries);
+        }
+    }
+}
+
+fn read_entries(path: &Path) -> io::Result<HashMap<Vec<u8>, Vec<u8>>> {
+    let encoded: HashMap<String, String> = serde_json::from_slice(&fs::read(path)?)?;
+    Ok(encoded
+        .into_iter()
+        .filter_map(|(key, value)| Some((STANDARD.decode(key).ok()?, STANDARD.decode(value).ok()?)))
+        .collect())
+}
+
+/// Write to a sibling temporary file and rename it over `path`, so concurrent readers never see a partial file.
+fn write_entries(path: &Path, entries: &HashMap<Vec<u8>, Vec<u8>>) -> io::Result<()> {
+    let encoded: HashMap<String, String> = entries
+        .iter()
+        .map(|(key, value)| (STANDARD.encode(key), STANDARD.encode(value)))
+        .collect();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(format!(".{}.tmp", std::process::id()));
+    fs::write(&temporary, serde_json::to_vec(&encoded)?)?;
+    fs::rename(&temporary, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persisted_entries_survive_reload() {
+        let path = std::env::temp_dir().join(format!(
+            "snowflake-connector-rs-ocsp-cache-{}.json",
+            uuid::Uuid::new_v4()
+        ));
+        let cache = ResponseCache::load(Some(path.clone()));
+        cache.insert(b"id-1".to_vec(), b"response-1".to_vec());
+        cache.insert(b"id-2".to_vec(), b"response-2".to_vec());
+        cache.remove(b"id-2");
+
+        let reloaded = ResponseCache::load(Some(path.clone()));
+        assert_eq!(reloaded.get(b"id-1"), Some(b"response-1".to_vec()));
+        assert_eq!(reloaded.get(b"id-2"), None);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn only_one_fetch_per_cert_id_is_in_flight() {
+        let cache = ResponseCache::load(None);
+        assert!(cache.start_fetch(b"id"));
+        assert!(!cache.start_fetch(b"id"));
+        assert!(cache.start_fetch(b"other"));
+        cache.finish_fetch(b"id");
+        assert!(cache.start_fetch(b"id"));
+    }
+
+    #[tokio::test]
+    async fn waiting_ends_when_the_fetch_finishes_or_times_out() {
+        let cache = std::sync::Arc::new(ResponseCache::load(None));
+        let ids = [b"id".to_vec()];
+        assert!(cache.wait_for_fetches(&ids, Duration::ZERO).await);
+
+        cache.start_fetch(b"id");
+        assert!(
+            !cache
+                .wait_for_fetches(&ids, Duration::from_millis(10))
+                .await
+        );
+
+        let finisher = std::sync::Arc::clone(&cache);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            finisher.finish_fetch(b"id");
+        });
+        assert!(cache.wait_for_fetches(&ids, Duration::from_secs(5)).await);
+    }
+
+    #[test]
+    fn unreadable_file_yields_empty_cache() {
+        let path = std::env::temp_dir().join(format!(
+            "snowflake-connector-rs-ocsp-cache-{}.json",
+            uuid::Uuid::new_v4()
+        ));
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(ResponseCache::load(Some(path.clone())).get(b"id"), None);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+use std::{io, time::Duration};
+
+use http::{
+    StatusCode,
+    header::{ACCEPT, CONTENT_TYPE},
+};
+use url::Url;
+
+/// Upper bound on a responder reply; real OCSP responses are a few kilobytes.
+const MAX_RESPONSE_SIZE: usize = 64 * 1024;
+
+/// POST a DER-encoded OCSP request to `url` and return the response body.
+///
+/// `http` is a client built from the same transport settings as the Snowflake client, minus the revocation check,
+/// so responders are reached through the configured proxy and may use `http` or `https`.
+pub(super) async fn post_ocsp_request(
+    http: &reqwest::Client,
+    url: Url,
+    request: Vec<u8>,
+    timeout: Duration,
+) -> io::Result<Vec<u8>> {
+    let mut response = http
+        .post(url)
+        .timeout(timeout)
+        .header(CONTENT_TYPE, "application/ocsp-request")
+        .header(ACCEPT, "application/ocsp-response")
+        .body(request)
+        .send()
+        .await
+        .map_err(io::Error::other)?;
+    if response.status() != StatusCode::OK {
+        return Err(invalid_data("OCSP responder returned a non-success status"));
+    }
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_RESPONSE_SIZE as u64)
+    {
+        return Err(invalid_data("OCSP response is too large"));
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(io::Error::other)? {
+        if body.len() + chunk.len() > MAX_RESPONSE_SIZE {
+            return Err(invalid_data("OCSP response is too large"));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt as _, AsyncWriteExt as _},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Answer one request with `reply` verbatim.
+    async fn spawn_server(reply: Vec<u8>) -> Url {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0_u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream.write_all(&reply).await;
+            let _ = stream.shutdown().await;
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn reply_body_is_returned_for_success_status() {
+        let url = spawn_server(b"HTTP/1.1 200 OK\r\ncontent-length: 4\r\n\r\nbody".to_vec()).await;
+        let body = post_ocsp_request(
+            &reqwest::Client::new(),
+            url,
+            Vec::new(),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(body, b"body");
+    }
+
+    #[tokio::test]
+    async fn non_success_status_is_error() {
+        let url =
+            spawn_server(b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n\r\n".to_vec())
+                .await;
+        let err = post_ocsp_request(
+            &reqwest::Client::new(),
+            url,
+            Vec::new(),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn oversized_reply_is_error() {
+        let mut reply = b"HTTP/1.1 200 OK\r\nconnection: close\r\n\r\n".to_vec();
+        reply.resize(reply.len() + MAX_RESPONSE_SIZE + 1, 0);
+        let url = spawn_server(reply).await;
+        let err = post_ocsp_request(
+            &reqwest::Client::new(),
+            url,
+            Vec::new(),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,514 @@
+//! The subset of RFC 6960 needed to request and validate OCSP responses for a single certificate.
+
+use std::time::Duration;
+
+use der::{
+    Choice, Decode, Encode, Enumerated, Sequence,
+    asn1::{Any, BitString, GeneralizedTime, Null, ObjectIdentifier, OctetString},
+};
+use rustls::crypto::CryptoProvider;
+use sha1::{Digest as _, Sha1};
+use x509_cert::{
+    Certificate,
+    ext::{
+        Extensions,
+        pkix::{AuthorityInfoAccessSyntax, ExtendedKeyUsage, crl::CrlReason, name::GeneralName},
+    },
+    name::Name,
+    serial_number::SerialNumber,
+    spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned},
+};
+
+const ID_PKIX_OCSP_BASIC: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.48.1.1");
+const ID_AD_OCSP: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.48.1");
+const ID_KP_OCSP_SIGNING: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.3.9");
+const ID_SHA1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+
+/// Tolerated difference between the local clock and the responder's.
+const ALLOWED_CLOCK_SKEW: Duration = Duration::from_secs(10 * 60);
+/// How long a response without `nextUpdate` is trusted after its `thisUpdate`.
+const DEFAULT_RESPONSE_VALIDITY: Duration = Duration::from_secs(60 * 60);
+
+/// `CertID`, always built with SHA-1 as that is what responders are required to support.
+#[derive(Clone, Debug, PartialEq, Eq, Sequence)]
+pub(super) struct CertId {
+    hash_algorithm: AlgorithmIdentifierOwned,
+    issuer_name_hash: OctetString,
+    issuer_key_hash: OctetString,
+    serial_number: SerialNumber,
+}
+
+impl CertId {
+    pub(super) fn new(cert: &Certificate, issuer: &Certificate) -> der::Result<Self> {
+        let issuer_name = cert.tbs_certificate.issuer.to_der()?;
+        let issuer_key = issuer
+            .tbs_certificate
+            .subject_public_key_info
+            .subject_public_key
+            .raw_bytes();
+        Ok(Self {
+            hash_algorithm: AlgorithmIdentifierOwned {
+                oid: ID_SHA1,
+                parameters: Some(Null.into()),
+            },
+            issuer_name_hash: OctetString::new(Sha1::digest(issuer_name).to_vec())?,
+            issuer_key_hash: OctetString::new(Sha1::digest(issuer_key).to_vec())?,
+            serial_number: cert.tbs_certificate.serial_number.clone(),
+        })
+    }
+
+    /// Compares everything but the hash algorithm parameters, which responders encode either as NULL or absent.
+    fn matches(&self, other: &Self) -> bool {
+        self.hash_algorithm.oid == other.hash_algorithm.oid
+            && self.issuer_name_hash == other.issuer_name_hash
+            && self.issuer_key_hash == other.issuer_key_hash
+            && self.serial_number == other.serial_number
+    }
+}
+
+#[derive(Sequence)]
+struct OcspRequest {
+    tbs_request: TbsRequest,
+}
+
+#[derive(Sequence)]
+struct TbsRequest {
+    request_list: Vec<Request>,
+}
+
+#[derive(Sequence)]
+struct Request {
+    req_cert: CertId,
+}
+
+/// DER-encoded unsigned `OCSPRequest` for a single certificate.
+pub(super) fn encode_request(cert_id: &CertId) -> der::Result<Vec<u8>> {
+    OcspRequest {
+        tbs_request: TbsRequest {
+            request_list: vec![Request {
+                req_cert: cert_id.clone(),
+            }],
+        },
+    }
+    .to_der()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Enumerated)]
+#[repr(u32)]
+enum OcspResponseStatus {
+    Successful = 0,
+    MalformedRequest = 1,
+    InternalError = 2,
+    TryLater = 3,
+    SigRequired = 5,
+    Unauthorized = 6,
+}
+
+#[derive(Sequence)]
+struct OcspResponse {
+    response_status: OcspResponseStatus,
+    #[asn1(context_specific = "0", optional = "true")]
+    response_bytes: Option<ResponseBytes>,
+}
+
+#[derive(Sequence)]
+struct ResponseBytes {
+    response_type: ObjectIdentifier,
+    response: OctetString,
+}
+
+#[derive(Sequence)]
+struct BasicOcspResponse {
+    /// Kept undecoded so the signature is checked over the exact bytes the responder signed.
+    tbs_response_data: Any,
+    signature_algorithm: AlgorithmIdentifierOwned,
+    signature: BitString,
+    #[asn1(context_specific = "0", optional = "true")]
+    certs: Option<Vec<Certificate>>,
+}
+
+#[derive(Sequence)]
+struct ResponseData {
+    #[asn1(context_specific = "0", default = "Default::default")]
+    version: u8,
+    responder_id: ResponderId,
+    produced_at: GeneralizedTime,
+    responses: Vec<SingleResponse>,
+    #[asn1(context_specific = "1", optional = "true")]
+    response_extensions: Option<Extensions>,
+}
+
+#[derive(Choice)]
+enum ResponderId {
+    #[asn1(context_specific = "1", tag_mode = "EXPLICIT", constructed = "true")]
+    ByName(Name),
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT", constructed = "true")]
+    ByKey(OctetString),
+}
+
+#[derive(Sequence)]
+struct SingleResponse {
+    cert_id: CertId,
+    cert_status: CertStatus,
+    this_update: GeneralizedTime,
+    #[asn1(context_specific = "0", optional = "true")]
+    next_update: Option<GeneralizedTime>,
+    #[asn1(context_specific = "1", optional = "true")]
+    single_extensions: Option<Extensions>,
+}
+
+#[derive(Choice)]
+enum CertStatus {
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    Good(Null),
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", constructed = "true")]
+    Revoked(RevokedInfo),
+    #[asn1(context_specific = "2", tag_mode = "IMPLICIT")]
+    Unknown(Null),
+}
+
+#[derive(Sequence)]
+struct RevokedInfo {
+    revocation_time: GeneralizedTime,
+    #[asn1(context_specific = "0", optional = "true")]
+    revocation_reason: Option<CrlReason>,
+}
+
+/// Revocation status asserted by a response that passed [`validate_response`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Status {
+    Good,
+    Revoked,
+    Unknown,
+}
+
+/// Why a response was not usable. Only surfaced in tests; callers treat every variant as "status undetermined".
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct InvalidResponse(&'static str);
+
+impl From<der::Error> for InvalidResponse {
+    fn from(_: der::Error) -> Self {
+        Self("malformed DER")
+    }
+}
+
+/// Parse a DER-encoded `OCSPResponse` and return the status it asserts for `cert_id`.
+///
+/// The response must be signed by `issuer` or by a delegated responder certificate that `issuer` signed for OCSP,
+/// and `now` must fall inside its validity window.
+pub(super) fn validate_response(
+    response: &[u8],
+    cert_id: &CertId,
+    issuer: &Certificate,
+    provider: &CryptoProvider,
+    now: Duration,
+) -> Result<Status, InvalidResponse> {
+    let response = OcspResponse::from_der(response)?;
+    if response.response_status != OcspResponseStatus::Successful {
+        return Err(InvalidResponse("unsuccessful response status"));
+    }
+    let bytes = response
+        .response_bytes
+        .ok_or(InvalidResponse("missing response bytes"))?;
+    if bytes.response_type != ID_PKIX_OCSP_BASIC {
+        return Err(InvalidResponse("unsupported response type"));
+    }
+    let basic = BasicOcspResponse::from_der(bytes.response.as_bytes())?;
+
+    let signed = basic.tbs_response_data.to_der()?;
+    let issuer_key = &issuer.tbs_certificate.subject_public_key_info;
+    let signed_by_issuer = verify_signature(
+        provider,
+        issuer_key,
+        &basic.signature_algorithm,
+        &basic.signature,
+        &signed,
+    );
+    let signed_by_delegate = || {
+        basic.certs.iter().flatten().any(|responder| {
+            is_delegated_responder(responder, issuer, provider, now)
+                && verify_signature(
+                    provider,
+                    &responder.tbs_certificate.subject_public_key_info,
+                    &basic.signature_algorithm,
+                    &basic.signature,
+                    &signed,
+                )
+        })
+    };
+    if !signed_by_issuer && !signed_by_delegate() {
+        return Err(InvalidResponse("signature does not verify"));
+    }
+
+    let data: ResponseData = basic.tbs_response_data.decode_as()?;
+    let single = data
+        .responses
+        .iter()
+        .find(|single| single.cert_id.matches(cert_id))
+        .ok_or(InvalidResponse("no response for the certificate"))?;
+
+    let this_update = single.this_update.to_unix_duration();
+    let expires_at = match single.next_update {
+        Some(next_update) => next_update.to_unix_duration(),
+        None => this_update + DEFAULT_RESPONSE_VALIDITY,
+    };
+    if this_update > now + ALLOWED_CLOCK_SKEW {
+        return Err(InvalidResponse("response is not yet valid"));
+    }
+    if expires_at + ALLOWED_CLOCK_SKEW < now {
+        return Err(InvalidResponse("response has expired"));
+    }
+
+    Ok(match single.cert_status {
+        CertStatus::Good(_) => Status::Good,
+        CertStatus::Revoked(_) => Status::Revoked,
+        CertStatus::Unknown(_) => Status::Unknown,
+    })
+}
+
+/// The first OCSP responder URL in the certificate's Authority Information Access extension.
+pub(super) fn responder_url(cert: &Certificate) -> Option<String> {
+    let (_, aia) = cert
+        .tbs_certificate
+        .get::<AuthorityInfoAccessSyntax>()
+        .ok()??;
+    aia.0.into_iter().find_map(
+        |access| match (access.access_method, access.access_location) {
+            (ID_AD_OCSP, GeneralName::UniformResourceIdentifier(uri)) => Some(uri.to_string()),
+            _ => None,
+        },
+    )
+}
+
+/// A responder certificate issued by `issuer`, currently valid, and carrying the `id-kp-OCSPSigning` usage.
+fn is_delegated_responder(
+    responder: &Certificate,
+    issuer: &Certificate,
+    provider: &CryptoProvider,
+    now: Duration,
+) -> bool {
+    let tbs = &responder.tbs_certificate;
+    let validity = &tbs.validity;
+    if now < validity.not_before.to_unix_duration() || now > validity.not_after.to_unix_duration() {
+        return false;
+    }
+    let ocsp_signing = matches!(
+        tbs.get::<ExtendedKeyUsage>(),
+        Ok(Some((_, usage))) if usage.0.contains(&ID_KP_OCSP_SIGNING)
+    );
+    ocsp_signing
+        && tbs.issuer == issuer.tbs_certificate.subject
+        && tbs.to_der().is_ok_and(|signed| {
+            verify_signature(
+                provider,
+                &issuer.tbs_certificate.subject_public_key_info,
+                &responder.signature_algorithm,
+                &responder.signature,
+                &signed,
+            )
+        })
+}
+
+fn verify_signature(
+    provider: &CryptoProvider,
+    public_key: &SubjectPublicKeyInfoOwned,
+    signature_algorithm: &AlgorithmIdentifierOwned,
+    signature: &BitString,
+    message: &[u8],
+) -> bool {
+    let (Ok(key_alg), Ok(signature_alg)) = (
+        algorithm_id_contents(&public_key.algorithm),
+        algorithm_id_contents(signature_algorithm),
+    ) else {
+        return false;
+    };
+    let (Some(key), Some(signature)) = (
+        public_key.subject_public_key.as_bytes(),
+        signature.as_bytes(),
+    ) else {
+        return false;
+    };
+    provider
+        .signature_verification_algorithms
+        .all
+        .iter()
+        .filter(|alg| {
+            alg.public_key_alg_id().as_ref() == key_alg
+                && alg.signature_alg_id().as_ref() == signature_alg
+        })
+        .any(|alg| alg.verify_signature(key, message, signature).is_ok())
+}
+
+/// The contents of an `AlgorithmIdentifier` SEQUENCE, which is how rustls identifies algorithms.
+fn algorithm_id_contents(algorithm: &AlgorithmIdentifierOwned) -> der::Result<Vec<u8>> {
+    let mut contents = algorithm.oid.to_der()?;
+    if let Some(parameters) = &algorithm.parameters {
+        contents.extend(parameters.to_der()?);
+    }
+    Ok(contents)
+}
+
+/// Build a `BasicOCSPResponse` for `cert_id`, signed with an ECDSA P-256 key given as PKCS#8 PEM.
+#[cfg(test)]
+pub(super) fn signed_test_response(
+    cert_id: &CertId,
+    status: Status,
+    this_update: Duration,
+    next_update: Option<Duration>,
+    signer_key_pem: &str,
+) -> Vec<u8> {
+    use rustls::{
+        SignatureScheme,
+        pki_types::{PrivateKeyDer, pem::PemObject},
+    };
+
+    const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+
+    let cert_status = match status {
+        Status::Good => CertStatus::Good(Null),
+        Status::Revoked => CertStatus::Revoked(RevokedInfo {
+            revocation_time: GeneralizedTime::from_unix_duration(this_update).unwrap(),
+            revocation_reason: Some(CrlReason::KeyCompromise),
+        }),
+        Status::Unknown => CertStatus::Unknown(Null),
+    };
+    let data = ResponseData {
+        version: 0,
+        responder_id: ResponderId::ByKey(OctetString::new(vec![0; 20]).unwrap()),
+        produced_at: GeneralizedTime::from_unix_duration(this_update).unwrap(),
+        responses: vec![SingleResponse {
+            cert_id: cert_id.clone(),
+            cert_status,
+            this_update: GeneralizedTime::from_unix_duration(this_update).unwrap(),
+            next_update: next_update.map(|t| GeneralizedTime::from_unix_duration(t).unwrap()),
+            single_extensions: None,
+        }],
+        response_extensions: None,
+    };
+    let tbs_response_data = Any::encode_from(&data).unwrap();
+
+    let key = rustls::crypto::aws_lc_rs::default_provider()
+        .key_provider
+        .load_private_key(PrivateKeyDer::from_pem_slice(signer_key_pem.as_bytes()).unwrap())
+        .unwrap();
+    let signer = key
+        .choose_scheme(&[SignatureScheme::ECDSA_NISTP256_SHA256])
+        .unwrap();
+    let signature = signer.sign(&tbs_response_data.to_der().unwrap()).unwrap();
+
+    let basic = BasicOcspResponse {
+        tbs_response_data,
+        signature_algorithm: AlgorithmIdentifierOwned {
+            oid: ECDSA_WITH_SHA256,
+            parameters: None,
+        },
+        signature: BitString::from_bytes(&signature).unwrap(),
+        certs: None,
+    };
+    OcspResponse {
+        response_status: OcspResponseStatus::Successful,
+        response_bytes: Some(ResponseBytes {
+            response_type: ID_PKIX_OCSP_BASIC,
+            response: OctetString::new(basic.to_der().unwrap()).unwrap(),
+        }),
+    }
+    .to_der()
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::tls::{CA_KEY, CA_PEM, SERVER_KEY, SERVER_PEM};
+
+    const NOW: Duration = Duration::from_secs(1_800_000_000);
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn parse(pem: &str) -> Certificate {
+        Certificate::from_der(&crate::test_support::tls::cert_der(pem)).unwrap()
+    }
+
+    fn server_cert_id() -> CertId {
+        CertId::new(&parse(SERVER_PEM), &parse(CA_PEM)).unwrap()
+    }
+
+    fn validate(response: &[u8]) -> Result<Status, InvalidResponse> {
+        let provider = rustls::crypto::aws_lc_rs::default_provider();
+        validate_response(response, &server_cert_id(), &parse(CA_PEM), &provider, NOW)
+    }
+
+    #[test]
+    fn request_round_trips_cert_id() {
+        let cert_id = server_cert_id();
+        let request = OcspRequest::from_der(&encode_request(&cert_id).unwrap()).unwrap();
+        assert_eq!(request.tbs_request.request_list.len(), 1);
+        assert_eq!(request.tbs_request.request_list[0].req_cert, cert_id);
+    }
+
+    #[test]
+    fn issuer_signed_responses_report_status() {
+        for status in [Status::Good, Status::Revoked, Status::Unknown] {
+            let response = signed_test_response(
+                &server_cert_id(),
+                status,
+                NOW - DAY,
+                Some(NOW + DAY),
+                CA_KEY,
+            );
+            assert_eq!(validate(&response), Ok(status));
+        }
+    }
+
+    #[test]
+    fn response_signed_by_another_key_is_rejected() {
+        let response = signed_test_response(
+            &server_cert_id(),
+            Status::Good,
+            NOW - DAY,
+            Some(NOW + DAY),
+            SERVER_KEY,
+        );
+        assert_eq!(
+            validate(&response),
+            Err(InvalidResponse("signature does not verify"))
+        );
+    }
+
+    #[test]
+    fn response_outside_validity_window_is_rejected() {
+        let expired = signed_test_response(
+            &server_cert_id(),
+            Status::Good,
+            NOW - 2 * DAY,
+            Some(NOW - DAY),
+            CA_KEY,
+        );
+        assert_eq!(
+            validate(&expired),
+            Err(InvalidResponse("response has expired"))
+        );
+
+        let stale = signed_test_response(&server_cert_id(), Status::Good, NOW - DAY, None, CA_KEY);
+        assert_eq!(
+            validate(&stale),
+            Err(InvalidResponse("response has expired"))
+        );
+
+        let future = signed_test_response(&server_cert_id(), Status::Good, NOW + DAY, None, CA_KEY);
+        assert_eq!(
+            validate(&future),
+            Err(InvalidResponse("response is not yet valid"))
+        );
+    }
+
+    #[test]
+    fn response_for_another_certificate_is_rejected() {
+        let other = CertId::new(&parse(CA_PEM), &parse(CA_PEM)).unwrap();
+        let response =
+            signed_test_response(&other, Status::Good, NOW - DAY, Some(NOW + DAY), CA_KEY);
+        assert_eq!(
+            validate(&response),
+            Err(InvalidResponse("no response for the certificate"))
+        );
+    }
+}
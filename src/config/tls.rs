@@ -0,0 +1,386 @@
+use std::{fmt, sync::Arc};
+
+use rustls::{
+    DigitallySignedStruct, SignatureScheme,
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    crypto::CryptoProvider,
+    pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime, pem::PemObject},
+    version::{TLS12, TLS13},
+};
+use sha2::{Digest, Sha256};
+
+use super::ProxyConfig;
+use crate::{Result, error::ConfigError};
+
+/// Minimum TLS protocol version accepted by [`TransportConfig`](crate::TransportConfig).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsVersion {
+    /// TLS 1.2 or later.
+    Tls1_2,
+    /// TLS 1.3 only.
+    Tls1_3,
+}
+
+/// A client certificate chain and private key presented for mutual TLS.
+///
+/// Both inputs are PEM encoded. The certificate chain starts with the end-entity certificate; the private key may be
+/// PKCS#1, PKCS#8, or SEC1 encoded. Parsing is deferred until [`Client::new`](crate::Client::new).
+#[derive(Clone)]
+pub struct ClientIdentity {
+    cert_chain_pem: Vec<u8>,
+    private_key_pem: Vec<u8>,
+}
+
+impl ClientIdentity {
+    /// A client identity from a PEM-encoded certificate chain and private key.
+    ///
+    /// # Errors
+    ///
+    /// This constructor does not parse its inputs. [`Client::new`](crate::Client::new) returns `ErrorKind::Config`
+    /// when the chain holds no certificate or a malformed one, or when the private key cannot be parsed.
+    pub fn from_pem(
+        cert_chain_pem: impl Into<Vec<u8>>,
+        private_key_pem: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            cert_chain_pem: cert_chain_pem.into(),
+            private_key_pem: private_key_pem.into(),
+        }
+    }
+}
+
+impl fmt::Debug for ClientIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientIdentity")
+            .field(
+                "cert_chain_pem",
+                &format_args!("<{} bytes>", self.cert_chain_pem.len()),
+            )
+            .field("private_key_pem", &"<redacted>")
+            .finish()
+    }
+}
+
+/// TLS options collected by [`TransportConfig`](crate::TransportConfig).
+///
+/// When every option is left at its default the connector keeps reqwest's built-in rustls setup. Otherwise a rustls
+/// client configuration is assembled here and handed to reqwest as a preconfigured backend.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct TlsSettings {
+    pub(crate) root_certificates_pem: Vec<Vec<u8>>,
+    pub(crate) client_identity: Option<ClientIdentity>,
+    pub(crate) min_version: Option<TlsVersion>,
+    pub(crate) spki_pins: Vec<[u8; 32]>,
+    #[cfg(feature = "ocsp")]
+    pub(crate) revocation: Option<super::RevocationConfig>,
+}
+
+impl TlsSettings {
+    pub(crate) fn is_customized(&self) -> bool {
+        !self.root_certificates_pem.is_empty()
+            || self.client_identity.is_some()
+            || self.min_version.is_some()
+            || !self.spki_pins.is_empty()
+            || self.revocation_enabled()
+    }
+
+    #[cfg(feature = "ocsp")]
+    fn revocation_enabled(&self) -> bool {
+        self.revocation
+            .as_ref()
+            .is_some_and(super::RevocationConfig::is_enabled)
+    }
+
+    #[cfg(not(feature = "ocsp"))]
+    fn revocation_enabled(&self) -> bool {
+        false
+    }
+
+    /// Build the rustls client configuration shared by the Snowflake API and chunk download clients.
+    ///
+    /// `proxy` is the transport's proxy, which OCSP responders are reached through as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorKind::Config` when a PEM input is malformed or rustls rejects the resulting configuration.
+    #[cfg_attr(not(feature = "ocsp"), allow(unused_variables))]
+    pub(crate) fn build_rustls_config(
+        &self,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<rustls::ClientConfig> {
+        let provider = CryptoProvider::get_default()
+            .cloned()
+            .unwrap_or_else(|| Arc::new(rustls::crypto::aws_lc_rs::default_provider()));
+
+        let versions: &[&'static rustls::SupportedProtocolVersion] = match self.min_version {
+            None | Some(TlsVersion::Tls1_2) => &[&TLS13, &TLS12],
+            Some(TlsVersion::Tls1_3) => &[&TLS13],
+        };
+        let builder = rustls::ClientConfig::builder_with_provider(Arc::clone(&provider))
+            .with_protocol_versions(versions)
+            .map_err(|e| ConfigError::tls_with_source("unsupported TLS protocol versions", e))?;
+
+        let extra_roots = self.parse_root_certificates()?;
+        let verifier: Arc<dyn ServerCertVerifier> = Arc::new(
+            rustls_platform_verifier::Verifier::new_with_extra_roots(
+                extra_roots.clone(),
+                Arc::clone(&provider),
+            )
+            .map_err(|e| {
+                ConfigError::tls_with_source("failed to initialize certificate verifier", e)
+            })?,
+        );
+        #[cfg(feature = "ocsp")]
+        let verifier = match &self.revocation {
+            Some(revocation) if revocation.is_enabled() => revocation.wrap_verifier(
+                verifier,
+                &extra_roots,
+                provider,
+                self.responder_client(proxy)?,
+            ),
+            _ => verifier,
+        };
+        let verifier = if self.spki_pins.is_empty() {
+            verifier
+        } else {
+            Arc::new(SpkiPinningVerifier {
+                inner: verifier,
+                pins: self.spki_pins.clone(),
+            })
+        };
+        let builder = builder
+            .dangerous()
+            .with_custom_certificate_verifier(verifier);
+
+        let mut config = match &self.client_identity {
+            Some(identity) => {
+                let (cert_chain, key) = parse_client_identity(identity)?;
+                builder
+                    .with_client_auth_cert(cert_chain, key)
+                    .map_err(|e| ConfigError::tls_with_source("invalid client identity", e))?
+            }
+            None => builder.with_no_client_auth(),
+        };
+        // The connector only speaks HTTP/1.1; advertise it explicitly as reqwest does for its own rustls setup.
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+        Ok(config)
+    }
+
+    /// The client OCSP responders are queried with: this transport's TLS and proxy settings without the revocation
+    /// check, which would otherwise recurse into the responder's own certificate.
+    #[cfg(feature = "ocsp")]
+    pub(super) fn responder_client(&self, proxy: Option<&ProxyConfig>) -> Result<reqwest::Client> {
+        let mut transport = super::TransportConfig::new();
+        transport.tls = Self {
+            revocation: None,
+            ..self.clone()
+        };
+        transport.proxy = proxy.cloned();
+        transport.build_http_client()
+    }
+
+    fn parse_root_certificates(&self) -> Result<Vec<CertificateDer<'static>>> {
+        let mut roots = Vec::new();
+        for pem in &self.root_certificates_pem {
+            let start = roots.len();
+            for cert in CertificateDer::pem_slice_iter(pem) {
+                roots.push(cert.map_err(|e| {
+                    ConfigError::tls_with_source("invalid root certificate PEM", e)
+                })?);
+            }
+            if roots.len() == start {
+                return Err(
+                    ConfigError::tls("root certificate PEM contains no certificates").into(),
+                );
+            }
+        }
+        Ok(roots)
+    }
+}
+
+fn parse_client_identity(
+    identity: &ClientIdentity,
+) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)> {
+    let cert_chain = CertificateDer::pem_slice_iter(&identity.cert_chain_pem)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| ConfigError::tls_with_source("invalid client certificate PEM", e))?;
+    if cert_chain.is_empty() {
+        return Err(ConfigError::tls("client certificate PEM contains no certificates").into());
+    }
+    // Keep the key parser's error out of the chain: its message may quote the offending PEM section.
+    let key = PrivateKeyDer::from_pem_slice(&identity.private_key_pem)
+        .map_err(|_| ConfigError::tls("invalid client private key PEM"))?;
+    Ok((cert_chain, key))
+}
+
+/// SHA-256 digest of a certificate's DER-encoded `SubjectPublicKeyInfo`, as used by [`SpkiPinningVerifier`].
+fn spki_sha256(cert: &CertificateDer<'_>) -> Option<[u8; 32]> {
+    let cert = webpki::EndEntityCert::try_from(cert).ok()?;
+    Some(Sha256::digest(cert.subject_public_key_info().as_ref()).into())
+}
+
+/// Runs the regular chain verification first, then requires at least one certificate in the presented chain to
+/// carry a pinned public key.
+#[derive(Debug)]
+struct SpkiPinningVerifier {
+    inner: Arc<dyn ServerCertVerifier>,
+    pins: Vec<[u8; 32]>,
+}
+
+impl ServerCertVerifier for SpkiPinningVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        )?;
+
+        let pinned = std::iter::once(end_entity)
+            .chain(intermediates)
+            .filter_map(spki_sha256)
+            .any(|digest| self.pins.contains(&digest));
+        if pinned {
+            Ok(verified)
+        } else {
+            Err(rustls::Error::InvalidCertificate(
+                rustls::CertificateError::ApplicationVerificationFailure,
+            ))
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ErrorKind, TransportConfig,
+        test_support::tls::{
+            CA_PEM, CLIENT_KEY, CLIENT_PEM, SERVER_PEM, cert_der, https_get as get,
+            spawn_https_server,
+        },
+    };
+
+    fn server_spki_pin() -> [u8; 32] {
+        spki_sha256(&cert_der(SERVER_PEM)).unwrap()
+    }
+
+    #[test]
+    fn default_settings_are_not_customized() {
+        assert!(!TlsSettings::default().is_customized());
+        assert!(
+            TransportConfig::new()
+                .with_tls_min_version(TlsVersion::Tls1_3)
+                .tls
+                .is_customized()
+        );
+    }
+
+    #[test]
+    fn client_identity_debug_redacts_private_key() {
+        let debug = format!("{:?}", ClientIdentity::from_pem(CLIENT_PEM, CLIENT_KEY));
+        assert!(debug.contains("<redacted>"), "{debug}");
+        assert!(!debug.contains("PRIVATE KEY"), "{debug}");
+    }
+
+    #[test]
+    fn malformed_root_certificate_pem_is_config_error() {
+        let err = TransportConfig::new()
+            .with_root_certificates("not a certificate")
+            .build_http_client()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Config);
+        assert!(err.to_string().contains("no certificates"), "{err}");
+    }
+
+    #[test]
+    fn malformed_client_key_pem_is_config_error() {
+        let err = TransportConfig::new()
+            .with_client_identity(ClientIdentity::from_pem(CLIENT_PEM, "not a key"))
+            .build_http_client()
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Config);
+        assert!(err.to_string().contains("private key"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn extra_root_certificate_trusts_private_ca() {
+        let (addr, server) = spawn_https_server(false).await;
+        let body = get(TransportConfig::new().with_root_certificates(CA_PEM), addr)
+            .await
+            .unwrap();
+        assert_eq!(body, "ok");
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn private_ca_is_rejected_without_extra_root() {
+        let (addr, server) = spawn_https_server(false).await;
+        let err = get(TransportConfig::new(), addr).await.unwrap_err();
+        assert!(err.is_connect(), "{err:?}");
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn matching_spki_pin_is_accepted() {
+        let (addr, server) = spawn_https_server(false).await;
+        let transport = TransportConfig::new()
+            .with_root_certificates(CA_PEM)
+            .with_spki_pins([server_spki_pin()]);
+        assert_eq!(get(transport, addr).await.unwrap(), "ok");
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn mismatching_spki_pin_is_rejected() {
+        let (addr, server) = spawn_https_server(false).await;
+        let transport = TransportConfig::new()
+            .with_root_certificates(CA_PEM)
+            .with_spki_pins([[0_u8; 32]]);
+        let err = get(transport, addr).await.unwrap_err();
+        assert!(err.is_connect(), "{err:?}");
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn client_identity_is_presented_for_mutual_tls() {
+        let (addr, server) = spawn_https_server(true).await;
+        let transport = TransportConfig::new()
+            .with_root_certificates(CA_PEM)
+            .with_client_identity(ClientIdentity::from_pem(CLIENT_PEM, CLIENT_KEY));
+        assert_eq!(get(transport, addr).await.unwrap(), "ok");
+        server.await.unwrap();
+    }
+}
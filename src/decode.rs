@@ -2,7 +2,9 @@
 //!
 //! Most callers decode with `#[derive(FromRow)]` or [`query_as`](crate::Session::query_as) and never touch this module.
 //! For hand-written decoders, implement [`FromCell`] for a single column or [`FromRow`] for a whole row.
-//! [`Json`] and [`Vector`] are the wrappers for decoding semi-structured and `VECTOR` columns.
+//! [`Json`] and [`Vector`] are the wrappers for decoding semi-structured and `VECTOR` columns, [`Serde`] decodes rows
+//! or cells through `serde::Deserialize`, and [`EnumPlan`] is the plan behind `#[derive(FromCell)]` on fieldless
+//! enums.
 //!
 //! Hand-written decoders report their own validation failures with one of three errors. The connector attaches
 //! position context where it can, so implementations provide only the reason and optional source:
@@ -44,7 +46,12 @@ pub use crate::error::{
     CustomPlanErrorBuilder, PlanBuildError, PlanBuildResult, RowConversionError,
     RowConversionErrorBuilder, RowDecodeError, RowDecodeResult,
 };
+#[cfg(feature = "chrono-tz")]
+pub use crate::result_table::ZonedTimestampPlan;
 pub use crate::result_table::{
-    CellPlan, CellPlanContext, FromCell, FromRow, Json, RowPlanContext, TimePlan, TimestampPlan,
-    UtcTimestampPlan, Vector,
+    ArrayPlan, BinaryPlan, BytesPlan, CellPlan, CellPlanContext, CellRefPlan, EnumPlan, FromCell,
+    FromCellRef, FromRow, FromRowRef, Json, Lossy, MapPlan, Object, ObjectPlan, RowPlanContext,
+    Serde, TimePlan, TimestampPlan, UtcTimestampPlan, UuidPlan, Vector, VectorPlan,
 };
+#[cfg(feature = "geo")]
+pub use crate::result_table::{GeoPlan, Wkb};
@@ -27,8 +27,8 @@ pub(crate) use query_scoped::{
     QueryScopedError, QueryScopedRepr, QueryScopedResult, with_optional_query_id,
 };
 pub(crate) use repr::{
-    AuthError, CancelledError, ConfigError, InternalError, NetworkError, ProtocolError,
-    ServerError, SessionExpiredError, TimeoutError,
+    AuthError, CancelledError, ConfigError, ExportError, InternalError, NetworkError,
+    ProtocolError, ServerError, SessionExpiredError, TimeoutError,
 };
 
 const VALUE_PREVIEW_MAX_CHARS: usize = 128;
@@ -140,8 +140,12 @@ pub enum ErrorKind {
     /// decoding failures, [`Error::as_custom_plan_error`] for custom plan-time failures, and
     /// [`Error::as_row_conversion_error`] for custom row-level conversion failures.
     Decode,
-    /// Connector-internal runtime work failed, such as a cancelled or panicked task join.
+    /// Connector-internal runtime work failed, such as a cancelled or panicked task join, or the Parquet encoder
+    /// rejected decoded rows.
     Internal,
+    /// Exporting query results failed locally: the caller-supplied writer returned an error, or a row could not be
+    /// serialized for the output format.
+    Export,
     /// Caller-supplied fallback error created via [`Error::other`].
     Other,
 }
@@ -172,6 +176,7 @@ impl Error {
             | Repr::CustomPlan(_)
             | Repr::RowConversion(_) => ErrorKind::Decode,
             Repr::Internal { .. } => ErrorKind::Internal,
+            Repr::Export { .. } => ErrorKind::Export,
             Repr::Other(_) => ErrorKind::Other,
         }
     }
@@ -210,7 +215,8 @@ impl Error {
             Repr::Network { query_id, .. }
             | Repr::Timeout { query_id, .. }
             | Repr::Protocol { query_id, .. }
-            | Repr::Internal { query_id, .. } => query_id.as_deref(),
+            | Repr::Internal { query_id, .. }
+            | Repr::Export { query_id, .. } => query_id.as_deref(),
             Repr::Server(ServerError { query_id, .. }) => query_id.as_deref(),
             Repr::Cancelled(CancelledError { query_id, .. }) => query_id.as_deref(),
             Repr::SessionExpired(SessionExpiredError { query_id, .. }) => query_id.as_deref(),
@@ -381,6 +387,15 @@ impl From<InternalError> for Error {
     }
 }
 
+impl From<ExportError> for Error {
+    fn from(error: ExportError) -> Self {
+        Self::new(Repr::Export {
+            error,
+            query_id: None,
+        })
+    }
+}
+
 impl From<CellDecodeError> for Error {
     fn from(error: CellDecodeError) -> Self {
         Self::new(Repr::CellDecode(error))
@@ -438,6 +453,23 @@ impl ConfigError {
     pub(crate) fn client_builder_failure(source: reqwest::Error) -> Self {
         Self::HttpClientBuild(source)
     }
+
+    pub(crate) fn tls(message: impl Into<String>) -> Self {
+        Self::Tls {
+            message: message.into().into_boxed_str(),
+            source: None,
+        }
+    }
+
+    pub(crate) fn tls_with_source(
+        message: impl Into<String>,
+        source: impl Into<Box<dyn StdError + Send + Sync>>,
+    ) -> Self {
+        Self::Tls {
+            message: message.into().into_boxed_str(),
+            source: Some(source.into()),
+        }
+    }
 }
 
 impl AuthError {
@@ -506,6 +538,16 @@ impl NetworkError {
     }
 }
 
+impl ExportError {
+    pub(crate) fn write(source: std::io::Error) -> Self {
+        Self::Write(source)
+    }
+
+    pub(crate) fn serialize(source: serde_json::Error) -> Self {
+        Self::Serialize(source)
+    }
+}
+
 impl ServerError {
     pub(crate) fn new(
         code: Option<String>,
@@ -637,6 +679,11 @@ impl InternalError {
     pub(crate) fn future_join(source: JoinError) -> Self {
         Self::FutureJoin(source)
     }
+
+    #[cfg(feature = "parquet")]
+    pub(crate) fn parquet_encode(source: parquet::errors::ParquetError) -> Self {
+        Self::ParquetEncode(source)
+    }
 }
 
 pub(crate) fn truncate_preview_chars(input: &str, max_chars: usize) -> Box<str> {
@@ -803,6 +850,23 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet_encode_failure_is_internal_and_keeps_query_id() {
+        let err: Error = QueryScopedError::new(
+            Arc::from("query-id"),
+            InternalError::parquet_encode(parquet::errors::ParquetError::General(
+                "bad row group".to_string(),
+            )),
+        )
+        .into();
+
+        assert_eq!(err.kind(), ErrorKind::Internal);
+        assert_eq!(err.query_id(), Some("query-id"));
+        assert_eq!(err.to_string(), "failed to encode query results as Parquet");
+        assert!(StdError::source(&err).is_some());
+    }
+
     #[test]
     fn cancelled_error_without_server_fields_has_stable_display() {
         let err: Error = CancelledError::new(None, None, None).into();
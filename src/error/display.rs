@@ -6,8 +6,8 @@ use std::{
 use super::{
     Error,
     repr::{
-        AuthError, CancelledError, ConfigError, InternalError, NetworkError, ProtocolError, Repr,
-        ServerError, TimeoutError,
+        AuthError, CancelledError, ConfigError, ExportError, InternalError, NetworkError,
+        ProtocolError, Repr, ServerError, TimeoutError,
     },
 };
 
@@ -18,6 +18,9 @@ impl Display for Error {
             Repr::Config(ConfigError::HttpClientBuild(_source)) => {
                 f.write_str("HTTP client build error")
             }
+            Repr::Config(ConfigError::Tls { message, .. }) => {
+                write!(f, "invalid TLS configuration: {message}")
+            }
             Repr::Auth(AuthError::LoginRejected {
                 message: Some(message),
             }) => write!(f, "authentication rejected: {message}"),
@@ -187,6 +190,19 @@ impl Display for Error {
                 error: InternalError::FutureJoin(_source),
                 ..
             } => f.write_str("future join error"),
+            #[cfg(feature = "parquet")]
+            Repr::Internal {
+                error: InternalError::ParquetEncode(_source),
+                ..
+            } => f.write_str("failed to encode query results as Parquet"),
+            Repr::Export {
+                error: ExportError::Write(_source),
+                ..
+            } => f.write_str("failed to write query results"),
+            Repr::Export {
+                error: ExportError::Serialize(_source),
+                ..
+            } => f.write_str("failed to serialize query results"),
             Repr::BindEncode { message, .. } => write!(f, "bind encode error: {message}"),
             Repr::Other(message) => write!(f, "snowflake connector error: {message}"),
             Repr::Schema(error) => Display::fmt(error, f),
@@ -201,6 +217,10 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match &*self.repr {
             Repr::Config(ConfigError::HttpClientBuild(source)) => Some(source),
+            Repr::Config(ConfigError::Tls {
+                source: Some(source),
+                ..
+            }) => Some(source.as_ref()),
             #[cfg(feature = "key-pair-auth")]
             Repr::Auth(AuthError::KeyParse(source)) => Some(source.as_ref()),
             #[cfg(feature = "key-pair-auth")]
@@ -220,6 +240,14 @@ impl StdError for Error {
                 error: NetworkError::Http(source),
                 ..
             } => Some(source),
+            Repr::Export {
+                error: ExportError::Write(source),
+                ..
+            } => Some(source),
+            Repr::Export {
+                error: ExportError::Serialize(source),
+                ..
+            } => Some(source),
             Repr::Protocol {
                 error: ProtocolError::JsonParse { source, .. },
                 ..
@@ -248,6 +276,11 @@ impl StdError for Error {
                 error: InternalError::FutureJoin(source),
                 ..
             } => Some(source),
+            #[cfg(feature = "parquet")]
+            Repr::Internal {
+                error: InternalError::ParquetEncode(source),
+                ..
+            } => Some(source),
             Repr::BindEncode {
                 source: Some(source),
                 ..
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use super::{
-    CancelledError, Error, InternalError, NetworkError, ProtocolError, RowsetParseError,
-    ServerError, SessionExpiredError, TimeoutError, repr::Repr,
+    CancelledError, Error, ExportError, InternalError, NetworkError, ProtocolError,
+    RowsetParseError, ServerError, SessionExpiredError, TimeoutError, repr::Repr,
 };
 
 #[derive(Debug)]
@@ -17,6 +17,7 @@ pub(crate) enum QueryScopedRepr {
     Timeout(TimeoutError),
     Protocol(ProtocolError),
     Internal(InternalError),
+    Export(ExportError),
     Server(ServerError),
     Cancelled(CancelledError),
     SessionExpired(SessionExpiredError),
@@ -57,6 +58,10 @@ impl From<QueryScopedError> for Error {
                 error,
                 query_id: Some(query_id),
             },
+            QueryScopedRepr::Export(error) => Repr::Export {
+                error,
+                query_id: Some(query_id),
+            },
             QueryScopedRepr::Server(mut error) => {
                 if error.query_id.is_none() {
                     error.query_id = Some(query_id);
@@ -104,6 +109,12 @@ impl From<InternalError> for QueryScopedRepr {
     }
 }
 
+impl From<ExportError> for QueryScopedRepr {
+    fn from(error: ExportError) -> Self {
+        Self::Export(error)
+    }
+}
+
 impl From<ServerError> for QueryScopedRepr {
     fn from(error: ServerError) -> Self {
         Self::Server(error)
@@ -140,6 +151,7 @@ pub(crate) fn with_optional_query_id(
             QueryScopedRepr::Timeout(error) => error.into(),
             QueryScopedRepr::Protocol(error) => error.into(),
             QueryScopedRepr::Internal(error) => error.into(),
+            QueryScopedRepr::Export(error) => error.into(),
             QueryScopedRepr::Server(error) => error.into(),
             QueryScopedRepr::Cancelled(error) => error.into(),
             QueryScopedRepr::SessionExpired(error) => error.into(),
@@ -36,6 +36,10 @@ pub(crate) enum Repr {
         error: InternalError,
         query_id: Option<Arc<str>>,
     },
+    Export {
+        error: ExportError,
+        query_id: Option<Arc<str>>,
+    },
     Other(Box<str>),
 }
 
@@ -43,6 +47,10 @@ pub(crate) enum Repr {
 pub(crate) enum ConfigError {
     InvalidUrl(Box<str>),
     HttpClientBuild(reqwest::Error),
+    Tls {
+        message: Box<str>,
+        source: Option<Box<dyn StdError + Send + Sync>>,
+    },
 }
 
 #[derive(Debug)]
@@ -129,7 +137,15 @@ pub(crate) enum ProtocolError {
     RowsetParse(RowsetParseError),
 }
 
+#[derive(Debug)]
+pub(crate) enum ExportError {
+    Write(std::io::Error),
+    Serialize(serde_json::Error),
+}
+
 #[derive(Debug)]
 pub(crate) enum InternalError {
     FutureJoin(JoinError),
+    #[cfg(feature = "parquet")]
+    ParquetEncode(parquet::errors::ParquetError),
 }
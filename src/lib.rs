@@ -69,6 +69,7 @@ mod client;
 mod config;
 pub mod decode;
 pub mod error;
+pub mod observer;
 mod result_cursor;
 mod result_table;
 mod rowset;
@@ -82,17 +83,26 @@ mod test_support;
 pub use auth::config::{AuthConfig, PasswordConfig};
 pub use client::Client;
 pub use config::{
-    ClientConfig, EndpointConfig, ProxyConfig, QueryConfig, SessionConfig, TransportConfig,
+    ClientConfig, ClientIdentity, EndpointConfig, ProxyConfig, QueryConfig, RetryPolicy,
+    SessionConfig, TlsVersion, TransportConfig,
+};
+pub use decode::{
+    CellPlan, CellPlanContext, CellRefPlan, FromCell, FromCellRef, FromRow, FromRowRef,
+    RowPlanContext,
 };
-pub use decode::{CellPlan, CellPlanContext, FromCell, FromRow, RowPlanContext};
 pub use error::{Error, ErrorKind, Result};
-pub use result_cursor::{CollectOptions, ResultCursor, TypedResultCursor};
+pub use observer::QueryObserver;
+pub use result_cursor::{CollectOptions, CsvOptions, ResultCursor, TypedResultCursor};
 pub use result_table::{
-    BinaryValue, CellRef, CellValue, Column, ColumnType, DecimalValue, DynamicRow, ResultTable,
-    RowRef, Rows, Schema, TypedResultTable, VectorValue,
+    BinaryFormat, BinaryValue, CellRef, CellValue, Column, ColumnLookup, ColumnType, DecimalFormat,
+    DecimalValue, DeserializeRows, DynamicRow, NonFiniteFloatFormat, PrettyTable, ResultTable,
+    RowRef, Rows, RowsRef, Schema, SerializeOptions, TypedResultTable, VectorElementType,
+    VectorValue,
 };
 pub use session::{QueryOptions, Session};
-pub use statement::builder::{IntoStatement, NamedBinds, PositionalBinds, Statement, UnboundBinds};
+pub use statement::builder::{
+    IntoBinds, IntoStatement, NamedBinds, PositionalBinds, Statement, UnboundBinds,
+};
 pub use statement::{QueryCancelStatus, QueryCanceller, QueryHandle};
 
 #[cfg(feature = "external-browser-sso")]
@@ -101,8 +111,11 @@ pub use auth::external_browser::{BrowserLaunchMode, ExternalBrowserConfig};
 #[cfg(feature = "key-pair-auth")]
 pub use auth::config::KeyPairConfig;
 
+#[cfg(feature = "ocsp")]
+pub use config::{RevocationConfig, RevocationMode};
+
 #[cfg(feature = "derive")]
-pub use snowflake_connector_rs_derive::FromRow;
+pub use snowflake_connector_rs_derive::{FromCell, FromRow, IntoBinds};
 
 #[cfg(feature = "bench-internals")]
 #[doc(hidden)]
@@ -0,0 +1,383 @@
+//! Query-lifecycle hooks for collecting metrics.
+//!
+//! Register a [`QueryObserver`] with [`ClientConfig::with_observer`](crate::ClientConfig::with_observer) to receive
+//! callbacks as queries are submitted, polled, downloaded, and parsed. Callbacks run inline on the task doing the
+//! work, so implementations should be cheap and must not block; forward to a metrics registry or a channel rather
+//! than doing I/O.
+//!
+//! Events carry identifiers, sizes, counts, and timings only. SQL text, bind values, and credentials are never
+//! passed to an observer.
+//!
+//! With the `metrics` feature, [`MetricsObserver`] exports these events through the
+//! [`metrics`](https://docs.rs/metrics) facade.
+
+#[cfg(feature = "metrics")]
+mod metrics;
+
+use std::{fmt, sync::Arc, time::Duration};
+
+use crate::ErrorKind;
+
+#[cfg(feature = "metrics")]
+pub use metrics::MetricsObserver;
+
+/// Callbacks for query-lifecycle events. Every method has a no-op default, so implementations override only the
+/// events they need.
+pub trait QueryObserver: Send + Sync + 'static {
+    /// A statement is about to be submitted.
+    fn on_submit(&self, event: &SubmitEvent) {
+        let _ = event;
+    }
+
+    /// The first response to a statement submit arrived, either with the result or with an in-progress marker.
+    fn on_first_response(&self, event: &FirstResponseEvent) {
+        let _ = event;
+    }
+
+    /// One poll request for an asynchronously executing query completed.
+    fn on_poll(&self, event: &PollEvent) {
+        let _ = event;
+    }
+
+    /// A remote result partition was downloaded.
+    fn on_partition_downloaded(&self, event: &PartitionDownloadEvent) {
+        let _ = event;
+    }
+
+    /// A result partition was parsed into a table.
+    fn on_parse(&self, event: &ParseEvent) {
+        let _ = event;
+    }
+
+    /// A transient failure is about to be retried under the [`RetryPolicy`](crate::RetryPolicy).
+    fn on_retry(&self, event: &RetryEvent) {
+        let _ = event;
+    }
+
+    /// Statement execution finished, successfully or not. Fires once per executed statement, before any result
+    /// partition is consumed.
+    fn on_complete(&self, event: &QueryCompleteEvent) {
+        let _ = event;
+    }
+}
+
+/// Reported by [`QueryObserver::on_submit`].
+#[derive(Debug, Clone)]
+pub struct SubmitEvent {
+    pub(crate) request_id: Arc<str>,
+    pub(crate) body_bytes: usize,
+}
+
+impl SubmitEvent {
+    /// The client-generated `requestId` of the submit.
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// Size of the serialized request body, including bind values.
+    pub fn body_bytes(&self) -> usize {
+        self.body_bytes
+    }
+}
+
+/// Reported by [`QueryObserver::on_first_response`].
+#[derive(Debug, Clone)]
+pub struct FirstResponseEvent {
+    pub(crate) request_id: Arc<str>,
+    pub(crate) query_id: Option<Arc<str>>,
+    pub(crate) elapsed: Duration,
+    pub(crate) response_bytes: usize,
+    pub(crate) in_progress: bool,
+}
+
+impl FirstResponseEvent {
+    /// The client-generated `requestId` of the submit.
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// The Snowflake query ID, when the response carried one.
+    pub fn query_id(&self) -> Option<&str> {
+        self.query_id.as_deref()
+    }
+
+    /// Time from sending the submit until the response was read, including retries.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Size of the response body.
+    pub fn response_bytes(&self) -> usize {
+        self.response_bytes
+    }
+
+    /// Whether the query is still running and its result must be polled.
+    pub fn in_progress(&self) -> bool {
+        self.in_progress
+    }
+}
+
+/// Reported by [`QueryObserver::on_poll`].
+#[derive(Debug, Clone)]
+pub struct PollEvent {
+    pub(crate) query_id: Arc<str>,
+    pub(crate) iteration: u32,
+    pub(crate) elapsed: Duration,
+    pub(crate) response_bytes: usize,
+    pub(crate) in_progress: bool,
+}
+
+impl PollEvent {
+    /// The Snowflake query ID being polled.
+    pub fn query_id(&self) -> &str {
+        &self.query_id
+    }
+
+    /// The 1-based number of this poll for the query.
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    /// Duration of this poll request, including retries.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Size of the response body.
+    pub fn response_bytes(&self) -> usize {
+        self.response_bytes
+    }
+
+    /// Whether the query was still running after this poll.
+    pub fn in_progress(&self) -> bool {
+        self.in_progress
+    }
+}
+
+/// Reported by [`QueryObserver::on_partition_downloaded`].
+#[derive(Debug, Clone)]
+pub struct PartitionDownloadEvent {
+    pub(crate) query_id: Arc<str>,
+    pub(crate) partition: usize,
+    pub(crate) row_count: i64,
+    pub(crate) compressed_size: i64,
+    pub(crate) uncompressed_size: i64,
+    pub(crate) downloaded_bytes: usize,
+    pub(crate) elapsed: Duration,
+    pub(crate) retries: u32,
+}
+
+impl PartitionDownloadEvent {
+    /// The Snowflake query ID the partition belongs to.
+    pub fn query_id(&self) -> &str {
+        &self.query_id
+    }
+
+    /// The partition's position in the result, counting an inline first partition.
+    pub fn partition(&self) -> usize {
+        self.partition
+    }
+
+    /// Row count announced by Snowflake for the partition.
+    pub fn row_count(&self) -> i64 {
+        self.row_count
+    }
+
+    /// Compressed size announced by Snowflake for the partition.
+    pub fn compressed_size(&self) -> i64 {
+        self.compressed_size
+    }
+
+    /// Uncompressed size announced by Snowflake for the partition.
+    pub fn uncompressed_size(&self) -> i64 {
+        self.uncompressed_size
+    }
+
+    /// Number of body bytes actually received.
+    pub fn downloaded_bytes(&self) -> usize {
+        self.downloaded_bytes
+    }
+
+    /// Time spent downloading, including retries and their backoff.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Number of retries before the download succeeded.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+}
+
+/// Reported by [`QueryObserver::on_parse`].
+#[derive(Debug, Clone)]
+pub struct ParseEvent {
+    pub(crate) query_id: Arc<str>,
+    pub(crate) input_bytes: usize,
+    pub(crate) row_count: usize,
+    pub(crate) elapsed: Duration,
+    pub(crate) blocking: bool,
+}
+
+impl ParseEvent {
+    /// The Snowflake query ID the partition belongs to.
+    pub fn query_id(&self) -> &str {
+        &self.query_id
+    }
+
+    /// Size of the parsed input as received, before decompression.
+    pub fn input_bytes(&self) -> usize {
+        self.input_bytes
+    }
+
+    /// Number of rows in the parsed table.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Time spent decompressing and parsing, excluding any wait for a blocking-parse slot.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Whether the parse ran on the blocking thread pool rather than inline on the async task.
+    pub fn blocking(&self) -> bool {
+        self.blocking
+    }
+}
+
+/// The request kind being retried, reported by [`RetryEvent::operation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RetryOperation {
+    /// The login request that opens a session.
+    Login,
+    /// The request that submits a statement for execution.
+    Submit,
+    /// A request polling an in-progress query for its result.
+    Poll,
+    /// The request that aborts a running query.
+    Abort,
+    /// A download of one remote result chunk.
+    ChunkDownload,
+}
+
+impl RetryOperation {
+    /// A stable lowercase name, suitable as a metric label.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::Submit => "submit",
+            Self::Poll => "poll",
+            Self::Abort => "abort",
+            Self::ChunkDownload => "chunk_download",
+        }
+    }
+}
+
+impl fmt::Display for RetryOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Reported by [`QueryObserver::on_retry`].
+#[derive(Debug, Clone)]
+pub struct RetryEvent {
+    pub(crate) operation: RetryOperation,
+    pub(crate) retry: u32,
+    pub(crate) delay: Duration,
+    pub(crate) status: Option<u16>,
+}
+
+impl RetryEvent {
+    /// The request kind being retried.
+    pub fn operation(&self) -> RetryOperation {
+        self.operation
+    }
+
+    /// The 1-based number of this retry.
+    pub fn retry(&self) -> u32 {
+        self.retry
+    }
+
+    /// The backoff before the retry is sent.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// The HTTP status that triggered the retry, or `None` for a connection failure.
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+}
+
+/// Reported by [`QueryObserver::on_complete`].
+#[derive(Debug, Clone)]
+pub struct QueryCompleteEvent {
+    pub(crate) request_id: Arc<str>,
+    pub(crate) query_id: Option<Arc<str>>,
+    pub(crate) elapsed: Duration,
+    pub(crate) row_count: Option<u64>,
+    pub(crate) partition_count: usize,
+    pub(crate) error: Option<ErrorKind>,
+}
+
+impl QueryCompleteEvent {
+    /// The client-generated `requestId` of the submit.
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+
+    /// The Snowflake query ID, when one was received.
+    pub fn query_id(&self) -> Option<&str> {
+        self.query_id.as_deref()
+    }
+
+    /// Time from submit until the result was ready to consume, or until execution failed.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Total rows in the result, when Snowflake reported row counts for every partition.
+    pub fn row_count(&self) -> Option<u64> {
+        self.row_count
+    }
+
+    /// Number of result partitions, inline and remote.
+    pub fn partition_count(&self) -> usize {
+        self.partition_count
+    }
+
+    /// The kind of error execution failed with, or `None` on success.
+    pub fn error(&self) -> Option<ErrorKind> {
+        self.error
+    }
+}
+
+/// The registered observer, if any, as carried through the connector internals.
+#[derive(Clone, Default)]
+pub(crate) struct ObserverHandle(Option<Arc<dyn QueryObserver>>);
+
+impl ObserverHandle {
+    pub(crate) fn new(observer: impl QueryObserver) -> Self {
+        Self(Some(Arc::new(observer)))
+    }
+
+    /// Run `notify` against the registered observer. Event construction stays inside the closure so that it costs
+    /// nothing when no observer is registered.
+    pub(crate) fn notify(&self, notify: impl FnOnce(&dyn QueryObserver)) {
+        if let Some(observer) = &self.0 {
+            notify(observer.as_ref());
+        }
+    }
+}
+
+impl fmt::Debug for ObserverHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("Some(<observer>)"),
+            None => f.write_str("None"),
+        }
+    }
+}
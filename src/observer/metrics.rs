@@ -0,0 +1,94 @@
+use metrics::{counter, histogram};
+
+use super::{
+    FirstResponseEvent, ParseEvent, PartitionDownloadEvent, PollEvent, QueryCompleteEvent,
+    QueryObserver, RetryEvent, SubmitEvent,
+};
+
+/// A [`QueryObserver`] that records query-lifecycle events through the [`metrics`](https://docs.rs/metrics) facade.
+///
+/// Install any `metrics` recorder (for example a Prometheus exporter) and register this observer with
+/// [`ClientConfig::with_observer`](crate::ClientConfig::with_observer). Durations are recorded in seconds and sizes in
+/// bytes:
+///
+/// | Metric | Type | Labels |
+/// |---|---|---|
+/// | `snowflake_queries_submitted_total` | counter | |
+/// | `snowflake_submit_request_bytes` | histogram | |
+/// | `snowflake_first_response_seconds` | histogram | |
+/// | `snowflake_poll_requests_total` | counter | |
+/// | `snowflake_poll_request_seconds` | histogram | |
+/// | `snowflake_partitions_downloaded_total` | counter | |
+/// | `snowflake_partition_download_seconds` | histogram | |
+/// | `snowflake_downloaded_bytes_total` | counter | |
+/// | `snowflake_uncompressed_bytes_total` | counter | |
+/// | `snowflake_parse_seconds` | histogram | `execution` (`inline`, `blocking`) |
+/// | `snowflake_parsed_bytes_total` | counter | |
+/// | `snowflake_retries_total` | counter | `operation` |
+/// | `snowflake_queries_completed_total` | counter | `outcome` (`success`, `error`) |
+/// | `snowflake_query_seconds` | histogram | `outcome` |
+/// | `snowflake_result_rows_total` | counter | |
+#[derive(Debug, Clone, Default)]
+pub struct MetricsObserver {
+    _private: (),
+}
+
+impl MetricsObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl QueryObserver for MetricsObserver {
+    fn on_submit(&self, event: &SubmitEvent) {
+        counter!("snowflake_queries_submitted_total").increment(1);
+        histogram!("snowflake_submit_request_bytes").record(event.body_bytes() as f64);
+    }
+
+    fn on_first_response(&self, event: &FirstResponseEvent) {
+        histogram!("snowflake_first_response_seconds").record(event.elapsed().as_secs_f64());
+    }
+
+    fn on_poll(&self, event: &PollEvent) {
+        counter!("snowflake_poll_requests_total").increment(1);
+        histogram!("snowflake_poll_request_seconds").record(event.elapsed().as_secs_f64());
+    }
+
+    fn on_partition_downloaded(&self, event: &PartitionDownloadEvent) {
+        counter!("snowflake_partitions_downloaded_total").increment(1);
+        histogram!("snowflake_partition_download_seconds").record(event.elapsed().as_secs_f64());
+        counter!("snowflake_downloaded_bytes_total").increment(event.downloaded_bytes() as u64);
+        if let Ok(uncompressed) = u64::try_from(event.uncompressed_size()) {
+            counter!("snowflake_uncompressed_bytes_total").increment(uncompressed);
+        }
+    }
+
+    fn on_parse(&self, event: &ParseEvent) {
+        let execution = if event.blocking() {
+            "blocking"
+        } else {
+            "inline"
+        };
+        histogram!("snowflake_parse_seconds", "execution" => execution)
+            .record(event.elapsed().as_secs_f64());
+        counter!("snowflake_parsed_bytes_total").increment(event.input_bytes() as u64);
+    }
+
+    fn on_retry(&self, event: &RetryEvent) {
+        counter!("snowflake_retries_total", "operation" => event.operation().as_str()).increment(1);
+    }
+
+    fn on_complete(&self, event: &QueryCompleteEvent) {
+        let outcome = if event.error().is_none() {
+            "success"
+        } else {
+            "error"
+        };
+        counter!("snowflake_queries_completed_total", "outcome" => outcome).increment(1);
+        histogram!("snowflake_query_seconds", "outcome" => outcome)
+            .record(event.elapsed().as_secs_f64());
+        if let Some(rows) = event.row_count() {
+            counter!("snowflake_result_rows_total").increment(rows);
+        }
+    }
+}
@@ -148,6 +148,7 @@ impl ResultCursor {
                     bytes,
                     row_count_hint,
                     Some(self.runtime.blocking_parse_limiter()),
+                    self.runtime.observer(),
                 )
                 .await?;
 
@@ -246,6 +247,24 @@ impl ResultCursor {
             .min(MAX_PREALLOCATED_ROWS)
     }
 
+    pub(crate) fn partition_count(&self) -> usize {
+        self.snapshot.partitions.len()
+    }
+
+    /// Total rows announced by Snowflake across all partitions, or `None` when any partition's count is unknown.
+    pub(crate) fn announced_row_count(&self) -> Option<u64> {
+        self.snapshot
+            .partitions
+            .iter()
+            .try_fold(0u64, |total, spec| {
+                let rows = match spec {
+                    PartitionSpec::Remote { row_count, .. } => u64::try_from(*row_count).ok()?,
+                    PartitionSpec::Inline => self.inline_rowset.as_ref()?.row_count_hint?,
+                };
+                total.checked_add(rows)
+            })
+    }
+
     async fn collect_dynamic_rows<C: FromIterator<DynamicRow>>(
         self,
         policy: CollectPolicy,
@@ -350,6 +369,7 @@ impl ResultCursor {
                 inline.bytes,
                 inline.row_count_hint,
                 Some(runtime.blocking_parse_limiter()),
+                runtime.observer(),
             )
             .await?;
             state = on_table(state, table)?;
@@ -857,6 +877,139 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn write_ndjson_streams_one_line_per_row_across_partitions() {
+        let result_set = build_result_set(
+            dummy_snapshot(2),
+            fake_source(vec![(0, vec![ok_rows(1)]), (1, vec![ok_rows(2)])]),
+        );
+
+        let mut out = Vec::new();
+        let written = result_set.write_ndjson(&mut out).await.unwrap();
+
+        assert_eq!(written, 3);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"X\":0}\n{\"X\":0}\n{\"X\":1}\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn write_ndjson_write_failure_is_an_export_error_with_query_id() {
+        let result_set =
+            build_result_set(dummy_snapshot(1), fake_source(vec![(0, vec![ok_rows(1)])]));
+        let (writer, reader) = tokio::io::duplex(16);
+        drop(reader);
+
+        let err = result_set.write_ndjson(writer).await.unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Export);
+        assert_eq!(err.query_id(), Some("test"));
+        assert_eq!(err.to_string(), "failed to write query results");
+    }
+
+    #[tokio::test]
+    async fn write_csv_writes_header_then_rows_across_partitions() {
+        let result_set = build_result_set(
+            dummy_snapshot(2),
+            fake_source(vec![(0, vec![ok_rows(1)]), (1, vec![ok_rows(2)])]),
+        );
+
+        let mut out = Vec::new();
+        let written = result_set
+            .write_csv(&mut out, crate::CsvOptions::new())
+            .await
+            .unwrap();
+
+        assert_eq!(written, 3);
+        assert_eq!(String::from_utf8(out).unwrap(), "X\n0\n0\n1\n");
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn write_parquet_write_failure_is_an_export_error_with_query_id() {
+        let result_set =
+            build_result_set(dummy_snapshot(1), fake_source(vec![(0, vec![ok_rows(1)])]));
+        let (writer, reader) = tokio::io::duplex(16);
+        drop(reader);
+
+        let err = result_set.write_parquet(writer).await.unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Export);
+        assert_eq!(err.query_id(), Some("test"));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn write_parquet_writes_one_row_group_per_partition_as_wide_decimals() {
+        use parquet::{
+            file::reader::{FileReader, SerializedFileReader},
+            record::RowAccessor,
+        };
+
+        let result_set = build_result_set(
+            dummy_snapshot(2),
+            fake_source(vec![(0, vec![ok_rows(1)]), (1, vec![ok_rows(2)])]),
+        );
+
+        let mut out = Vec::new();
+        let written = result_set.write_parquet(&mut out).await.unwrap();
+
+        assert_eq!(written, 3);
+        let reader = SerializedFileReader::new(Bytes::from(out)).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.num_row_groups(), 2);
+        assert_eq!(metadata.file_metadata().num_rows(), 3);
+        let column = metadata.file_metadata().schema_descr().column(0);
+        assert_eq!(column.name(), "X");
+        assert_eq!(
+            column.physical_type(),
+            parquet::basic::Type::FIXED_LEN_BYTE_ARRAY
+        );
+        assert_eq!(
+            column.logical_type(),
+            Some(parquet::basic::LogicalType::Decimal {
+                scale: 0,
+                precision: 38
+            })
+        );
+        let values = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| {
+                let decimal = row.unwrap().get_decimal(0).unwrap().clone();
+                i128::from_be_bytes(decimal.data().try_into().unwrap())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, [0, 0, 1]);
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn write_parquet_value_failure_reports_result_set_row_and_raw_text() {
+        let too_wide = "1".repeat(40);
+        let result_set = build_result_set(
+            dummy_snapshot(2),
+            fake_source(vec![
+                (0, vec![ok_rows(1)]),
+                (
+                    1,
+                    vec![Ok(vec![
+                        vec![Some("7".into())],
+                        vec![Some(too_wide.clone())],
+                    ])],
+                ),
+            ]),
+        );
+
+        let err = result_set.write_parquet(Vec::new()).await.unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Decode);
+        let cell = err.as_cell_decode_error().unwrap();
+        assert_eq!(cell.row_index(), 2);
+        assert_eq!(cell.raw_value_preview(), Some(too_wide.as_str()));
+    }
+
     #[tokio::test]
     async fn typed_result_set_collect_with_options_builds_plan_once() {
         reset_build_plan_calls();
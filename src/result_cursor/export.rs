@@ -0,0 +1,402 @@
+use std::{borrow::Cow, sync::Arc};
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    Result,
+    error::{ExportError, QueryScopedError, QueryScopedRepr},
+    result_table::{
+        BinaryFormat, CellValue, SerializeOptions, SessionZone, check_unique_labels, encode_binary,
+    },
+};
+
+use super::ResultCursor;
+
+#[cfg(feature = "parquet")]
+mod parquet;
+
+/// Buffered output is handed to the writer once it reaches this many bytes.
+const WRITE_THRESHOLD: usize = 64 * 1024;
+
+/// Options for [`ResultCursor::write_csv`].
+///
+/// The defaults use RFC 4180 quoting with `\n` line endings: comma-separated, a header row of raw column labels, and
+/// fields quoted only when they need to be. SQL `NULL` is written as an empty unquoted field, and an empty string as
+/// `""`, so the two stay distinguishable.
+///
+/// # Example
+///
+/// ```
+/// use snowflake_connector_rs::{BinaryFormat, CsvOptions};
+///
+/// let options = CsvOptions::new()
+///     .with_delimiter(b'\t')
+///     .with_null_text("\\N")
+///     .with_binary_format(BinaryFormat::Base64);
+/// assert_eq!(options.delimiter(), b'\t');
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    delimiter: u8,
+    header: bool,
+    null_text: Box<str>,
+    binary: BinaryFormat,
+    ltz_in_session_timezone: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            header: true,
+            null_text: "".into(),
+            binary: BinaryFormat::Hex,
+            ltz_in_session_timezone: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the field delimiter. Defaults to `b','`.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether a header row of raw column labels is written first. Defaults to `true`.
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets the text written for SQL `NULL`. Defaults to the empty string.
+    ///
+    /// A non-`NULL` value that renders to the same text is quoted.
+    pub fn with_null_text(mut self, null_text: impl Into<Box<str>>) -> Self {
+        self.null_text = null_text.into();
+        self
+    }
+
+    /// Sets how `BINARY` values are rendered. Defaults to [`BinaryFormat::Hex`], matching Snowflake's unload format.
+    pub fn with_binary_format(mut self, format: BinaryFormat) -> Self {
+        self.binary = format;
+        self
+    }
+
+    /// Sets whether `TIMESTAMP_LTZ` values are written in the session time zone
+    /// ([`Schema::session_timezone`](crate::Schema::session_timezone)) instead of UTC, matching the wall-clock time
+    /// Snowflake shows. Values stay in UTC when the session `TIMEZONE` is absent or not an IANA zone name. Defaults to
+    /// `false`.
+    #[cfg(feature = "chrono-tz")]
+    pub fn with_ltz_in_session_timezone(mut self, enabled: bool) -> Self {
+        self.ltz_in_session_timezone = enabled;
+        self
+    }
+
+    pub fn delimiter(&self) -> u8 {
+        self.delimiter
+    }
+
+    pub fn header(&self) -> bool {
+        self.header
+    }
+
+    pub fn null_text(&self) -> &str {
+        &self.null_text
+    }
+
+    pub fn binary_format(&self) -> BinaryFormat {
+        self.binary
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    pub fn ltz_in_session_timezone(&self) -> bool {
+        self.ltz_in_session_timezone
+    }
+}
+
+impl ResultCursor {
+    /// Consume this result set and write every remaining row to `writer` as newline-delimited JSON.
+    ///
+    /// Each line is one row rendered by [`DynamicRow`](crate::DynamicRow)'s `Serialize` impl: an object keyed by raw
+    /// column label. Partitions are fetched one at a time and written as they are decoded, so no more than one
+    /// partition is held in memory. The writer is flushed before returning. Returns the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorKind::Decode` when two columns share a label or a cell fails to decode, `ErrorKind::Export` when
+    /// a row cannot be serialized or writing to `writer` fails, and the errors of [`next_table`](Self::next_table)
+    /// when fetching a partition fails. Rows written before the failure stay written.
+    pub async fn write_ndjson<W: AsyncWrite + Unpin>(self, writer: W) -> Result<u64> {
+        self.write_ndjson_with_options(writer, SerializeOptions::default())
+            .await
+    }
+
+    /// Like [`write_ndjson`](Self::write_ndjson), but renders decimals, binary values, and non-finite floats as
+    /// configured by `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`write_ndjson`](Self::write_ndjson).
+    pub async fn write_ndjson_with_options<W: AsyncWrite + Unpin>(
+        mut self,
+        writer: W,
+        options: SerializeOptions,
+    ) -> Result<u64> {
+        check_unique_labels(self.schema())?;
+
+        let mut sink = ExportSink::new(writer, self.query_id());
+        let mut written = 0u64;
+        while let Some(table) = self.next_table().await? {
+            for row in table.dynamic_rows()? {
+                let row = row?;
+                serde_json::to_writer(sink.buf(), &row.serialize_with(&options))
+                    .map_err(|e| sink.error(ExportError::serialize(e)))?;
+                sink.buf().push(b'\n');
+                written += 1;
+                sink.write_if_full().await?;
+            }
+        }
+        sink.finish().await?;
+
+        Ok(written)
+    }
+
+    /// Consume this result set and write every remaining row to `writer` as CSV.
+    ///
+    /// Cells are rendered as text: numbers and decimals as Snowflake delivered them, dates, times, and timestamps in
+    /// the ISO 8601 forms used by [`CellValue::into_json_value`], `VARIANT` / `OBJECT` / `ARRAY` and `VECTOR` values as
+    /// compact JSON, and `BINARY` as configured by `options`. Partitions are fetched one at a time and written as they
+    /// are decoded. The writer is flushed before returning. Returns the number of data rows written, excluding the
+    /// header.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorKind::Decode` when a cell fails to decode, `ErrorKind::Export` when writing to `writer` fails, and
+    /// the errors of [`next_table`](Self::next_table) when fetching a partition fails. Rows written before the failure
+    /// stay written.
+    pub async fn write_csv<W: AsyncWrite + Unpin>(
+        mut self,
+        writer: W,
+        options: CsvOptions,
+    ) -> Result<u64> {
+        let mut sink = ExportSink::new(writer, self.query_id());
+        if options.header {
+            for (idx, col) in self.schema().columns().iter().enumerate() {
+                if idx > 0 {
+                    sink.buf().push(options.delimiter);
+                }
+                push_csv_field(sink.buf(), col.name(), false, &options);
+            }
+            sink.buf().push(b'\n');
+        }
+
+        let zone = self.schema().ltz_zone(options.ltz_in_session_timezone);
+        let mut written = 0u64;
+        while let Some(table) = self.next_table().await? {
+            for row in table.dynamic_rows()? {
+                let row = row?;
+                for (idx, value) in row.values().iter().enumerate() {
+                    if idx > 0 {
+                        sink.buf().push(options.delimiter);
+                    }
+                    match csv_text(value, &options, zone) {
+                        Some(text) => push_csv_field(sink.buf(), &text, true, &options),
+                        None => sink.buf().extend_from_slice(options.null_text.as_bytes()),
+                    }
+                }
+                sink.buf().push(b'\n');
+                written += 1;
+                sink.write_if_full().await?;
+            }
+        }
+        sink.finish().await?;
+
+        Ok(written)
+    }
+}
+
+/// Buffers export output and hands it to an async writer in [`WRITE_THRESHOLD`]-sized batches.
+struct ExportSink<W> {
+    writer: W,
+    buf: Vec<u8>,
+    query_id: Arc<str>,
+}
+
+impl<W: AsyncWrite + Unpin> ExportSink<W> {
+    fn new(writer: W, query_id: &str) -> Self {
+        Self {
+            writer,
+            buf: Vec::with_capacity(WRITE_THRESHOLD),
+            query_id: Arc::from(query_id),
+        }
+    }
+
+    fn buf(&mut self) -> &mut Vec<u8> {
+        &mut self.buf
+    }
+
+    async fn write_if_full(&mut self) -> Result<()> {
+        if self.buf.len() >= WRITE_THRESHOLD {
+            self.write_buffered().await?;
+        }
+        Ok(())
+    }
+
+    async fn write_buffered(&mut self) -> Result<()> {
+        match self.writer.write_all(&self.buf).await {
+            Ok(()) => {
+                self.buf.clear();
+                Ok(())
+            }
+            Err(e) => Err(self.error(ExportError::write(e))),
+        }
+    }
+
+    async fn finish(mut self) -> Result<()> {
+        self.write_buffered().await?;
+        self.writer
+            .flush()
+            .await
+            .map_err(|e| self.error(ExportError::write(e)))
+    }
+
+    fn error(&self, error: impl Into<QueryScopedRepr>) -> crate::Error {
+        QueryScopedError::new(Arc::clone(&self.query_id), error).into()
+    }
+}
+
+/// The CSV text of a cell, or `None` for SQL `NULL`. `TIMESTAMP_LTZ` values are written in `zone`.
+fn csv_text<'a>(
+    value: &'a CellValue,
+    options: &CsvOptions,
+    zone: SessionZone,
+) -> Option<Cow<'a, str>> {
+    let text = match value {
+        CellValue::Null => return None,
+        CellValue::Boolean(b) => Cow::Borrowed(if *b { "true" } else { "false" }),
+        CellValue::Integer(i) => Cow::Owned(i.to_string()),
+        CellValue::Float(f) => Cow::Owned(f.to_string()),
+        CellValue::Decimal(d) | CellValue::DecFloat(d) => Cow::Borrowed(d.raw()),
+        CellValue::String(s) => Cow::Borrowed(s.as_str()),
+        CellValue::Binary(bytes) => Cow::Owned(encode_binary(bytes.as_bytes(), options.binary)),
+        CellValue::Json(value) => Cow::Owned(value.to_string()),
+        CellValue::TimestampLtz(dt) => Cow::Owned(zone.ltz_rfc3339(dt)),
+        other => match other.clone().into_json_value() {
+            serde_json::Value::String(s) => Cow::Owned(s),
+            json => Cow::Owned(json.to_string()),
+        },
+    };
+    Some(text)
+}
+
+/// Append `text` as one CSV field, quoting it when it holds a delimiter, quote, or line break, or when a value would
+/// otherwise read back as `NULL`.
+fn push_csv_field(out: &mut Vec<u8>, text: &str, is_value: bool, options: &CsvOptions) {
+    let needs_quotes = text
+        .bytes()
+        .any(|b| b == options.delimiter || matches!(b, b'"' | b'\n' | b'\r'))
+        || (is_value && text == &*options.null_text);
+    if !needs_quotes {
+        out.extend_from_slice(text.as_bytes());
+        return;
+    }
+
+    out.push(b'"');
+    for b in text.bytes() {
+        if b == b'"' {
+            out.push(b'"');
+        }
+        out.push(b);
+    }
+    out.push(b'"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(value: &CellValue, options: &CsvOptions) -> String {
+        let mut out = Vec::new();
+        match csv_text(value, options, SessionZone::default()) {
+            Some(text) => push_csv_field(&mut out, &text, true, options),
+            None => out.extend_from_slice(options.null_text().as_bytes()),
+        }
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn csv_fields_quote_only_when_needed_and_keep_null_distinct() {
+        let options = CsvOptions::new();
+        let text = |s: &str| CellValue::String(s.to_string());
+
+        assert_eq!(field(&text("plain"), &options), "plain");
+        assert_eq!(field(&text("a,b"), &options), "\"a,b\"");
+        assert_eq!(field(&text("say \"hi\""), &options), "\"say \"\"hi\"\"\"");
+        assert_eq!(field(&text("two\nlines"), &options), "\"two\nlines\"");
+        assert_eq!(field(&text(""), &options), "\"\"");
+        assert_eq!(field(&CellValue::Null, &options), "");
+
+        let tsv = CsvOptions::new()
+            .with_delimiter(b'\t')
+            .with_null_text("\\N");
+        assert_eq!(field(&text("a,b"), &tsv), "a,b");
+        assert_eq!(field(&text("a\tb"), &tsv), "\"a\tb\"");
+        assert_eq!(field(&text("\\N"), &tsv), "\"\\N\"");
+        assert_eq!(field(&CellValue::Null, &tsv), "\\N");
+    }
+
+    #[test]
+    fn csv_fields_render_typed_values_as_text() {
+        let options = CsvOptions::new();
+
+        assert_eq!(field(&CellValue::Boolean(true), &options), "true");
+        assert_eq!(field(&CellValue::Float(f64::NAN), &options), "NaN");
+        assert_eq!(
+            field(&CellValue::Binary(vec![0xCA, 0xFE].into()), &options),
+            "CAFE"
+        );
+        assert_eq!(
+            field(
+                &CellValue::Binary(vec![0xCA, 0xFE].into()),
+                &options.clone().with_binary_format(BinaryFormat::Base64)
+            ),
+            "yv4="
+        );
+        assert_eq!(
+            field(
+                &CellValue::Json(serde_json::json!({"a": [1, "x"]})),
+                &options
+            ),
+            "\"{\"\"a\"\":[1,\"\"x\"\"]}\""
+        );
+        assert_eq!(
+            field(
+                &CellValue::Date(chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()),
+                &options
+            ),
+            "2024-02-29"
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn ltz_values_are_written_in_the_session_zone_when_asked() {
+        let schema = crate::Schema::from_columns(Vec::new())
+            .with_session_timezone(Some(Arc::from("America/Los_Angeles")));
+        let value =
+            CellValue::TimestampLtz(chrono::DateTime::from_timestamp(1_705_320_000, 0).unwrap());
+        let options = CsvOptions::new();
+        let text = |zoned: bool| {
+            let zone = schema.ltz_zone(zoned);
+            csv_text(&value, &options, zone).unwrap().into_owned()
+        };
+
+        assert_eq!(text(false), "2024-01-15T12:00:00+00:00");
+        assert_eq!(text(true), "2024-01-15T04:00:00-08:00");
+    }
+}
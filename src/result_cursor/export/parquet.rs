@@ -0,0 +1,547 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use parquet::{
+    basic::{Compression, LogicalType, Repetition, TimeUnit, Type as PhysicalType},
+    data_type::{
+        BoolType, ByteArray, ByteArrayType, DoubleType, FixedLenByteArray, FixedLenByteArrayType,
+        Int32Type, Int64Type,
+    },
+    errors::ParquetError,
+    file::{
+        properties::WriterProperties,
+        writer::{SerializedColumnWriter, SerializedFileWriter},
+    },
+    format::{MicroSeconds, MilliSeconds, NanoSeconds},
+    schema::types::Type,
+};
+use tokio::io::AsyncWrite;
+
+use crate::{
+    Result,
+    error::{CellDecodeError, InternalError},
+    result_table::{
+        CellConversionError, CellValue, ColumnType, RowRefs, Schema, check_unique_labels,
+        parse_decimal_unscaled,
+    },
+};
+
+use super::{ExportSink, ResultCursor};
+
+/// Bytes used for `DECIMAL` columns too wide for `INT64`; 16 bytes hold all 38 digits of a Snowflake `NUMBER`.
+const WIDE_DECIMAL_BYTES: usize = 16;
+
+impl ResultCursor {
+    /// Consume this result set and write every remaining row to `writer` as a Parquet file.
+    ///
+    /// Each partition becomes one Snappy-compressed row group, written as soon as it has been fetched and decoded, so
+    /// no more than one partition is held in memory. Every column is `OPTIONAL` and its type follows the Snowflake
+    /// column type:
+    ///
+    /// | Snowflake type | Parquet type |
+    /// |---|---|
+    /// | `BOOLEAN` | `BOOLEAN` |
+    /// | `NUMBER(p, 0)`, `p <= 18` | `INT64` |
+    /// | other `NUMBER(p, s)` | `DECIMAL(p, s)` as `INT32` (`p <= 9`), `INT64` (`p <= 18`), or a 16-byte `FIXED_LEN_BYTE_ARRAY` |
+    /// | `FLOAT` | `DOUBLE` |
+    /// | `TEXT`, `GEOGRAPHY`, `GEOMETRY` | `STRING` |
+    /// | `VARIANT`, `OBJECT`, `ARRAY`, `VECTOR` | `JSON` |
+    /// | `BINARY` | `BYTE_ARRAY` |
+    /// | `DATE` | `DATE` |
+    /// | `TIME(s)` | `TIME` in milliseconds (`s <= 3`), microseconds (`s <= 6`), or nanoseconds |
+    /// | `TIMESTAMP_NTZ(s)` | `TIMESTAMP` with the same unit choice, not adjusted to UTC |
+    /// | `TIMESTAMP_LTZ(s)`, `TIMESTAMP_TZ(s)` | `TIMESTAMP` with the same unit choice, adjusted to UTC |
+    ///
+    /// `TIMESTAMP_TZ` values keep their instant but not their offset. The writer is flushed before returning. Returns
+    /// the number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ErrorKind::Decode` when two columns share a label, a cell fails to decode, or a value does not fit its
+    /// Parquet type (a nanosecond timestamp outside 1677–2262, for example); `ErrorKind::Internal` when the Parquet
+    /// encoder rejects the schema or a row group; `ErrorKind::Export` when writing to `writer` fails; and the errors
+    /// of [`next_table`](Self::next_table) when fetching a partition fails. Output written before the failure is not
+    /// a complete Parquet file.
+    pub async fn write_parquet<W: AsyncWrite + Unpin>(mut self, writer: W) -> Result<u64> {
+        check_unique_labels(self.schema())?;
+
+        let mut sink = ExportSink::new(writer, self.query_id());
+        let targets = self
+            .schema()
+            .columns()
+            .iter()
+            .map(|col| Target::for_column(col.ty()))
+            .collect::<Vec<_>>();
+        let parquet_schema = message_type(self.schema(), &targets)
+            .map_err(|e| sink.error(InternalError::parquet_encode(e)))?;
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let mut file =
+            SerializedFileWriter::new(Vec::new(), Arc::new(parquet_schema), Arc::new(properties))
+                .map_err(|e| sink.error(InternalError::parquet_encode(e)))?;
+
+        let mut written = 0u64;
+        while let Some(table) = self.next_table().await? {
+            if table.is_empty() {
+                continue;
+            }
+
+            let mut buffers = targets
+                .iter()
+                .map(|target| ColumnBuffer::new(*target, table.row_count()))
+                .collect::<Vec<_>>();
+            for (row, row_ref) in table.dynamic_rows()?.zip(RowRefs::new(&table)) {
+                let row = row?;
+                for ((buffer, col), value) in buffers
+                    .iter_mut()
+                    .zip(table.schema().columns())
+                    .zip(row.values())
+                {
+                    buffer.push(value).map_err(|issue| {
+                        // Rows are counted across partitions, so `written` is the row's index in the result set.
+                        CellDecodeError::new(
+                            written as usize,
+                            col.index(),
+                            col.name(),
+                            buffer.target.type_name(),
+                            col.ty().clone(),
+                            row_ref.cell_at_offset(col, col.index()).raw(),
+                            issue,
+                        )
+                    })?;
+                }
+                written += 1;
+            }
+
+            write_row_group(&mut file, &buffers)
+                .map_err(|e| sink.error(InternalError::parquet_encode(e)))?;
+            sink.buf().append(file.inner_mut());
+            sink.write_if_full().await?;
+        }
+
+        let rest = file
+            .into_inner()
+            .map_err(|e| sink.error(InternalError::parquet_encode(e)))?;
+        sink.buf().extend_from_slice(&rest);
+        sink.finish().await?;
+
+        Ok(written)
+    }
+}
+
+/// Precision of a Parquet `TIME` / `TIMESTAMP`, chosen from the Snowflake column scale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Unit {
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl Unit {
+    /// Snowflake defaults time and timestamp columns to scale 9.
+    fn for_scale(scale: Option<u8>) -> Self {
+        match scale.unwrap_or(9) {
+            0..=3 => Unit::Millis,
+            4..=6 => Unit::Micros,
+            _ => Unit::Nanos,
+        }
+    }
+
+    fn parquet(self) -> TimeUnit {
+        match self {
+            Unit::Millis => TimeUnit::MILLIS(MilliSeconds::new()),
+            Unit::Micros => TimeUnit::MICROS(MicroSeconds::new()),
+            Unit::Nanos => TimeUnit::NANOS(NanoSeconds::new()),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Unit::Millis => "MILLIS",
+            Unit::Micros => "MICROS",
+            Unit::Nanos => "NANOS",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DecimalStorage {
+    Int32,
+    Int64,
+    Wide,
+}
+
+/// The Parquet column a Snowflake column is written as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Target {
+    Boolean,
+    Int64,
+    Decimal {
+        precision: u8,
+        scale: u8,
+        storage: DecimalStorage,
+    },
+    Double,
+    String,
+    Json,
+    Binary,
+    Date,
+    Time(Unit),
+    Timestamp {
+        unit: Unit,
+        adjusted_to_utc: bool,
+    },
+}
+
+impl Target {
+    fn for_column(ty: &ColumnType) -> Self {
+        match ty {
+            ColumnType::Boolean => Target::Boolean,
+            ColumnType::Fixed { precision, scale } => {
+                let precision = precision.unwrap_or(38).clamp(1, 38);
+                let scale = scale.unwrap_or(0).min(precision);
+                if scale == 0 && precision <= 18 {
+                    return Target::Int64;
+                }
+                let storage = match precision {
+                    1..=9 => DecimalStorage::Int32,
+                    10..=18 => DecimalStorage::Int64,
+                    _ => DecimalStorage::Wide,
+                };
+                Target::Decimal {
+                    precision,
+                    scale,
+                    storage,
+                }
+            }
+            ColumnType::Real => Target::Double,
+            // A Parquet DECIMAL cannot span DECFLOAT's exponent range, so the exact text is kept instead.
+            ColumnType::DecFloat
+            | ColumnType::Text { .. }
+            | ColumnType::Geography
+            | ColumnType::Geometry
+            | ColumnType::Unknown { .. } => Target::String,
+            ColumnType::Variant
+            | ColumnType::Object { .. }
+            | ColumnType::Array { .. }
+            | ColumnType::Map { .. }
+            | ColumnType::Vector { .. } => Target::Json,
+            ColumnType::Binary { .. } => Target::Binary,
+            ColumnType::Date => Target::Date,
+            ColumnType::Time { scale } => Target::Time(Unit::for_scale(*scale)),
+            ColumnType::TimestampNtz { scale } => Target::Timestamp {
+                unit: Unit::for_scale(*scale),
+                adjusted_to_utc: false,
+            },
+            ColumnType::TimestampLtz { scale } | ColumnType::TimestampTz { scale } => {
+                Target::Timestamp {
+                    unit: Unit::for_scale(*scale),
+                    adjusted_to_utc: true,
+                }
+            }
+        }
+    }
+
+    /// Names the Parquet type in decode errors.
+    fn type_name(self) -> String {
+        match self {
+            Target::Boolean => "parquet BOOLEAN".to_string(),
+            Target::Int64 => "parquet INT64".to_string(),
+            Target::Decimal {
+                precision, scale, ..
+            } => format!("parquet DECIMAL({precision}, {scale})"),
+            Target::Double => "parquet DOUBLE".to_string(),
+            Target::String => "parquet STRING".to_string(),
+            Target::Json => "parquet JSON".to_string(),
+            Target::Binary => "parquet BYTE_ARRAY".to_string(),
+            Target::Date => "parquet DATE".to_string(),
+            Target::Time(unit) => format!("parquet TIME({})", unit.name()),
+            Target::Timestamp { unit, .. } => format!("parquet TIMESTAMP({})", unit.name()),
+        }
+    }
+
+    fn field(self, name: &str) -> std::result::Result<Type, ParquetError> {
+        let (physical, logical) = match self {
+            Target::Boolean => (PhysicalType::BOOLEAN, None),
+            Target::Int64 => (
+                PhysicalType::INT64,
+                Some(LogicalType::Integer {
+                    bit_width: 64,
+                    is_signed: true,
+                }),
+            ),
+            Target::Decimal {
+                precision,
+                scale,
+                storage,
+            } => {
+                let physical = match storage {
+                    DecimalStorage::Int32 => PhysicalType::INT32,
+                    DecimalStorage::Int64 => PhysicalType::INT64,
+                    DecimalStorage::Wide => PhysicalType::FIXED_LEN_BYTE_ARRAY,
+                };
+                let mut builder = Type::primitive_type_builder(name, physical)
+                    .with_repetition(Repetition::OPTIONAL)
+                    .with_logical_type(Some(LogicalType::Decimal {
+                        scale: i32::from(scale),
+                        precision: i32::from(precision),
+                    }))
+                    .with_precision(i32::from(precision))
+                    .with_scale(i32::from(scale));
+                if storage == DecimalStorage::Wide {
+                    builder = builder.with_length(WIDE_DECIMAL_BYTES as i32);
+                }
+                return builder.build();
+            }
+            Target::Double => (PhysicalType::DOUBLE, None),
+            Target::String => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+            Target::Json => (PhysicalType::BYTE_ARRAY, Some(LogicalType::Json)),
+            Target::Binary => (PhysicalType::BYTE_ARRAY, None),
+            Target::Date => (PhysicalType::INT32, Some(LogicalType::Date)),
+            Target::Time(unit) => (
+                if unit == Unit::Millis {
+                    PhysicalType::INT32
+                } else {
+                    PhysicalType::INT64
+                },
+                Some(LogicalType::Time {
+                    is_adjusted_to_u_t_c: false,
+                    unit: unit.parquet(),
+                }),
+            ),
+            Target::Timestamp {
+                unit,
+                adjusted_to_utc,
+            } => (
+                PhysicalType::INT64,
+                Some(LogicalType::Timestamp {
+                    is_adjusted_to_u_t_c: adjusted_to_utc,
+                    unit: unit.parquet(),
+                }),
+            ),
+        };
+        Type::primitive_type_builder(name, physical)
+            .with_repetition(Repetition::OPTIONAL)
+            .with_logical_type(logical)
+            .build()
+    }
+}
+
+fn message_type(schema: &Schema, targets: &[Target]) -> std::result::Result<Type, ParquetError> {
+    let fields = schema
+        .columns()
+        .iter()
+        .zip(targets)
+        .map(|(col, target)| target.field(col.name()).map(Arc::new))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Type::group_type_builder("schema")
+        .with_fields(fields)
+        .build()
+}
+
+/// Values of one column for one row group, in the physical type of its [`Target`].
+enum Values {
+    Boolean(Vec<bool>),
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    Double(Vec<f64>),
+    Bytes(Vec<ByteArray>),
+    Fixed(Vec<FixedLenByteArray>),
+}
+
+struct ColumnBuffer {
+    target: Target,
+    values: Values,
+    /// `1` for a present value, `0` for SQL `NULL`.
+    def_levels: Vec<i16>,
+}
+
+impl ColumnBuffer {
+    fn new(target: Target, capacity: usize) -> Self {
+        let values = match target {
+            Target::Boolean => Values::Boolean(Vec::with_capacity(capacity)),
+            Target::Decimal {
+                storage: DecimalStorage::Int32,
+                ..
+            }
+            | Target::Date
+            | Target::Time(Unit::Millis) => Values::Int32(Vec::with_capacity(capacity)),
+            Target::Int64
+            | Target::Decimal {
+                storage: DecimalStorage::Int64,
+                ..
+            }
+            | Target::Time(_)
+            | Target::Timestamp { .. } => Values::Int64(Vec::with_capacity(capacity)),
+            Target::Decimal {
+                storage: DecimalStorage::Wide,
+                ..
+            } => Values::Fixed(Vec::with_capacity(capacity)),
+            Target::Double => Values::Double(Vec::with_capacity(capacity)),
+            Target::String | Target::Json | Target::Binary => {
+                Values::Bytes(Vec::with_capacity(capacity))
+            }
+        };
+        Self {
+            target,
+            values,
+            def_levels: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, value: &CellValue) -> std::result::Result<(), CellConversionError> {
+        if value.is_null() {
+            self.def_levels.push(0);
+            return Ok(());
+        }
+
+        match (&mut self.values, self.target, value) {
+            (Values::Boolean(values), _, CellValue::Boolean(b)) => values.push(*b),
+            (Values::Int64(values), Target::Int64, CellValue::Integer(i)) => {
+                values.push(i64::try_from(*i).map_err(|_| out_of_range(value))?);
+            }
+            (Values::Int32(values), Target::Decimal { scale, .. }, _) => {
+                let unscaled = unscaled_decimal(value, scale)?;
+                values.push(i32::try_from(unscaled).map_err(|_| out_of_range(value))?);
+            }
+            (Values::Int64(values), Target::Decimal { scale, .. }, _) => {
+                let unscaled = unscaled_decimal(value, scale)?;
+                values.push(i64::try_from(unscaled).map_err(|_| out_of_range(value))?);
+            }
+            (Values::Fixed(values), Target::Decimal { scale, .. }, _) => {
+                let unscaled = unscaled_decimal(value, scale)?;
+                values.push(FixedLenByteArray::from(unscaled.to_be_bytes().to_vec()));
+            }
+            (Values::Double(values), _, CellValue::Float(f)) => values.push(*f),
+            (Values::Bytes(values), _, CellValue::String(s)) => values.push(s.as_str().into()),
+            (Values::Bytes(values), _, CellValue::DecFloat(d)) => values.push(d.raw().into()),
+            (Values::Bytes(values), _, CellValue::Json(json)) => {
+                values.push(json.to_string().into_bytes().into());
+            }
+            (Values::Bytes(values), _, CellValue::Vector(_)) => {
+                let json = serde_json::to_vec(value).map_err(|e| {
+                    CellConversionError::builder(format!("cannot render VECTOR as JSON: {e}"))
+                        .source(e)
+                        .build()
+                })?;
+                values.push(json.into());
+            }
+            (Values::Bytes(values), _, CellValue::Binary(bytes)) => {
+                values.push(bytes.clone().into_bytes().into());
+            }
+            (Values::Int32(values), Target::Date, CellValue::Date(date)) => {
+                let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("epoch");
+                let days = date.signed_duration_since(epoch).num_days();
+                values.push(i32::try_from(days).map_err(|_| out_of_range(value))?);
+            }
+            (values, Target::Time(unit), CellValue::Time(time)) => {
+                let nanos = i64::from(time.num_seconds_from_midnight()) * 1_000_000_000
+                    + i64::from(time.nanosecond());
+                match (values, unit) {
+                    (Values::Int32(values), Unit::Millis) => {
+                        values.push(i32::try_from(nanos / 1_000_000).expect("a day fits i32"));
+                    }
+                    (Values::Int64(values), Unit::Micros) => values.push(nanos / 1_000),
+                    (Values::Int64(values), Unit::Nanos) => values.push(nanos),
+                    _ => return Err(unexpected_value(value, self.target)),
+                }
+            }
+            (Values::Int64(values), Target::Timestamp { unit, .. }, _) => {
+                let instant = match value {
+                    CellValue::TimestampNtz(dt) => dt.and_utc(),
+                    CellValue::TimestampLtz(dt) => *dt,
+                    CellValue::TimestampTz(dt) => dt.with_timezone(&Utc),
+                    _ => return Err(unexpected_value(value, self.target)),
+                };
+                values.push(timestamp_in_unit(instant, unit).ok_or_else(|| out_of_range(value))?);
+            }
+            _ => return Err(unexpected_value(value, self.target)),
+        }
+        self.def_levels.push(1);
+        Ok(())
+    }
+}
+
+fn unscaled_decimal(
+    value: &CellValue,
+    scale: u8,
+) -> std::result::Result<i128, CellConversionError> {
+    match value {
+        CellValue::Integer(i) => i
+            .checked_mul(10i128.pow(u32::from(scale)))
+            .ok_or_else(|| out_of_range(value)),
+        CellValue::Decimal(d) => parse_decimal_unscaled(d.raw(), scale)
+            .map_err(|reason| CellConversionError::builder(reason).build()),
+        _ => Err(
+            CellConversionError::builder(format!("expected a NUMBER value, got {value:?}")).build(),
+        ),
+    }
+}
+
+fn timestamp_in_unit(instant: DateTime<Utc>, unit: Unit) -> Option<i64> {
+    match unit {
+        Unit::Millis => Some(instant.timestamp_millis()),
+        Unit::Micros => Some(instant.timestamp_micros()),
+        Unit::Nanos => instant.timestamp_nanos_opt(),
+    }
+}
+
+fn out_of_range(value: &CellValue) -> CellConversionError {
+    CellConversionError::builder(format!(
+        "{value:?} is out of range for the Parquet column type"
+    ))
+    .build()
+}
+
+fn unexpected_value(value: &CellValue, target: Target) -> CellConversionError {
+    CellConversionError::builder(format!(
+        "{value:?} cannot be written as {}",
+        target.type_name()
+    ))
+    .build()
+}
+
+fn write_row_group(
+    file: &mut SerializedFileWriter<Vec<u8>>,
+    buffers: &[ColumnBuffer],
+) -> std::result::Result<(), ParquetError> {
+    let mut row_group = file.next_row_group()?;
+    for buffer in buffers {
+        let mut column = row_group.next_column()?.ok_or_else(|| {
+            ParquetError::General("fewer Parquet columns than schema columns".to_string())
+        })?;
+        write_column(&mut column, buffer)?;
+        column.close()?;
+    }
+    row_group.close()?;
+    Ok(())
+}
+
+fn write_column(
+    column: &mut SerializedColumnWriter<'_>,
+    buffer: &ColumnBuffer,
+) -> std::result::Result<(), ParquetError> {
+    let def_levels = Some(buffer.def_levels.as_slice());
+    match &buffer.values {
+        Values::Boolean(values) => column
+            .typed::<BoolType>()
+            .write_batch(values, def_levels, None),
+        Values::Int32(values) => column
+            .typed::<Int32Type>()
+            .write_batch(values, def_levels, None),
+        Values::Int64(values) => column
+            .typed::<Int64Type>()
+            .write_batch(values, def_levels, None),
+        Values::Double(values) => column
+            .typed::<DoubleType>()
+            .write_batch(values, def_levels, None),
+        Values::Bytes(values) => column
+            .typed::<ByteArrayType>()
+            .write_batch(values, def_levels, None),
+        Values::Fixed(values) => column
+            .typed::<FixedLenByteArrayType>()
+            .write_batch(values, def_levels, None),
+    }?;
+    Ok(())
+}
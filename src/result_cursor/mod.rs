@@ -1,11 +1,13 @@
 mod collect;
 mod cursor;
+mod export;
 mod model;
 mod remote;
 mod typed_cursor;
 
 pub use collect::CollectOptions;
 pub use cursor::ResultCursor;
+pub use export::CsvOptions;
 pub use typed_cursor::TypedResultCursor;
 
 pub(crate) use collect::CollectPolicy;
@@ -5,7 +5,9 @@ use std::{collections::HashMap, sync::Arc};
 use http::HeaderMap;
 
 use crate::{
+    RetryPolicy,
     error::QueryScopedResult,
+    observer::ObserverHandle,
     result_table::{ResultTable, Schema},
     runtime::BlockingParseLimiter,
 };
@@ -58,10 +60,15 @@ pub(crate) struct RemotePartitionSource {
 }
 
 impl RemotePartitionSource {
-    pub(crate) fn new(lease: ResolvedLease, client: reqwest::Client) -> Self {
+    pub(crate) fn new(
+        lease: ResolvedLease,
+        client: reqwest::Client,
+        retry: RetryPolicy,
+        observer: ObserverHandle,
+    ) -> Self {
         Self {
             lease,
-            downloader: RemotePartitionDownloader::new(client),
+            downloader: RemotePartitionDownloader::new(client, retry, observer),
         }
     }
 
@@ -97,6 +104,7 @@ fn download_request(
             url: locator.url.clone(),
             headers: Arc::clone(&locator.headers),
             query_id: Arc::clone(&snapshot.identity.query_id),
+            ordinal,
             row_count,
             compressed_size,
             uncompressed_size,
@@ -1,4 +1,9 @@
-use std::{fmt, result::Result as StdResult, sync::Arc, time::Duration};
+use std::{
+    fmt,
+    result::Result as StdResult,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use http::HeaderMap;
@@ -6,21 +11,21 @@ use reqwest::StatusCode;
 use tokio::time::sleep;
 
 use crate::{
+    RetryPolicy,
+    config::{is_retryable_status, retry_after, send_request},
     error::{NetworkError, QueryScopedError, QueryScopedResult, TimeoutError},
+    observer::{ObserverHandle, PartitionDownloadEvent, RetryEvent, RetryOperation},
     result_table::{ResultTable, Schema},
     rowset::{ParseWorkload, parser::parse_remote_chunk_result_table_async},
     runtime::BlockingParseLimiter,
 };
 
-const MAX_RETRIES: usize = 7;
-const MIN_RETRY_DELAY: Duration = Duration::from_secs(1);
-const MAX_RETRY_DELAY: Duration = Duration::from_secs(16);
-
 /// Everything the downloader needs to fetch and parse a single remote partition.
 pub(super) struct DownloadRequest {
     pub(super) url: String,
     pub(super) headers: Arc<HeaderMap>,
     pub(super) query_id: Arc<str>,
+    pub(super) ordinal: usize,
     pub(super) row_count: i64,
     pub(super) compressed_size: i64,
     pub(super) uncompressed_size: i64,
@@ -29,7 +34,8 @@ pub(super) struct DownloadRequest {
 
 /// Internal download error used for retry classification.
 enum DownloadFailure {
-    Retryable(QueryScopedError),
+    /// Carries the failed HTTP status, if a response arrived, and the server-requested `Retry-After` delay, if any.
+    Retryable(QueryScopedError, Option<StatusCode>, Option<Duration>),
     Fatal(QueryScopedError),
 }
 
@@ -38,49 +44,110 @@ struct DownloadedChunk {
     workload: ParseWorkload,
 }
 
-impl DownloadFailure {
-    fn into_inner(self) -> QueryScopedError {
-        match self {
-            Self::Retryable(e) | Self::Fatal(e) => e,
-        }
-    }
-}
-
 /// Downloads remote partitions from S3/blob storage with retry and gzip support.
 #[derive(Clone)]
 pub(super) struct RemotePartitionDownloader {
     client: reqwest::Client,
+    retry: RetryPolicy,
+    observer: ObserverHandle,
 }
 
 impl RemotePartitionDownloader {
-    pub(super) fn new(client: reqwest::Client) -> Self {
-        Self { client }
+    pub(super) fn new(
+        client: reqwest::Client,
+        retry: RetryPolicy,
+        observer: ObserverHandle,
+    ) -> Self {
+        Self {
+            client,
+            retry,
+            observer,
+        }
     }
 
+    // The presigned URL and its headers are deliberately left out of the span: they carry credentials for the
+    // storage location.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "snowflake.chunk_download",
+            level = "debug",
+            skip_all,
+            fields(
+                query_id = %request.query_id,
+                partition = request.ordinal,
+                rows = request.row_count,
+                compressed_bytes = request.compressed_size,
+                uncompressed_bytes = request.uncompressed_size,
+                downloaded_bytes = tracing::field::Empty,
+                retries = tracing::field::Empty,
+            )
+        )
+    )]
     pub(super) async fn download_table(
         &self,
         request: DownloadRequest,
         schema: Arc<Schema>,
     ) -> QueryScopedResult<ResultTable> {
-        let mut retries = 0;
+        let started_at = Instant::now();
+        let mut retry = self.retry.start();
         let column_count = schema.len();
         loop {
             match self.download_once(&request, column_count).await {
                 Ok(chunk) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::Span::current()
+                        .record("downloaded_bytes", chunk.body.len())
+                        .record("retries", retry.retries());
+                    self.observer.notify(|observer| {
+                        observer.on_partition_downloaded(&PartitionDownloadEvent {
+                            query_id: Arc::clone(&request.query_id),
+                            partition: request.ordinal,
+                            row_count: request.row_count,
+                            compressed_size: request.compressed_size,
+                            uncompressed_size: request.uncompressed_size,
+                            downloaded_bytes: chunk.body.len(),
+                            elapsed: started_at.elapsed(),
+                            retries: retry.retries(),
+                        })
+                    });
                     return parse_remote_chunk_result_table_async(
                         schema,
                         request.query_id,
                         chunk.body,
                         chunk.workload,
                         request.blocking_parse_limiter,
+                        &self.observer,
                     )
                     .await;
                 }
-                Err(DownloadFailure::Retryable(_)) if retries < MAX_RETRIES => {
-                    sleep(retry_delay(retries)).await;
-                    retries += 1;
+                Err(DownloadFailure::Retryable(error, status, retry_after)) => {
+                    match retry.next_delay(retry_after) {
+                        Some(delay) => {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!(
+                                retry = retry.retries(),
+                                delay_ms = delay.as_millis() as u64,
+                                "retrying chunk download"
+                            );
+                            self.observer.notify(|observer| {
+                                observer.on_retry(&RetryEvent {
+                                    operation: RetryOperation::ChunkDownload,
+                                    retry: retry.retries(),
+                                    delay,
+                                    status: status.map(|status| status.as_u16()),
+                                })
+                            });
+                            sleep(delay).await
+                        }
+                        None => {
+                            #[cfg(feature = "tracing")]
+                            tracing::Span::current().record("retries", retry.retries());
+                            return Err(error);
+                        }
+                    }
                 }
-                Err(e) => return Err(e.into_inner()),
+                Err(DownloadFailure::Fatal(error)) => return Err(error),
             }
         }
     }
@@ -90,32 +157,39 @@ impl RemotePartitionDownloader {
         request: &DownloadRequest,
         column_count: usize,
     ) -> StdResult<DownloadedChunk, DownloadFailure> {
-        let response = match self
-            .client
-            .get(&request.url)
-            .headers((*request.headers).clone())
-            .send()
-            .await
+        let response = match send_request(
+            self.client
+                .get(&request.url)
+                .headers((*request.headers).clone()),
+        )
+        .await
         {
             Ok(response) => response,
             Err(error) => {
                 if error.is_timeout() {
-                    return Err(DownloadFailure::Retryable(QueryScopedError::new(
-                        Arc::clone(&request.query_id),
-                        TimeoutError::request(error),
-                    )));
+                    return Err(DownloadFailure::Retryable(
+                        QueryScopedError::new(
+                            Arc::clone(&request.query_id),
+                            TimeoutError::request(error),
+                        ),
+                        None,
+                        None,
+                    ));
                 }
-                return Err(DownloadFailure::Retryable(QueryScopedError::new(
-                    Arc::clone(&request.query_id),
-                    NetworkError::Http(error),
-                )));
+                return Err(DownloadFailure::Retryable(
+                    QueryScopedError::new(Arc::clone(&request.query_id), NetworkError::Http(error)),
+                    None,
+                    None,
+                ));
             }
         };
 
         let status = response.status();
         if !status.is_success() {
+            let retry_after = retry_after(response.headers());
             return Err(classify_failed_status(
                 status,
+                retry_after,
                 response.bytes().await,
                 Arc::clone(&request.query_id),
             ));
@@ -125,15 +199,20 @@ impl RemotePartitionDownloader {
             Ok(body) => body,
             Err(error) => {
                 if error.is_timeout() {
-                    return Err(DownloadFailure::Retryable(QueryScopedError::new(
-                        Arc::clone(&request.query_id),
-                        TimeoutError::request(error),
-                    )));
+                    return Err(DownloadFailure::Retryable(
+                        QueryScopedError::new(
+                            Arc::clone(&request.query_id),
+                            TimeoutError::request(error),
+                        ),
+                        None,
+                        None,
+                    ));
                 }
-                return Err(DownloadFailure::Retryable(QueryScopedError::new(
-                    Arc::clone(&request.query_id),
-                    NetworkError::Http(error),
-                )));
+                return Err(DownloadFailure::Retryable(
+                    QueryScopedError::new(Arc::clone(&request.query_id), NetworkError::Http(error)),
+                    None,
+                    None,
+                ));
             }
         };
 
@@ -153,6 +232,7 @@ impl RemotePartitionDownloader {
 
 fn classify_failed_status<E>(
     status: StatusCode,
+    retry_after: Option<Duration>,
     body: StdResult<Bytes, E>,
     query_id: Arc<str>,
 ) -> DownloadFailure
@@ -174,23 +254,12 @@ where
     };
 
     if is_retryable_status(status) {
-        DownloadFailure::Retryable(error)
+        DownloadFailure::Retryable(error, Some(status), retry_after)
     } else {
         DownloadFailure::Fatal(error)
     }
 }
 
-fn is_retryable_status(status: StatusCode) -> bool {
-    status.is_server_error()
-        || status == StatusCode::TOO_MANY_REQUESTS
-        || status == StatusCode::REQUEST_TIMEOUT
-}
-
-fn retry_delay(retries: usize) -> Duration {
-    let factor = 1u32.checked_shl(retries as u32).unwrap_or(u32::MAX);
-    MIN_RETRY_DELAY.saturating_mul(factor).min(MAX_RETRY_DELAY)
-}
-
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -201,8 +270,11 @@ mod tests {
     use super::*;
     use crate::{
         ErrorKind,
-        result_table::Schema,
-        test_support::http::{read_http_request, write_json_response},
+        result_table::{ColumnType, Schema, test_data::make_schema},
+        test_support::{
+            http::{read_http_request, write_json_response},
+            observer::{ObservedEvent, RecordingObserver},
+        },
     };
 
     fn qid() -> Arc<str> {
@@ -229,13 +301,18 @@ mod tests {
             url: format!("http://{addr}/chunk-0"),
             headers: Arc::new(headers),
             query_id: qid(),
+            ordinal: 0,
             row_count: 1,
             compressed_size: 1,
             uncompressed_size: 1,
             blocking_parse_limiter: None,
         };
 
-        let downloader = RemotePartitionDownloader::new(reqwest::Client::new());
+        let downloader = RemotePartitionDownloader::new(
+            reqwest::Client::new(),
+            RetryPolicy::default(),
+            ObserverHandle::default(),
+        );
         let _ = downloader
             .download_table(request, Arc::new(Schema::from_columns(vec![])))
             .await;
@@ -252,35 +329,70 @@ mod tests {
         assert!(!lowered.contains("snowflake-connector-rs/"));
     }
 
-    #[test]
-    fn retryable_status_codes() {
-        assert!(is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
-        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
-        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
-        assert!(is_retryable_status(StatusCode::GATEWAY_TIMEOUT));
-        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
-        assert!(is_retryable_status(StatusCode::REQUEST_TIMEOUT));
-
-        assert!(!is_retryable_status(StatusCode::OK));
-        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
-        assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
-        assert!(!is_retryable_status(StatusCode::FORBIDDEN));
-    }
+    #[tokio::test]
+    async fn download_reports_retry_and_partition_to_observer() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            for (status, body) in [
+                (StatusCode::SERVICE_UNAVAILABLE, "busy"),
+                (StatusCode::OK, r#"["a"],["b"]"#),
+            ] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                read_http_request(&mut socket).await.unwrap();
+                write_json_response(&mut socket, status, body)
+                    .await
+                    .unwrap();
+            }
+        });
 
-    #[test]
-    fn retry_delay_exponential_backoff() {
-        assert_eq!(retry_delay(0), Duration::from_secs(1));
-        assert_eq!(retry_delay(1), Duration::from_secs(2));
-        assert_eq!(retry_delay(2), Duration::from_secs(4));
-        assert_eq!(retry_delay(3), Duration::from_secs(8));
-        assert_eq!(retry_delay(4), Duration::from_secs(16));
-        assert_eq!(retry_delay(5), Duration::from_secs(16));
-        assert_eq!(retry_delay(6), Duration::from_secs(16));
+        let recorder = RecordingObserver::default();
+        let downloader = RemotePartitionDownloader::new(
+            reqwest::Client::new(),
+            RetryPolicy::new()
+                .with_jitter(false)
+                .with_initial_backoff(Duration::from_millis(1)),
+            recorder.handle(),
+        );
+        let request = DownloadRequest {
+            url: format!("http://{addr}/chunk-3"),
+            headers: Arc::new(HeaderMap::new()),
+            query_id: qid(),
+            ordinal: 3,
+            row_count: 2,
+            compressed_size: 10,
+            uncompressed_size: 11,
+            blocking_parse_limiter: None,
+        };
+        let schema = make_schema(vec![("C".into(), ColumnType::Text { length: None }, true)]);
+
+        let table = downloader.download_table(request, schema).await.unwrap();
+        server.await.unwrap();
+        assert_eq!(table.row_count(), 2);
+
+        let events = recorder.events();
+        let [
+            ObservedEvent::Retry(retry),
+            ObservedEvent::PartitionDownloaded(download),
+            ObservedEvent::Parse(parse),
+        ] = events.as_slice()
+        else {
+            panic!("unexpected observer events: {events:?}");
+        };
+        assert_eq!(retry.operation(), RetryOperation::ChunkDownload);
+        assert_eq!(retry.retry(), 1);
+        assert_eq!(retry.status(), Some(503));
+        assert_eq!(download.partition(), 3);
+        assert_eq!(download.compressed_size(), 10);
+        assert_eq!(download.uncompressed_size(), 11);
+        assert_eq!(download.downloaded_bytes(), r#"["a"],["b"]"#.len());
+        assert_eq!(download.retries(), 1);
+        assert_eq!(parse.row_count(), 2);
     }
 
     #[test]
     fn body_read_failure_keeps_non_retryable_status_fatal() {
-        match classify_failed_status(StatusCode::FORBIDDEN, Err("body read failed"), qid()) {
+        match classify_failed_status(StatusCode::FORBIDDEN, None, Err("body read failed"), qid()) {
             DownloadFailure::Fatal(error) => {
                 let error: crate::Error = error.into();
                 assert_eq!(error.kind(), ErrorKind::Network);
@@ -289,7 +401,7 @@ mod tests {
                     "chunk download failed with HTTP 403: <failed to read response body: body read failed>"
                 );
             }
-            DownloadFailure::Retryable(error) => {
+            DownloadFailure::Retryable(error, ..) => {
                 panic!("expected fatal classification, got retryable: {error:?}")
             }
         }
@@ -297,8 +409,13 @@ mod tests {
 
     #[test]
     fn body_read_failure_keeps_retryable_status_retryable() {
-        match classify_failed_status(StatusCode::BAD_GATEWAY, Err("body read failed"), qid()) {
-            DownloadFailure::Retryable(error) => {
+        match classify_failed_status(
+            StatusCode::BAD_GATEWAY,
+            None,
+            Err("body read failed"),
+            qid(),
+        ) {
+            DownloadFailure::Retryable(error, ..) => {
                 let error: crate::Error = error.into();
                 assert_eq!(error.kind(), ErrorKind::Network);
                 assert_eq!(
@@ -316,6 +433,7 @@ mod tests {
     fn non_utf8_body_keeps_lossy_preview() {
         match classify_failed_status(
             StatusCode::FORBIDDEN,
+            None,
             Ok::<Bytes, &str>(Bytes::from_static(b"\xfffoo")),
             qid(),
         ) {
@@ -325,7 +443,7 @@ mod tests {
                 assert!(error.to_string().contains("foo"));
                 assert!(!error.to_string().contains("<failed to read response body"));
             }
-            DownloadFailure::Retryable(error) => {
+            DownloadFailure::Retryable(error, ..) => {
                 panic!("expected fatal classification, got retryable: {error:?}")
             }
         }
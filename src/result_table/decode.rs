@@ -1,5 +1,12 @@
-use std::{any::type_name, borrow::Cow, result::Result as StdResult};
+use std::{
+    any::type_name,
+    borrow::Cow,
+    collections::HashMap,
+    result::Result as StdResult,
+    sync::{Mutex, PoisonError},
+};
 
+use bytes::{BufMut, BytesMut};
 use chrono::{DateTime, Days, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use serde::de::DeserializeOwned;
 
@@ -13,15 +20,44 @@ use crate::{
         dynamic::{BinaryValue, DecimalValue},
         plan::{CellPlanContext, RowPlanContext},
         row::RowRef,
-        schema::{Column, ColumnType},
+        schema::{Column, ColumnType, VectorElementType},
     },
 };
 
+#[cfg(feature = "chrono-tz")]
+mod chrono_tz;
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+mod decimal;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "jiff")]
+mod jiff;
+mod structured;
+#[cfg(feature = "time")]
+mod time;
+mod uuid;
+
+#[cfg(feature = "chrono-tz")]
+pub use self::chrono_tz::ZonedTimestampPlan;
+#[cfg(feature = "geo")]
+pub use self::geo::{GeoPlan, Wkb};
+pub use self::{
+    structured::{ArrayPlan, MapPlan, Object, ObjectPlan},
+    uuid::UuidPlan,
+};
+
 const LEGACY_TIMESTAMP_TZ_SHIFT: i128 = 16_384;
 
 /// Decode a single result-set cell into a Rust value.
 ///
-/// Implementations exist for primitive Rust types, [`DecimalValue`](crate::DecimalValue), and `Option<T>`.
+/// Implementations exist for primitive Rust types, [`DecimalValue`](crate::DecimalValue), [`Lossy<f64>`](Lossy) for
+/// `DECFLOAT` columns, `uuid::Uuid`, and `Option<T>`;
+/// for structured `ARRAY(T)` and `MAP(K, V)` columns as `Vec<T>` and `HashMap<K, V>`, and structured `OBJECT(...)`
+/// columns through [`Object<T>`]; plus
+/// `rust_decimal::Decimal` and `bigdecimal::BigDecimal` behind the `rust_decimal` and `bigdecimal` features, and the
+/// `time` and `jiff` date, time, and timestamp types behind the `time` and `jiff` features, and `DateTime<chrono_tz::Tz>`
+/// for `TIMESTAMP_LTZ` in the session time zone behind the `chrono-tz` feature, and `geo_types::Geometry`, `wkt::Wkt`,
+/// and `Wkb` for `GEOGRAPHY` and `GEOMETRY` columns behind the `geo` feature.
 /// Implement it yourself when adapting Snowflake values into domain types.
 ///
 /// Decoding is split in two so schema-dependent work happens once per result schema rather than once per cell:
@@ -122,7 +158,8 @@ impl<T: FromCell> CellPlan<T> {
         })
     }
 
-    /// Resolve a column by raw label and build its cell plan.
+    /// Resolve a column by label, using the context's [`ColumnLookup`](crate::ColumnLookup) mode, and build its cell
+    /// plan.
     ///
     /// # Errors
     ///
@@ -131,7 +168,7 @@ impl<T: FromCell> CellPlan<T> {
     /// - Any [`FromCell::build_plan`] failure for `T`.
     pub fn by_name(ctx: RowPlanContext<'_>, name: &str) -> PlanBuildResult<Self> {
         let schema = ctx.schema();
-        let index = schema.column_index(name)?;
+        let index = ctx.column_index(name)?;
         let column = schema
             .column_at(index)
             .expect("column_index returns a valid index");
@@ -154,6 +191,39 @@ impl<T: FromCell> CellPlan<T> {
         })?;
         Self::new(CellPlanContext::new(ctx, column))
     }
+
+    /// Like [`by_name`](Self::by_name), but returns `None` instead of failing when no column has the label.
+    ///
+    /// # Errors
+    ///
+    /// - [`SchemaError::AmbiguousColumn`](crate::error::SchemaError::AmbiguousColumn) from the label lookup.
+    /// - Any [`FromCell::build_plan`] failure for `T`.
+    pub fn by_name_if_present(
+        ctx: RowPlanContext<'_>,
+        name: &str,
+    ) -> PlanBuildResult<Option<Self>> {
+        match Self::by_name(ctx, name) {
+            Ok(plan) => Ok(Some(plan)),
+            Err(PlanBuildError::Schema(SchemaError::MissingColumn(_))) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Like [`by_position`](Self::by_position), but returns `None` instead of failing when `position` is out of
+    /// range.
+    ///
+    /// # Errors
+    ///
+    /// Any [`FromCell::build_plan`] failure for `T`.
+    pub fn by_position_if_present(
+        ctx: RowPlanContext<'_>,
+        position: usize,
+    ) -> PlanBuildResult<Option<Self>> {
+        match ctx.schema().column_at(position) {
+            Some(column) => Self::new(CellPlanContext::new(ctx, column)).map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
 /// Decode an entire row into a Rust type.
@@ -255,6 +325,289 @@ impl FromRow for () {
     }
 }
 
+/// Decode a single cell into a value that may borrow from the result table.
+///
+/// The borrowed counterpart of [`FromCell`]: `raw` lives as long as the table, so `&'a str` and `Cow<'a, str>` decode
+/// without copying. `&'a [u8]` borrows the bytes of non-`BINARY` text, and `Cow<'a, [u8]>` borrows them too but decodes
+/// `BINARY` hex into an owned buffer.
+/// Every [`FromCell`] type implements this trait as well, so owned and borrowed fields mix freely in a
+/// [`FromRowRef`] row.
+pub trait FromCellRef<'a>: Sized {
+    /// Per-column state prepared once and reused for every cell in that column.
+    type Plan: Send + Sync;
+
+    /// Validate the column and precompute schema-dependent state. See [`FromCell::build_plan`].
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan>;
+
+    /// Decode a single cell's raw text into `Self`, borrowing from it where possible.
+    ///
+    /// `raw` is `None` for SQL `NULL`.
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, plan: &Self::Plan) -> CellDecodeResult<Self>;
+}
+
+impl<'a, T: FromCell> FromCellRef<'a> for T {
+    type Plan = T::Plan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        <T as FromCell>::build_plan(ctx)
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        T::from_cell_with_plan(raw, plan)
+    }
+}
+
+impl<'a> FromCellRef<'a> for &'a str {
+    type Plan = ();
+
+    fn build_plan(_ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        Ok(())
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        required_raw(raw)
+    }
+}
+
+impl<'a> FromCellRef<'a> for Option<&'a str> {
+    type Plan = ();
+
+    fn build_plan(_ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        Ok(())
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        Ok(raw)
+    }
+}
+
+/// Always [`Cow::Borrowed`]; for APIs that take a `Cow` and may later need to own the text.
+impl<'a> FromCellRef<'a> for Cow<'a, str> {
+    type Plan = ();
+
+    fn build_plan(_ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        Ok(())
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        required_raw(raw).map(Cow::Borrowed)
+    }
+}
+
+impl<'a> FromCellRef<'a> for Option<Cow<'a, str>> {
+    type Plan = ();
+
+    fn build_plan(_ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        Ok(())
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        Ok(raw.map(Cow::Borrowed))
+    }
+}
+
+/// Borrows the UTF-8 bytes of the cell's text.
+///
+/// `BINARY` columns are rejected: their cells arrive hex-encoded, so there are no decoded bytes to borrow. Decode them
+/// as `Cow<[u8]>` or [`BinaryValue`] instead.
+impl<'a> FromCellRef<'a> for &'a [u8] {
+    type Plan = ();
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        text_bytes_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        required_raw(raw).map(str::as_bytes)
+    }
+}
+
+impl<'a> FromCellRef<'a> for Option<&'a [u8]> {
+    type Plan = ();
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        text_bytes_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        Ok(raw.map(str::as_bytes))
+    }
+}
+
+/// Plan for a column whose raw text is borrowed as bytes by `T`.
+fn text_bytes_plan<T>(column: &Column) -> PlanBuildResult<()> {
+    match column.ty() {
+        ColumnType::Binary { .. } => Err(incompatible_column::<T>(
+            column,
+            Some(
+                "BINARY cells are hex-encoded; decode them as Cow<[u8]> or BinaryValue".to_string(),
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Whether a `Cow<[u8]>` column holds hex-encoded `BINARY` or text whose bytes can be borrowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BytesPlan {
+    /// `BINARY`: each cell is decoded from hex into [`Cow::Owned`].
+    Hex,
+    /// Any other column: each cell borrows its UTF-8 text as [`Cow::Borrowed`].
+    Text,
+}
+
+impl BytesPlan {
+    fn new(column: &Column) -> Self {
+        match column.ty() {
+            ColumnType::Binary { .. } => Self::Hex,
+            _ => Self::Text,
+        }
+    }
+
+    fn decode<'a>(self, raw: &'a str) -> CellDecodeResult<Cow<'a, [u8]>> {
+        match self {
+            Self::Hex => decode_hex(raw)
+                .map(Cow::Owned)
+                .map_err(|m| CellConversionError::builder(m).build()),
+            Self::Text => Ok(Cow::Borrowed(raw.as_bytes())),
+        }
+    }
+}
+
+/// The bytes of a cell: decoded from hex for `BINARY` columns, borrowed from the text otherwise.
+impl<'a> FromCellRef<'a> for Cow<'a, [u8]> {
+    type Plan = BytesPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        Ok(BytesPlan::new(ctx.column()))
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        plan.decode(required_raw(raw)?)
+    }
+}
+
+impl<'a> FromCellRef<'a> for Option<Cow<'a, [u8]>> {
+    type Plan = BytesPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        Ok(BytesPlan::new(ctx.column()))
+    }
+
+    fn from_cell_ref_with_plan(raw: Option<&'a str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        raw.map(|raw| plan.decode(raw)).transpose()
+    }
+}
+
+/// A resolved column paired with a prepared [`FromCellRef::Plan`]; the borrowed counterpart of [`CellPlan`].
+///
+/// Built by [`FromRowRef::build_plan`], then handed to [`RowRef::get_ref_with_plan`] for each row.
+pub struct CellRefPlan<'a, T: FromCellRef<'a>> {
+    pub(crate) column: Column,
+    pub(crate) offset: usize,
+    pub(crate) decode_plan: T::Plan,
+}
+
+impl<'a, T: FromCellRef<'a>> CellRefPlan<'a, T> {
+    /// Build a cell plan for an already-resolved column context.
+    ///
+    /// # Errors
+    ///
+    /// Propagates [`FromCellRef::build_plan`] failures for `T`.
+    pub fn new(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self> {
+        let column = ctx.column();
+        let decode_plan = T::build_plan(ctx).map_err(|mut error| {
+            if let PlanBuildError::Custom(custom) = &mut error {
+                custom.set_column_context(column.index(), column.name());
+            }
+            error
+        })?;
+        Ok(Self {
+            column: column.clone(),
+            offset: column.index(),
+            decode_plan,
+        })
+    }
+
+    /// Resolve a column by raw label and build its cell plan. See [`CellPlan::by_name`].
+    ///
+    /// # Errors
+    ///
+    /// The same as [`CellPlan::by_name`].
+    pub fn by_name(ctx: RowPlanContext<'_>, name: &str) -> PlanBuildResult<Self> {
+        let schema = ctx.schema();
+        let index = ctx.column_index(name)?;
+        let column = schema
+            .column_at(index)
+            .expect("column_index returns a valid index");
+        Self::new(CellPlanContext::new(ctx, column))
+    }
+
+    /// Resolve a column by position and build its cell plan. See [`CellPlan::by_position`].
+    ///
+    /// # Errors
+    ///
+    /// The same as [`CellPlan::by_position`].
+    pub fn by_position(ctx: RowPlanContext<'_>, position: usize) -> PlanBuildResult<Self> {
+        let schema = ctx.schema();
+        let column = schema.column_at(position).ok_or_else(|| {
+            SchemaError::ColumnCountMismatch(ColumnCountMismatchError::new(
+                position + 1,
+                schema.len(),
+            ))
+        })?;
+        Self::new(CellPlanContext::new(ctx, column))
+    }
+
+    /// Like [`by_name`](Self::by_name), but returns `None` instead of failing when no column has the label.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`CellPlan::by_name_if_present`].
+    pub fn by_name_if_present(
+        ctx: RowPlanContext<'_>,
+        name: &str,
+    ) -> PlanBuildResult<Option<Self>> {
+        match Self::by_name(ctx, name) {
+            Ok(plan) => Ok(Some(plan)),
+            Err(PlanBuildError::Schema(SchemaError::MissingColumn(_))) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Like [`by_position`](Self::by_position), but returns `None` instead of failing when `position` is out of
+    /// range.
+    ///
+    /// # Errors
+    ///
+    /// Any [`FromCellRef::build_plan`] failure for `T`.
+    pub fn by_position_if_present(
+        ctx: RowPlanContext<'_>,
+        position: usize,
+    ) -> PlanBuildResult<Option<Self>> {
+        match ctx.schema().column_at(position) {
+            Some(column) => Self::new(CellPlanContext::new(ctx, column)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Decode an entire row into a value that may borrow from the result table.
+///
+/// The borrowed counterpart of [`FromRow`], used by [`ResultTable::rows_ref`](crate::ResultTable::rows_ref).
+/// `#[derive(FromRow)]` implements it for every struct; a struct with a lifetime parameter, such as
+/// `struct Row<'a> { name: &'a str }`, implements only this trait.
+pub trait FromRowRef<'a>: Sized {
+    /// Per-result-schema state reused while decoding rows.
+    type Plan: Send + Sync;
+
+    /// Build the per-result-schema decode plan. See [`FromRow::build_plan`].
+    fn build_plan(ctx: RowPlanContext<'_>) -> PlanBuildResult<Self::Plan>;
+
+    /// Decode a single row using the associated plan. See [`FromRow::from_row_with_plan`].
+    fn from_row_ref_with_plan(row: RowRef<'a>, plan: &Self::Plan) -> RowDecodeResult<Self>;
+}
+
 impl<T: FromCell> FromCell for Option<T> {
     type Plan = T::Plan;
 
@@ -275,6 +628,16 @@ fn required_raw(raw: Option<&str>) -> CellDecodeResult<&str> {
     raw.ok_or_else(|| CellConversionError::builder("value is NULL").build())
 }
 
+/// Start the cell error for a value that parsed but does not fit `T`; callers attach a source when they have one.
+#[cfg(any(feature = "jiff", feature = "time"))]
+fn out_of_range<T>(raw: Option<&str>) -> crate::error::CellConversionErrorBuilder {
+    CellConversionError::builder(format!(
+        "'{}' is out of range for {}",
+        raw.unwrap_or_default(),
+        type_name::<T>()
+    ))
+}
+
 /// Build the plan-time error for a column that cannot be decoded as `T`.
 fn incompatible_column<T>(column: &Column, detail: Option<String>) -> PlanBuildError {
     SchemaError::IncompatibleColumnType(IncompatibleColumnTypeError::new(
@@ -287,6 +650,71 @@ fn incompatible_column<T>(column: &Column, detail: Option<String>) -> PlanBuildE
     .into()
 }
 
+/// Plan for a `DATE` column decoded as `T`.
+fn date_plan<T>(column: &Column) -> PlanBuildResult<()> {
+    match column.ty() {
+        ColumnType::Date => Ok(()),
+        _ => Err(incompatible_column::<T>(column, None)),
+    }
+}
+
+/// Plan for a `TIME` column decoded as `T`.
+fn time_plan<T>(column: &Column) -> PlanBuildResult<TimePlan> {
+    match column.ty() {
+        ColumnType::Time { scale } => {
+            let scale = match scale {
+                None => 0usize,
+                Some(s) if (0..=9).contains(s) => *s as usize,
+                Some(s) => {
+                    return Err(incompatible_column::<T>(
+                        column,
+                        Some(format!("invalid time scale: {s}")),
+                    ));
+                }
+            };
+            Ok(TimePlan::new(scale))
+        }
+        _ => Err(incompatible_column::<T>(column, None)),
+    }
+}
+
+/// Plan for a `TIMESTAMP_NTZ` column decoded as `T`.
+fn timestamp_ntz_plan<T>(column: &Column) -> PlanBuildResult<TimestampPlan> {
+    match column.ty() {
+        ColumnType::TimestampNtz { scale } => TimestampPlan::from_metadata_scale(*scale)
+            .map_err(|detail| incompatible_column::<T>(column, Some(detail))),
+        _ => Err(incompatible_column::<T>(column, None)),
+    }
+}
+
+/// Plan for a `TIMESTAMP_LTZ` or `TIMESTAMP_TZ` column decoded as the instant type `T`.
+fn utc_timestamp_plan<T>(column: &Column) -> PlanBuildResult<UtcTimestampPlan> {
+    match column.ty() {
+        ColumnType::TimestampLtz { scale } => TimestampPlan::from_metadata_scale(*scale)
+            .map(UtcTimestampPlan::Ltz)
+            .map_err(|detail| incompatible_column::<T>(column, Some(detail))),
+        ColumnType::TimestampTz { scale } => TimestampPlan::from_metadata_scale(*scale)
+            .map(UtcTimestampPlan::Tz)
+            .map_err(|detail| incompatible_column::<T>(column, Some(detail))),
+        _ => Err(incompatible_column::<T>(column, None)),
+    }
+}
+
+/// Decode a `TIMESTAMP_LTZ` cell at offset zero, or a `TIMESTAMP_TZ` cell at its own offset.
+#[cfg(any(feature = "jiff", feature = "time"))]
+fn parse_timestamp_with_offset(
+    raw: &str,
+    plan: &UtcTimestampPlan,
+) -> CellDecodeResult<DateTime<FixedOffset>> {
+    match plan {
+        UtcTimestampPlan::Ltz(plan) => {
+            parse_timestamp_epoch_with(raw, plan).map(|dt| dt.fixed_offset())
+        }
+        UtcTimestampPlan::Tz(plan) => parse_timestamp_tz_with_offset_with(raw, plan),
+    }
+    .map_err(|m| CellConversionError::builder(m).build())
+}
+
 macro_rules! impl_int_from_cell {
     ($t:ty) => {
         impl FromCell for $t {
@@ -343,6 +771,14 @@ macro_rules! impl_float_from_cell {
                 let column = ctx.column();
                 match column.ty() {
                     ColumnType::Real | ColumnType::Fixed { .. } => Ok(()),
+                    ColumnType::DecFloat => Err(incompatible_column::<$t>(
+                        column,
+                        Some(format!(
+                            "DECFLOAT values may not fit {}; decode as Lossy<{}> to accept rounding",
+                            stringify!($t),
+                            stringify!($t)
+                        )),
+                    )),
                     _ => Err(incompatible_column::<$t>(column, None)),
                 }
             }
@@ -359,12 +795,61 @@ macro_rules! impl_float_from_cell {
                 })
             }
         }
+
+        impl FromCell for Lossy<$t> {
+            type Plan = ();
+
+            fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+                let column = ctx.column();
+                match column.ty() {
+                    ColumnType::Real | ColumnType::Fixed { .. } | ColumnType::DecFloat => Ok(()),
+                    _ => Err(incompatible_column::<Self>(column, None)),
+                }
+            }
+
+            fn from_cell_with_plan(
+                raw: Option<&str>,
+                plan: &Self::Plan,
+            ) -> CellDecodeResult<Self> {
+                let value = <$t>::from_cell_with_plan(raw, plan)?;
+                // A finite decimal that overflowed; Snowflake's own infinity tokens carry no digits.
+                if value.is_infinite() && raw.is_some_and(|raw| raw.bytes().any(|b| b.is_ascii_digit())) {
+                    return Err(CellConversionError::builder(format!(
+                        "value is out of range for {}",
+                        stringify!($t)
+                    ))
+                    .build());
+                }
+                Ok(Lossy(value))
+            }
+        }
     };
 }
 
 impl_float_from_cell!(f32);
 impl_float_from_cell!(f64);
 
+/// Decode wrapper that accepts `DECFLOAT` columns into `f32` / `f64`, rounding to the nearest representable value.
+///
+/// Bare `f32` and `f64` reject `DECFLOAT` columns, whose 38 significant digits and wide exponent range they cannot
+/// hold; wrapping the target opts in to the rounding. Values too large for the float fail instead of becoming
+/// infinite, while values too small become zero. `FLOAT` and `NUMBER` columns decode as they do for the bare type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lossy<T>(T);
+
+impl<T> Lossy<T> {
+    /// Consume the wrapper, returning the decoded value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Lossy<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
 impl FromCell for String {
     type Plan = ();
 
@@ -377,13 +862,14 @@ impl FromCell for String {
     }
 }
 
+/// Accepts `NUMBER` and `DECFLOAT` columns, keeping the exact text Snowflake delivered.
 impl FromCell for DecimalValue {
     type Plan = ();
 
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
         let column = ctx.column();
         match column.ty() {
-            ColumnType::Fixed { .. } => Ok(()),
+            ColumnType::Fixed { .. } | ColumnType::DecFloat => Ok(()),
             _ => Err(incompatible_column::<Self>(column, None)),
         }
     }
@@ -420,11 +906,7 @@ impl FromCell for NaiveDate {
     type Plan = ();
 
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
-        let column = ctx.column();
-        match column.ty() {
-            ColumnType::Date => Ok(()),
-            _ => Err(incompatible_column::<Self>(column, None)),
-        }
+        date_plan::<Self>(ctx.column())
     }
 
     fn from_cell_with_plan(raw: Option<&str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
@@ -456,23 +938,7 @@ impl FromCell for NaiveTime {
     type Plan = TimePlan;
 
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
-        let column = ctx.column();
-        match column.ty() {
-            ColumnType::Time { scale } => {
-                let scale = match scale {
-                    None => 0usize,
-                    Some(s) if (0..=9).contains(s) => *s as usize,
-                    Some(s) => {
-                        return Err(incompatible_column::<Self>(
-                            column,
-                            Some(format!("invalid time scale: {s}")),
-                        ));
-                    }
-                };
-                Ok(TimePlan::new(scale))
-            }
-            _ => Err(incompatible_column::<Self>(column, None)),
-        }
+        time_plan::<Self>(ctx.column())
     }
 
     fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
@@ -490,12 +956,7 @@ impl FromCell for NaiveDateTime {
     type Plan = TimestampPlan;
 
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
-        let column = ctx.column();
-        match column.ty() {
-            ColumnType::TimestampNtz { scale } => TimestampPlan::from_metadata_scale(*scale)
-                .map_err(|detail| incompatible_column::<Self>(column, Some(detail))),
-            _ => Err(incompatible_column::<Self>(column, None)),
-        }
+        timestamp_ntz_plan::<Self>(ctx.column())
     }
 
     fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
@@ -506,7 +967,7 @@ impl FromCell for NaiveDateTime {
     }
 }
 
-/// Which UTC-anchored decode path a [`DateTime<Utc>`] column uses.
+/// Which UTC-anchored decode path a [`DateTime<Utc>`] (or other instant type) column uses.
 pub enum UtcTimestampPlan {
     /// `TIMESTAMP_LTZ` — stored as a UTC epoch.
     Ltz(TimestampPlan),
@@ -518,16 +979,7 @@ impl FromCell for DateTime<Utc> {
     type Plan = UtcTimestampPlan;
 
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
-        let column = ctx.column();
-        match column.ty() {
-            ColumnType::TimestampLtz { scale } => TimestampPlan::from_metadata_scale(*scale)
-                .map(UtcTimestampPlan::Ltz)
-                .map_err(|detail| incompatible_column::<Self>(column, Some(detail))),
-            ColumnType::TimestampTz { scale } => TimestampPlan::from_metadata_scale(*scale)
-                .map(UtcTimestampPlan::Tz)
-                .map_err(|detail| incompatible_column::<Self>(column, Some(detail))),
-            _ => Err(incompatible_column::<Self>(column, None)),
-        }
+        utc_timestamp_plan::<Self>(ctx.column())
     }
 
     fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
@@ -565,7 +1017,10 @@ impl FromCell for serde_json::Value {
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
         let column = ctx.column();
         match column.ty() {
-            ColumnType::Variant | ColumnType::Object | ColumnType::Array => Ok(()),
+            ColumnType::Variant
+            | ColumnType::Object { .. }
+            | ColumnType::Array { .. }
+            | ColumnType::Map { .. } => Ok(()),
             _ => Err(incompatible_column::<Self>(column, None)),
         }
     }
@@ -578,9 +1033,10 @@ impl FromCell for serde_json::Value {
 
 /// Decode wrapper for Snowflake `VARIANT`, `OBJECT`, and `ARRAY` cells.
 ///
-/// Snowflake result metadata identifies only the root kind; structured element types, object fields, and map key/value
-/// types are not available for plan-time checking. Decoding succeeds when the cell payload can be interpreted as `T`
-/// after normalizing Snowflake's array-element `undefined` values to JSON `null`.
+/// Semi-structured columns report only their root kind, so nothing about `T` is checked at plan time. Decoding succeeds
+/// when the cell payload can be interpreted as `T` after normalizing Snowflake's array-element `undefined` values to
+/// JSON `null`. Structured `ARRAY(T)`, `OBJECT(...)`, and `MAP(K, V)` columns also decode as `Json<T>`, but `Vec<T>`,
+/// [`Object<T>`], and `HashMap<K, V>` check their nested types when the plan is built.
 ///
 /// Snowflake non-finite float tokens (`Infinity`, `-Infinity`, `NaN`) are rejected because JSON has no representation for them.
 ///
@@ -610,7 +1066,10 @@ where
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
         let column = ctx.column();
         match column.ty() {
-            ColumnType::Variant | ColumnType::Object | ColumnType::Array => Ok(()),
+            ColumnType::Variant
+            | ColumnType::Object { .. }
+            | ColumnType::Array { .. }
+            | ColumnType::Map { .. } => Ok(()),
             _ => Err(incompatible_column::<Self>(column, None)),
         }
     }
@@ -628,30 +1087,57 @@ where
     }
 }
 
+/// Bytes reserved at once for a `BINARY` column's scratch buffer.
+const BINARY_SCRATCH_BYTES: usize = 8 * 1024;
+
+/// Scratch buffer that a `BINARY` column decodes its cells into.
+///
+/// Each [`BinaryValue`] is split off the front of a shared buffer instead of getting its own allocation. A fresh 8 KiB
+/// buffer is reserved only when the current one runs out, so small values allocate once per many cells. A buffer is
+/// freed when every value split from it has been dropped, so holding on to one small value also keeps its neighbours'
+/// buffer alive; copy it out with `Bytes::copy_from_slice` when that matters.
+#[derive(Debug, Default)]
+pub struct BinaryPlan {
+    scratch: Mutex<BytesMut>,
+}
+
+impl BinaryPlan {
+    fn decode(&self, raw: &str) -> CellDecodeResult<BinaryValue> {
+        let mut scratch = self.scratch.lock().unwrap_or_else(PoisonError::into_inner);
+        let len = raw.len() / 2;
+        if scratch.capacity() < len {
+            scratch.reserve(len.max(BINARY_SCRATCH_BYTES));
+        }
+        if let Err(m) = decode_hex_into(raw, &mut *scratch) {
+            scratch.clear();
+            return Err(CellConversionError::builder(m).build());
+        }
+        Ok(BinaryValue::new(scratch.split().freeze()))
+    }
+}
+
 impl FromCell for BinaryValue {
-    type Plan = ();
+    type Plan = BinaryPlan;
 
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
         let column = ctx.column();
         match column.ty() {
-            ColumnType::Binary { .. } => Ok(()),
+            ColumnType::Binary { .. } => Ok(BinaryPlan::default()),
             _ => Err(incompatible_column::<Self>(column, None)),
         }
     }
 
-    fn from_cell_with_plan(raw: Option<&str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
-        let raw = required_raw(raw)?;
-        decode_hex(raw)
-            .map(BinaryValue::new)
-            .map_err(|m| CellConversionError::builder(m).build())
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        plan.decode(required_raw(raw)?)
     }
 }
 
 /// Decoded value of a Snowflake `VECTOR` cell.
 ///
-/// [`ColumnType::Vector`](crate::ColumnType::Vector) is the only column this wrapper accepts. Snowflake result metadata
-/// reports neither the element type nor the dimension, so the element type is verified from the cell payload at decode
-/// time and the dimension is left to the caller to check via [`len`](Vector::len).
+/// [`ColumnType::Vector`](crate::ColumnType::Vector) is the only column this wrapper accepts. When the result metadata
+/// reports the column's element type, a mismatched `Vector<T>` is rejected when the plan is built, and a reported
+/// dimension is checked against every cell. Without that metadata the element type is verified from the cell payload
+/// at decode time and the dimension is left to the caller to check via [`len`](Vector::len).
 ///
 /// Two element types are supported, matching Snowflake's `VECTOR(INT, n)` / `VECTOR(FLOAT, n)`:
 ///
@@ -659,16 +1145,18 @@ impl FromCell for BinaryValue {
 /// - `Vector<f32>` decodes a `VECTOR(FLOAT)` cell. Elements are 32-bit IEEE 754 floats.
 ///
 /// `Vector<f32>` restores Snowflake's lowercase `inf` / `-inf` / `nan` tokens to the corresponding `f32` non-finite
-/// values, and rejects integer-only tokens (`[1,2,3]`) because a `VECTOR(FLOAT)` cell always renders finite elements
-/// with a decimal point. Snowflake renders `VECTOR(FLOAT)` elements as fixed 6-digit decimals over an already
-/// single-precision value, so a decoded `Vector<f32>` reconstructs the wire value rather than round-tripping the
-/// original SQL literal exactly.
+/// values. Unless the column reports a `FLOAT` element type, it rejects integer-only tokens (`[1,2,3]`) because a
+/// `VECTOR(FLOAT)` cell always renders finite elements with a decimal point. Snowflake renders `VECTOR(FLOAT)`
+/// elements as fixed 6-digit decimals over an already single-precision value, so a decoded `Vector<f32>` reconstructs
+/// the wire value rather than round-tripping the original SQL literal exactly.
 ///
 /// Only `Vector<i32>` and `Vector<f32>` implement [`FromCell`]; wider element types such as `Vector<i64>` /
 /// `Vector<f64>`, and bare `Vec<T>`, are intentionally left out. Use `Option<Vector<_>>` for a nullable `VECTOR` column.
 ///
 /// The wrapper does not implement `Deref<Target = [T]>`; borrow the elements through [`as_slice`](Vector::as_slice),
 /// [`AsRef<[T]>`](AsRef), or by iterating.
+///
+/// Build one from a `Vec<i32>` / `Vec<f32>` to bind it; see [`IntoBind`](crate::bind::IntoBind).
 #[derive(Clone, Debug, PartialEq)]
 pub struct Vector<T>(Vec<T>);
 
@@ -698,6 +1186,12 @@ impl<T> Vector<T> {
     }
 }
 
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self(values)
+    }
+}
+
 impl<T> AsRef<[T]> for Vector<T> {
     fn as_ref(&self) -> &[T] {
         &self.0
@@ -722,41 +1216,79 @@ impl<'a, T> IntoIterator for &'a Vector<T> {
     }
 }
 
-impl FromCell for Vector<i32> {
-    type Plan = ();
+/// Element type and dimension a `VECTOR` column reported, checked by [`Vector<T>`] decoding.
+#[derive(Clone, Copy, Debug)]
+pub struct VectorPlan {
+    element: Option<VectorElementType>,
+    dimension: Option<u32>,
+}
 
-    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+impl VectorPlan {
+    fn build<T>(ctx: CellPlanContext<'_>, expected: VectorElementType) -> PlanBuildResult<Self> {
         let column = ctx.column();
-        match column.ty() {
-            ColumnType::Vector => Ok(()),
-            _ => Err(incompatible_column::<Self>(column, None)),
+        let ColumnType::Vector { element, dimension } = column.ty() else {
+            return Err(incompatible_column::<Vector<T>>(column, None));
+        };
+        match element {
+            Some(element) if *element != expected => Err(incompatible_column::<Vector<T>>(
+                column,
+                Some(format!(
+                    "the column holds {element} elements; decode it as {}",
+                    match element {
+                        VectorElementType::Int => "Vector<i32>",
+                        _ => "Vector<f32>",
+                    }
+                )),
+            )),
+            _ => Ok(Self {
+                element: *element,
+                dimension: *dimension,
+            }),
         }
     }
 
-    fn from_cell_with_plan(raw: Option<&str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+    fn check<T>(&self, values: Vec<T>) -> CellDecodeResult<Vector<T>> {
+        match self.dimension {
+            Some(dimension) if usize::try_from(dimension).ok() != Some(values.len()) => {
+                Err(CellConversionError::builder(format!(
+                    "expected {dimension} vector elements, got {}",
+                    values.len()
+                ))
+                .build())
+            }
+            _ => Ok(Vector(values)),
+        }
+    }
+}
+
+impl FromCell for Vector<i32> {
+    type Plan = VectorPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        VectorPlan::build::<i32>(ctx, VectorElementType::Int)
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
         let raw = required_raw(raw)?;
-        parse_vector_i32_payload(raw)
-            .map(Vector::from_vec)
-            .map_err(|m| CellConversionError::builder(m).build())
+        let values =
+            parse_vector_i32_payload(raw).map_err(|m| CellConversionError::builder(m).build())?;
+        plan.check(values)
     }
 }
 
 impl FromCell for Vector<f32> {
-    type Plan = ();
+    type Plan = VectorPlan;
 
     fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
-        let column = ctx.column();
-        match column.ty() {
-            ColumnType::Vector => Ok(()),
-            _ => Err(incompatible_column::<Self>(column, None)),
-        }
+        VectorPlan::build::<f32>(ctx, VectorElementType::Float)
     }
 
-    fn from_cell_with_plan(raw: Option<&str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
         let raw = required_raw(raw)?;
-        parse_vector_f32_payload(raw)
-            .map(Vector::from_vec)
-            .map_err(|m| CellConversionError::builder(m).build())
+        let integer_tokens = plan.element == Some(VectorElementType::Float);
+        let values = parse_vector_f32_payload(raw, integer_tokens)
+            .map_err(|m| CellConversionError::builder(m).build())?;
+        plan.check(values)
     }
 }
 
@@ -771,22 +1303,35 @@ pub(crate) fn parse_vector_i32_payload(raw: &str) -> StdResult<Vec<i32>, String>
 
 /// Parse a Snowflake `VECTOR(FLOAT)` payload into a `Vec<f32>`.
 ///
-/// Restores the lowercase non-finite tokens `inf` / `-inf` / `nan`, and rejects integer-only tokens so a
-/// `VECTOR(INT)` payload is not silently accepted as float.
-pub(crate) fn parse_vector_f32_payload(raw: &str) -> StdResult<Vec<f32>, String> {
-    parse_vector_payload(raw, parse_vector_f32_element)
+/// Restores the lowercase non-finite tokens `inf` / `-inf` / `nan`. Integer-only tokens are rejected so a
+/// `VECTOR(INT)` payload is not silently accepted as float, unless `integer_tokens` is set because the column metadata
+/// already reported a `FLOAT` element type.
+pub(crate) fn parse_vector_f32_payload(
+    raw: &str,
+    integer_tokens: bool,
+) -> StdResult<Vec<f32>, String> {
+    parse_vector_payload(raw, |token| parse_vector_f32_element(token, integer_tokens))
 }
 
-fn parse_vector_f32_element(token: &str) -> StdResult<f32, String> {
+fn parse_vector_f32_element(token: &str, integer_tokens: bool) -> StdResult<f32, String> {
     match token {
         "inf" => Ok(f32::INFINITY),
         "-inf" => Ok(f32::NEG_INFINITY),
         "nan" => Ok(f32::NAN),
         // A finite `VECTOR(FLOAT)` element always carries a decimal point on the wire. Requiring one keeps
         // integer-only tokens (a `VECTOR(INT)` payload) and alternate non-finite spellings out.
-        _ if token.contains('.') => token
-            .parse::<f32>()
-            .map_err(|_| format!("invalid VECTOR(FLOAT) element: {token}")),
+        _ if token.contains('.')
+            || (integer_tokens
+                && token
+                    .strip_prefix('-')
+                    .unwrap_or(token)
+                    .bytes()
+                    .all(|b| b.is_ascii_digit())) =>
+        {
+            token
+                .parse::<f32>()
+                .map_err(|_| format!("invalid VECTOR(FLOAT) element: {token}"))
+        }
         _ => Err(format!("invalid VECTOR(FLOAT) element: {token}")),
     }
 }
@@ -996,12 +1541,13 @@ fn validate_ts_scale(scale: u8) -> StdResult<usize, String> {
     Ok(usize::from(scale))
 }
 
+/// Parse a decimal string into an integer scaled by `10^scale`; `scale_factor` must equal `10^scale`.
 fn parse_scaled_decimal_i128(
     s: &str,
-    plan: &TimestampPlan,
+    scale: usize,
+    scale_factor: i128,
     kind: &'static str,
 ) -> StdResult<i128, String> {
-    let scale = plan.scale;
     let s = s.trim();
     if s.is_empty() {
         return Err(format!("Could not decode {kind}: {s}"));
@@ -1042,7 +1588,7 @@ fn parse_scaled_decimal_i128(
     }
 
     let mut scaled = whole
-        .checked_mul(plan.scale_factor)
+        .checked_mul(scale_factor)
         .ok_or_else(|| format!("Could not decode {kind}: {s}"))?;
 
     if i < bytes.len() {
@@ -1089,6 +1635,20 @@ fn parse_scaled_decimal_i128(
     Ok(scaled)
 }
 
+/// Parse a `NUMBER(p, scale)` value into its unscaled integer, e.g. `"12.5"` at scale 2 into `1250`.
+#[cfg(feature = "parquet")]
+pub(crate) fn parse_decimal_unscaled(s: &str, scale: u8) -> StdResult<i128, String> {
+    if scale > 38 {
+        return Err(format!("invalid decimal scale: {scale} (expected 0..=38)"));
+    }
+    parse_scaled_decimal_i128(
+        s,
+        usize::from(scale),
+        10i128.pow(u32::from(scale)),
+        "decimal",
+    )
+}
+
 fn parse_timestamp_epoch_scaled(
     scaled: i128,
     plan: &TimestampPlan,
@@ -1192,7 +1752,7 @@ pub(crate) fn parse_timestamp_epoch_with(
     if let Some(result) = parse_unsigned_timestamp_epoch_fast(s, plan, s, "timestamp") {
         return result;
     }
-    let scaled = parse_scaled_decimal_i128(s, plan, "timestamp")?;
+    let scaled = parse_scaled_decimal_i128(s, plan.scale, plan.scale_factor, "timestamp")?;
     parse_timestamp_epoch_scaled(scaled, plan, s, "timestamp")
 }
 
@@ -1233,7 +1793,8 @@ fn parse_legacy_timestamp_tz_wire(
     s: &str,
     plan: &TimestampPlan,
 ) -> StdResult<TimestampTzWire, String> {
-    let packed = parse_scaled_decimal_i128(s, &TimestampPlan::SCALE_ZERO, "timestamp_tz")?;
+    let whole = &TimestampPlan::SCALE_ZERO;
+    let packed = parse_scaled_decimal_i128(s, whole.scale, whole.scale_factor, "timestamp_tz")?;
     let epoch_scaled = packed.div_euclid(LEGACY_TIMESTAMP_TZ_SHIFT);
     let tz_index = packed.rem_euclid(LEGACY_TIMESTAMP_TZ_SHIFT);
     let utc = parse_timestamp_epoch_scaled(epoch_scaled, plan, s, "timestamp_tz")?;
@@ -1343,19 +1904,22 @@ pub(crate) fn parse_time_seconds_and_nanos(
 }
 
 pub(crate) fn decode_hex(s: &str) -> StdResult<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(s.len() / 2);
+    decode_hex_into(s, &mut out)?;
+    Ok(out)
+}
+
+/// Append the bytes of hex string `s` to `out`. On error `out` may hold a partial prefix.
+fn decode_hex_into(s: &str, out: &mut impl BufMut) -> StdResult<(), String> {
     if s.len() % 2 != 0 {
         return Err(format!("invalid hex length: {}", s.len()));
     }
-    let mut out = Vec::with_capacity(s.len() / 2);
-    let bytes = s.as_bytes();
-    let mut i = 0;
-    while i < bytes.len() {
-        let hi = hex_nibble(bytes[i])?;
-        let lo = hex_nibble(bytes[i + 1])?;
-        out.push((hi << 4) | lo);
-        i += 2;
+    for pair in s.as_bytes().chunks_exact(2) {
+        let hi = hex_nibble(pair[0])?;
+        let lo = hex_nibble(pair[1])?;
+        out.put_u8((hi << 4) | lo);
     }
-    Ok(out)
+    Ok(())
 }
 
 fn hex_nibble(b: u8) -> StdResult<u8, String> {
@@ -1367,6 +1931,95 @@ fn hex_nibble(b: u8) -> StdResult<u8, String> {
     }
 }
 
+/// Variant lookup for a fieldless enum, built once per result schema by `#[derive(FromCell)]`.
+///
+/// A `TEXT` column is matched against variant names and a `FIXED` column with scale 0 against discriminants.
+pub struct EnumPlan {
+    type_name: &'static str,
+    lookup: EnumLookup,
+}
+
+enum EnumLookup {
+    Name(HashMap<Box<str>, usize>),
+    /// Names in declaration order; enums are small enough that a scan beats folding every cell into a new string.
+    CaseInsensitiveName(Vec<Box<str>>),
+    Discriminant(HashMap<i64, usize>),
+}
+
+impl EnumPlan {
+    /// Build the lookup for `variants`, given as `(name, discriminant)` pairs in declaration order.
+    ///
+    /// With `case_insensitive`, names are compared ignoring ASCII case. `#[derive(FromCell)]` passes them already
+    /// lowercased.
+    ///
+    /// # Errors
+    ///
+    /// Returns an incompatible column error when the column is neither `TEXT` nor an integer `FIXED`.
+    pub fn new<T>(
+        ctx: CellPlanContext<'_>,
+        variants: &[(&str, i64)],
+        case_insensitive: bool,
+    ) -> PlanBuildResult<Self> {
+        let column = ctx.column();
+        let lookup = match column.ty() {
+            ColumnType::Text { .. } if case_insensitive => EnumLookup::CaseInsensitiveName(
+                variants.iter().map(|(name, _)| Box::from(*name)).collect(),
+            ),
+            ColumnType::Text { .. } => {
+                let mut map = HashMap::with_capacity(variants.len());
+                for (index, (name, _)) in variants.iter().enumerate() {
+                    map.entry(Box::from(*name)).or_insert(index);
+                }
+                EnumLookup::Name(map)
+            }
+            ColumnType::Fixed { scale, .. } if scale.unwrap_or(0) == 0 => {
+                let mut map = HashMap::with_capacity(variants.len());
+                for (index, (_, discriminant)) in variants.iter().enumerate() {
+                    map.entry(*discriminant).or_insert(index);
+                }
+                EnumLookup::Discriminant(map)
+            }
+            _ => return Err(incompatible_column::<T>(column, None)),
+        };
+
+        Ok(Self {
+            type_name: type_name::<T>(),
+            lookup,
+        })
+    }
+
+    /// Resolve a raw cell to the index of the matching variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns a conversion error for SQL `NULL` or a value that matches no variant.
+    pub fn variant_index(&self, raw: Option<&str>) -> CellDecodeResult<usize> {
+        let raw = required_raw(raw)?;
+        let index = match &self.lookup {
+            EnumLookup::Name(variants) => variants.get(raw).copied(),
+            EnumLookup::CaseInsensitiveName(names) => {
+                names.iter().position(|name| name.eq_ignore_ascii_case(raw))
+            }
+            EnumLookup::Discriminant(variants) => {
+                let discriminant = raw.parse::<i64>().map_err(|e| {
+                    CellConversionError::builder(format!("parse error: {e}"))
+                        .source(e)
+                        .build()
+                })?;
+                variants.get(&discriminant).copied()
+            }
+        };
+
+        index.ok_or_else(|| {
+            CellConversionError::builder(format!(
+                "'{raw}' does not match any variant of `{}`",
+                self.type_name
+            ))
+            .build()
+        })
+    }
+}
+
 macro_rules! impl_tuple_from_row {
     ($len:expr; $($t:ident => $idx:tt),*) => {
         impl<$($t: FromCell),*> FromRow for ($($t,)*) {
@@ -1387,6 +2040,25 @@ macro_rules! impl_tuple_from_row {
                 Ok(($(row.get_with_plan(&plan.$idx)?,)*))
             }
         }
+
+        impl<'a, $($t: FromCellRef<'a>),*> FromRowRef<'a> for ($($t,)*) {
+            type Plan = ($(CellRefPlan<'a, $t>,)*);
+
+            fn build_plan(ctx: RowPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+                let schema = ctx.schema();
+                if schema.len() != $len {
+                    return Err(SchemaError::ColumnCountMismatch(
+                        ColumnCountMismatchError::new($len, schema.len()),
+                    )
+                    .into());
+                }
+                Ok(($(CellRefPlan::<$t>::by_position(ctx, $idx)?,)*))
+            }
+
+            fn from_row_ref_with_plan(row: RowRef<'a>, plan: &Self::Plan) -> RowDecodeResult<Self> {
+                Ok(($(row.get_ref_with_plan(&plan.$idx)?,)*))
+            }
+        }
     };
 }
 
@@ -1417,7 +2089,7 @@ mod tests {
     use crate::{
         ErrorKind, Result, ResultTable,
         error::{CustomPlanError, RowConversionError},
-        result_table::test_data::{make_result_table_from_rows, make_schema},
+        result_table::test_data::{make_result_table_from_rows, make_schema, one_cell_table},
         rowset::parser::parse_inline_result_table,
     };
 
@@ -1618,11 +2290,6 @@ mod tests {
         assert_eq!(nsec, 990_000_000);
     }
 
-    fn one_cell_table(ty: ColumnType, value: &str) -> ResultTable {
-        let schema = make_schema(vec![("X".to_string(), ty, true)]);
-        make_result_table_from_rows(schema, vec![vec![Some(value.to_string())]]).unwrap()
-    }
-
     fn one_nullable_cell_table(ty: ColumnType, value: Option<&str>) -> ResultTable {
         let schema = make_schema(vec![("X".to_string(), ty, true)]);
         make_result_table_from_rows(
@@ -1794,9 +2461,50 @@ mod tests {
             .unwrap()
             .next()
             .unwrap()
-            .unwrap()
-            .0;
-        assert_eq!(value, DecimalValue::new("12.34"));
+            .unwrap()
+            .0;
+        assert_eq!(value, DecimalValue::new("12.34"));
+    }
+
+    #[test]
+    fn decfloat_decodes_as_decimal_value_and_lossy_float() {
+        let t = one_cell_table(
+            ColumnType::DecFloat,
+            "1.2345678901234567890123456789012345678e-40",
+        );
+        let (value,) = t
+            .rows::<(DecimalValue,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(value.raw(), "1.2345678901234567890123456789012345678e-40");
+
+        let err = match t.rows::<(f64,)>() {
+            Ok(_) => panic!("expected plan build to reject DECFLOAT for f64"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("Lossy<f64>"), "{err}");
+
+        let (value,) = t.rows::<(Lossy<f64>,)>().unwrap().next().unwrap().unwrap();
+        assert_eq!(value.into_inner(), 1.2345678901234568e-40);
+
+        let t = one_cell_table(ColumnType::DecFloat, "1e400");
+        let err = t
+            .rows::<(Lossy<f64>,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().contains("out of range for f64"), "{err}");
+        assert!(
+            one_cell_table(ColumnType::Real, "-inf")
+                .rows::<(Lossy<f64>,)>()
+                .unwrap()
+                .next()
+                .unwrap()
+                .is_ok()
+        );
     }
 
     #[test]
@@ -1974,9 +2682,138 @@ mod tests {
         assert_eq!(value.as_bytes(), b"Hello");
     }
 
+    #[test]
+    fn binary_values_are_split_from_a_shared_column_buffer() {
+        let schema = make_schema(vec![(
+            "B".to_string(),
+            ColumnType::Binary { length: None },
+            true,
+        )]);
+        let t = make_result_table_from_rows(
+            schema,
+            vec![
+                vec![Some("0102".to_string())],
+                vec![Some("01ZZ".to_string())],
+                vec![None],
+                vec![Some("030405".to_string())],
+            ],
+        )
+        .unwrap();
+        let values = t
+            .rows::<(Option<BinaryValue>,)>()
+            .unwrap()
+            .collect::<Vec<_>>();
+        let [
+            Ok((Some(first),)),
+            Err(err),
+            Ok((None,)),
+            Ok((Some(second),)),
+        ] = values.as_slice()
+        else {
+            panic!("unexpected decode results: {values:?}");
+        };
+        assert!(err.to_string().contains("invalid hex digit: Z"), "{err}");
+        assert_eq!(first.as_bytes(), [1, 2]);
+        assert_eq!(second.as_bytes(), [3, 4, 5]);
+        // The failed cell left nothing behind, so the next value starts right where the first one ended.
+        assert!(std::ptr::eq(
+            first.as_bytes().as_ptr_range().end,
+            second.as_bytes().as_ptr()
+        ));
+    }
+
+    #[test]
+    fn tuple_rows_ref_borrows_text_and_decodes_owned_columns() {
+        let schema = make_schema(vec![
+            ("NAME".to_string(), ColumnType::Text { length: None }, false),
+            (
+                "ID".to_string(),
+                ColumnType::Fixed {
+                    precision: Some(38),
+                    scale: Some(0),
+                },
+                false,
+            ),
+            ("NICK".to_string(), ColumnType::Text { length: None }, true),
+        ]);
+        let t = make_result_table_from_rows(
+            schema,
+            vec![
+                vec![Some("alice".to_string()), Some("7".to_string()), None],
+                vec![
+                    Some("bob".to_string()),
+                    Some("8".to_string()),
+                    Some("bobby".to_string()),
+                ],
+            ],
+        )
+        .unwrap();
+
+        let rows = t
+            .rows_ref::<(&str, i64, Option<Cow<'_, str>>)>()
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rows[0], ("alice", 7, None));
+        assert_eq!(rows[1], ("bob", 8, Some(Cow::Borrowed("bobby"))));
+        assert!(matches!(rows[1].2, Some(Cow::Borrowed(_))));
+
+        let again = t.rows_ref::<(&str, i64, Option<&str>)>().unwrap().next();
+        assert!(std::ptr::eq(again.unwrap().unwrap().0, rows[0].0));
+
+        let err = match t.rows_ref::<(&str, i64)>() {
+            Ok(_) => panic!("a two-column tuple must not decode a three-column table"),
+            Err(err) => err,
+        };
+        assert!(matches!(
+            err.as_schema_error(),
+            Some(SchemaError::ColumnCountMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn borrowed_bytes_follow_the_column_type() {
+        let text = one_cell_table(ColumnType::Text { length: None }, "hi");
+        let (bytes, cow) = (
+            text.rows_ref::<(&[u8],)>()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap()
+                .0,
+            text.rows_ref::<(Cow<'_, [u8]>,)>()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap()
+                .0,
+        );
+        assert_eq!(bytes, b"hi");
+        assert!(matches!(cow, Cow::Borrowed(b"hi")));
+
+        let binary = one_cell_table(ColumnType::Binary { length: None }, "6869");
+        let cow = binary
+            .rows_ref::<(Option<Cow<'_, [u8]>>,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .0;
+        assert!(matches!(cow, Some(Cow::Owned(ref bytes)) if bytes == b"hi"));
+
+        let err = match binary.rows_ref::<(&[u8],)>() {
+            Ok(_) => panic!("BINARY hex must not be borrowed as raw bytes"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("hex-encoded"), "{err}");
+    }
+
     #[test]
     fn json_typed_array_decodes_null_elements_via_undefined_normalization() {
-        let t = one_cell_table(ColumnType::Array, "[\n  1,\n  undefined,\n  3\n]");
+        let t = one_cell_table(
+            ColumnType::Array { element: None },
+            "[\n  1,\n  undefined,\n  3\n]",
+        );
         let value = t
             .rows::<(Json<Vec<Option<i64>>>,)>()
             .unwrap()
@@ -1989,7 +2826,7 @@ mod tests {
 
     #[test]
     fn json_typed_object_decodes_integer_keyed_map() {
-        let t = one_cell_table(ColumnType::Object, r#"{"1":"a","2":"b"}"#);
+        let t = one_cell_table(ColumnType::Object { fields: None }, r#"{"1":"a","2":"b"}"#);
         let value = t
             .rows::<(Json<std::collections::BTreeMap<i64, String>>,)>()
             .unwrap()
@@ -2013,7 +2850,7 @@ mod tests {
             nested: Inner,
         }
 
-        let t = one_cell_table(ColumnType::Object, r#"{"nested":{"x":7}}"#);
+        let t = one_cell_table(ColumnType::Object { fields: None }, r#"{"nested":{"x":7}}"#);
         let value = t
             .rows::<(Json<Outer>,)>()
             .unwrap()
@@ -2032,7 +2869,10 @@ mod tests {
     /// A NULL array element (`undefined`) cannot land in a non-`Option` slot: `serde` rejects the `null`.
     #[test]
     fn json_typed_non_option_vec_rejects_null_element() {
-        let t = one_cell_table(ColumnType::Array, "[\n  1,\n  undefined,\n  3\n]");
+        let t = one_cell_table(
+            ColumnType::Array { element: None },
+            "[\n  1,\n  undefined,\n  3\n]",
+        );
         let err = t
             .rows::<(Json<Vec<i64>>,)>()
             .unwrap()
@@ -2067,7 +2907,10 @@ mod tests {
 
     #[test]
     fn serde_json_value_normalizes_undefined_array_elements() {
-        let t = one_cell_table(ColumnType::Array, "[\n  1,\n  undefined,\n  3\n]");
+        let t = one_cell_table(
+            ColumnType::Array { element: None },
+            "[\n  1,\n  undefined,\n  3\n]",
+        );
         let value = t
             .rows::<(serde_json::Value,)>()
             .unwrap()
@@ -2251,7 +3094,14 @@ mod tests {
     }
 
     fn decode_vector<T: FromCell>(raw: &str) -> CellDecodeResult<T> {
-        let schema = make_schema(vec![("X".to_string(), ColumnType::Vector, true)]);
+        let schema = make_schema(vec![(
+            "X".to_string(),
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            },
+            true,
+        )]);
         let column = schema.column_at(0).expect("single-column schema");
         let ctx = CellPlanContext::new(RowPlanContext::new(&schema), column);
         let plan = T::build_plan(ctx).unwrap();
@@ -2367,19 +3217,27 @@ mod tests {
 
     #[test]
     fn option_vector_handles_sql_null() {
-        let value = one_nullable_cell_table(ColumnType::Vector, None)
-            .rows::<(Option<Vector<i32>>,)>()
-            .unwrap()
-            .next()
-            .unwrap()
-            .unwrap()
-            .0;
+        let value = one_nullable_cell_table(
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            },
+            None,
+        )
+        .rows::<(Option<Vector<i32>>,)>()
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .0;
         assert_eq!(value, None);
     }
 
     #[test]
     fn vector_rejects_non_vector_column() {
-        let err = match one_cell_table(ColumnType::Array, "[1,2,3]").rows::<(Vector<i32>,)>() {
+        let err = match one_cell_table(ColumnType::Array { element: None }, "[1,2,3]")
+            .rows::<(Vector<i32>,)>()
+        {
             Ok(_) => panic!("expected plan build to reject ARRAY column for Vector"),
             Err(err) => err,
         };
@@ -2389,16 +3247,59 @@ mod tests {
         ));
     }
 
-    /// A caller can still read the raw `VECTOR` payload as text; `String` accepts any column type.
     #[test]
-    fn string_reads_raw_vector_payload() {
-        let value = one_cell_table(ColumnType::Vector, "[1,2,3]")
-            .rows::<(String,)>()
+    fn vector_plans_check_reported_element_type_and_dimension() {
+        let reported = |element| ColumnType::Vector {
+            element: Some(element),
+            dimension: Some(3),
+        };
+
+        let err = match one_cell_table(reported(VectorElementType::Float), "[1.5,2.0,3.0]")
+            .rows::<(Vector<i32>,)>()
+        {
+            Ok(_) => panic!("expected plan build to reject VECTOR(FLOAT) for Vector<i32>"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("Vector<f32>"), "{err}");
+
+        // A reported FLOAT element type lets integer-only tokens through.
+        let (value,) = one_cell_table(reported(VectorElementType::Float), "[1,2,3]")
+            .rows::<(Vector<f32>,)>()
             .unwrap()
             .next()
             .unwrap()
+            .unwrap();
+        assert_eq!(value.as_slice(), &[1.0, 2.0, 3.0]);
+
+        let err = one_cell_table(reported(VectorElementType::Int), "[1,2]")
+            .rows::<(Vector<i32>,)>()
+            .unwrap()
+            .next()
             .unwrap()
-            .0;
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("expected 3 vector elements, got 2"),
+            "{err}"
+        );
+    }
+
+    /// A caller can still read the raw `VECTOR` payload as text; `String` accepts any column type.
+    #[test]
+    fn string_reads_raw_vector_payload() {
+        let value = one_cell_table(
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            },
+            "[1,2,3]",
+        )
+        .rows::<(String,)>()
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .0;
         assert_eq!(value, "[1,2,3]");
     }
 
@@ -2663,4 +3564,383 @@ mod tests {
         assert_eq!(cell.row_index(), 0);
         assert_eq!(cell.column_name(), "X");
     }
+
+    #[cfg(feature = "derive")]
+    mod upper_text {
+        use super::*;
+
+        pub(super) type Plan = ();
+
+        pub(super) fn build_plan(_ctx: CellPlanContext<'_>) -> PlanBuildResult<Plan> {
+            Ok(())
+        }
+
+        pub(super) fn from_cell_with_plan(
+            raw: Option<&str>,
+            _plan: &Plan,
+        ) -> CellDecodeResult<String> {
+            required_raw(raw).map(str::to_uppercase)
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, PartialEq, crate::FromRow)]
+    #[snowflake(crate = "crate")]
+    struct AuditColumns {
+        created_by: String,
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, PartialEq, crate::FromRow)]
+    #[snowflake(crate = "crate")]
+    struct AccountRow {
+        #[snowflake(try_from = "i64")]
+        id: u32,
+        #[snowflake(with = "upper_text")]
+        name: String,
+        #[snowflake(flatten)]
+        audit: AuditColumns,
+        #[snowflake(default)]
+        nickname: String,
+        #[snowflake(default)]
+        region: Option<String>,
+        #[snowflake(skip)]
+        cache: Vec<u8>,
+    }
+
+    #[cfg(feature = "derive")]
+    fn account_table(id: &str, nickname: Option<&str>) -> ResultTable {
+        let fixed = ColumnType::Fixed {
+            precision: None,
+            scale: Some(0),
+        };
+        let text = ColumnType::Text { length: None };
+        let schema = make_schema(vec![
+            ("ID".to_string(), fixed, false),
+            ("NAME".to_string(), text.clone(), false),
+            ("CREATED_BY".to_string(), text.clone(), false),
+            ("NICKNAME".to_string(), text, true),
+        ]);
+        make_result_table_from_rows(
+            schema,
+            vec![vec![
+                Some(id.to_string()),
+                Some("alice".to_string()),
+                Some("admin".to_string()),
+                nickname.map(str::to_string),
+            ]],
+        )
+        .unwrap()
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_field_attributes_decode_through_adapters_flatten_and_defaults() {
+        let decode = |nickname| {
+            account_table("7", nickname)
+                .rows::<AccountRow>()
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap()
+        };
+
+        assert_eq!(
+            decode(None),
+            AccountRow {
+                id: 7,
+                name: "ALICE".to_string(),
+                audit: AuditColumns {
+                    created_by: "admin".to_string(),
+                },
+                nickname: String::new(),
+                region: None,
+                cache: Vec::new(),
+            }
+        );
+        assert_eq!(decode(Some("al")).nickname, "al");
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_try_from_failure_is_a_contextualized_cell_error() {
+        let err = account_table("-1", None)
+            .rows::<AccountRow>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+
+        let decode = err
+            .as_cell_decode_error()
+            .expect("try_from failure should be contextualized");
+        assert_eq!(decode.column_name(), "ID");
+        assert_eq!(
+            decode.conversion_error().reason(),
+            "conversion from `i64` failed"
+        );
+        assert!(decode.conversion_error().source().is_some());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_positional_default_and_skip_do_not_consume_columns() {
+        #[derive(Debug, PartialEq, crate::FromRow)]
+        #[snowflake(crate = "crate")]
+        struct Pair(String, #[snowflake(skip)] u8, #[snowflake(default)] i64);
+
+        let table = one_cell_table(ColumnType::Text { length: None }, "x");
+        let row = table.rows::<Pair>().unwrap().next().unwrap().unwrap();
+        assert_eq!(row, Pair("x".to_string(), 0, 0));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_borrowed_row_decodes_without_copying() {
+        #[derive(crate::FromRow)]
+        #[snowflake(crate = "crate")]
+        struct BorrowedAccount<'a> {
+            id: i64,
+            name: &'a str,
+            #[snowflake(default)]
+            nickname: &'a str,
+            #[snowflake(flatten)]
+            audit: AuditColumns,
+        }
+
+        let table = account_table("7", None);
+        let first = table
+            .rows_ref::<BorrowedAccount<'_>>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.id, 7);
+        assert_eq!(first.name, "alice");
+        assert_eq!(first.nickname, "");
+        assert_eq!(first.audit.created_by, "admin");
+
+        // Both iterations point into the table's own storage rather than into per-row copies.
+        let second = table
+            .rows_ref::<BorrowedAccount<'_>>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert!(std::ptr::eq(first.name, second.name));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_generic_row_adds_decode_bounds() {
+        #[derive(Debug, PartialEq, crate::FromRow)]
+        #[snowflake(crate = "crate")]
+        struct Page<T, I> {
+            id: I,
+            #[snowflake(flatten)]
+            item: T,
+        }
+
+        let table = account_table("7", Some("al"));
+        let row = table
+            .rows::<Page<AuditColumns, i64>>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            row,
+            Page {
+                id: 7,
+                item: AuditColumns {
+                    created_by: "admin".to_string(),
+                },
+            }
+        );
+
+        let row = table
+            .rows_ref::<Page<AuditColumns, String>>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.id, "7");
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, PartialEq, crate::FromCell)]
+    #[snowflake(crate = "crate")]
+    enum OrderStatus {
+        Open = 1,
+        InReview = 5,
+        #[snowflake(rename = "done")]
+        Closed,
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, PartialEq, crate::FromCell)]
+    #[snowflake(crate = "crate", rename_all = "none", case_insensitive)]
+    enum Tier {
+        Gold,
+        Silver,
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Debug, PartialEq, crate::FromCell)]
+    #[snowflake(crate = "crate")]
+    #[repr(u64)]
+    enum Priority {
+        Low = 1,
+        High = i64::MAX as u64,
+    }
+
+    #[cfg(feature = "derive")]
+    fn decode_enum<T: FromCell>(ty: ColumnType, value: &str) -> crate::Result<T> {
+        let table = one_cell_table(ty, value);
+        let (value,) = table.rows::<(T,)>()?.next().unwrap()?;
+        Ok(value)
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_from_cell_matches_text_by_variant_name() {
+        let text = || ColumnType::Text { length: None };
+        assert_eq!(
+            decode_enum::<OrderStatus>(text(), "IN_REVIEW").unwrap(),
+            OrderStatus::InReview
+        );
+        assert_eq!(
+            decode_enum::<OrderStatus>(text(), "done").unwrap(),
+            OrderStatus::Closed
+        );
+        assert_eq!(decode_enum::<Tier>(text(), "gOLD").unwrap(), Tier::Gold);
+
+        let err = decode_enum::<OrderStatus>(text(), "open").unwrap_err();
+        let cell = err
+            .as_cell_decode_error()
+            .expect("unknown variant should be a cell decode error");
+        assert!(
+            cell.conversion_error()
+                .reason()
+                .starts_with("'open' does not match any variant of `")
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_from_cell_matches_fixed_by_discriminant() {
+        let fixed = || ColumnType::Fixed {
+            precision: None,
+            scale: Some(0),
+        };
+        assert_eq!(
+            decode_enum::<OrderStatus>(fixed(), "5").unwrap(),
+            OrderStatus::InReview
+        );
+        assert_eq!(
+            decode_enum::<OrderStatus>(fixed(), "6").unwrap(),
+            OrderStatus::Closed
+        );
+        assert!(
+            decode_enum::<OrderStatus>(fixed(), "2")
+                .unwrap_err()
+                .as_cell_decode_error()
+                .is_some()
+        );
+
+        assert_eq!(
+            decode_enum::<Priority>(fixed(), "1").unwrap(),
+            Priority::Low
+        );
+        assert_eq!(
+            decode_enum::<Priority>(fixed(), "9223372036854775807").unwrap(),
+            Priority::High
+        );
+
+        let err = decode_enum::<OrderStatus>(ColumnType::Real, "5").unwrap_err();
+        assert!(matches!(
+            err.as_schema_error(),
+            Some(SchemaError::IncompatibleColumnType(_))
+        ));
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn decimal_unscaled_pads_fraction_to_scale_and_rejects_excess_digits() {
+        assert_eq!(parse_decimal_unscaled("12.5", 2), Ok(1250));
+        assert_eq!(parse_decimal_unscaled("-0.01", 2), Ok(-1));
+        assert_eq!(parse_decimal_unscaled("7", 3), Ok(7000));
+        assert_eq!(
+            parse_decimal_unscaled("99999999999999999999999999999999999999", 0),
+            Ok(99_999_999_999_999_999_999_999_999_999_999_999_999)
+        );
+        assert!(parse_decimal_unscaled("1.234", 2).is_err());
+        assert!(parse_decimal_unscaled("abc", 0).is_err());
+        assert!(parse_decimal_unscaled("1", 39).is_err());
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_lookup_modes_resolve_folded_and_quoted_labels() {
+        #[derive(Debug, PartialEq, crate::FromRow)]
+        #[snowflake(crate = "crate", lookup = "case_insensitive")]
+        struct Loose {
+            order_id: i64,
+        }
+
+        #[derive(Debug, PartialEq, crate::FromRow)]
+        #[snowflake(crate = "crate", lookup = "identifier", rename_all = "none")]
+        struct Dbt {
+            #[snowflake(rename = "\"order_id\"")]
+            order_id: i64,
+            // Inherits the identifier lookup from the outer context.
+            #[snowflake(flatten)]
+            audit: Audit,
+        }
+
+        #[derive(Debug, PartialEq, crate::FromRow)]
+        #[snowflake(crate = "crate", rename_all = "none")]
+        struct Audit {
+            created_by: String,
+        }
+
+        let fixed = ColumnType::Fixed {
+            precision: None,
+            scale: Some(0),
+        };
+        let schema = make_schema(vec![
+            ("order_id".to_string(), fixed, false),
+            (
+                "CREATED_BY".to_string(),
+                ColumnType::Text { length: None },
+                false,
+            ),
+        ]);
+        let table = make_result_table_from_rows(
+            schema,
+            vec![vec![Some("4".to_string()), Some("etl".to_string())]],
+        )
+        .unwrap();
+
+        let loose = table.rows::<Loose>().unwrap().next().unwrap().unwrap();
+        assert_eq!(loose, Loose { order_id: 4 });
+
+        let dbt = table.rows::<Dbt>().unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            dbt,
+            Dbt {
+                order_id: 4,
+                audit: Audit {
+                    created_by: "etl".to_string(),
+                },
+            }
+        );
+
+        // Without a lookup mode the lowercase label still needs an exact match.
+        let err = table.rows::<Audit>().map(|_| ()).unwrap_err();
+        assert!(matches!(
+            err.as_schema_error(),
+            Some(SchemaError::MissingColumn(error)) if error.name() == "created_by"
+        ));
+    }
 }
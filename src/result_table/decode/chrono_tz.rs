@@ -0,0 +1,164 @@
+use chrono::DateTime;
+use chrono_tz::Tz;
+
+use super::{
+    FromCell, TimestampPlan, incompatible_column, parse_timestamp_epoch_with, required_raw,
+};
+use crate::{
+    decode::PlanBuildResult,
+    result_table::{
+        CellConversionError, CellDecodeResult, plan::CellPlanContext, schema::ColumnType,
+    },
+};
+
+/// Plan for a `TIMESTAMP_LTZ` column decoded in the result's session time zone.
+pub struct ZonedTimestampPlan {
+    timestamp: TimestampPlan,
+    zone: Tz,
+}
+
+/// `TIMESTAMP_LTZ` cells decode in the zone named by [`Schema::session_timezone`](crate::Schema::session_timezone),
+/// matching the wall-clock time Snowflake shows for them.
+///
+/// Planning fails when the result does not report a session `TIMEZONE`, or reports one that is not an IANA zone name.
+/// `TIMESTAMP_TZ` cells carry their own offset; decode them as `DateTime<FixedOffset>`.
+impl FromCell for DateTime<Tz> {
+    type Plan = ZonedTimestampPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        let ColumnType::TimestampLtz { scale } = column.ty() else {
+            return Err(incompatible_column::<Self>(column, None));
+        };
+        let timestamp = TimestampPlan::from_metadata_scale(*scale)
+            .map_err(|detail| incompatible_column::<Self>(column, Some(detail)))?;
+        let schema = ctx.schema();
+        let zone = schema.session_zone().tz().ok_or_else(|| {
+            let detail = match schema.session_timezone() {
+                Some(name) => format!("session TIMEZONE '{name}' is not an IANA time zone"),
+                None => "the result does not report a session TIMEZONE".to_string(),
+            };
+            incompatible_column::<Self>(column, Some(detail))
+        })?;
+        Ok(ZonedTimestampPlan { timestamp, zone })
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        parse_timestamp_epoch_with(required_raw(raw)?, &plan.timestamp)
+            .map(|dt| dt.with_timezone(&plan.zone))
+            .map_err(|m| CellConversionError::builder(m).build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{NaiveDate, Offset, Utc};
+
+    use super::*;
+    use crate::{
+        CellValue, DynamicRow, ResultTable, SerializeOptions,
+        result_table::{Column, Schema, test_data::make_result_table_from_rows},
+    };
+
+    fn ltz_table(timezone: Option<&str>, value: &str) -> ResultTable {
+        let schema = Schema::from_columns(vec![Column::new(
+            "X",
+            0,
+            true,
+            ColumnType::TimestampLtz { scale: Some(0) },
+        )])
+        .with_session_timezone(timezone.map(Arc::from));
+        make_result_table_from_rows(Arc::new(schema), vec![vec![Some(value.to_string())]]).unwrap()
+    }
+
+    #[test]
+    fn ltz_decodes_in_the_session_zone() {
+        // 2024-01-15T12:00:00Z is 04:00 in Los Angeles (PST, -08:00).
+        let table = ltz_table(Some("America/Los_Angeles"), "1705320000");
+        let (dt,) = table
+            .rows::<(DateTime<Tz>,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(dt.timezone(), Tz::America__Los_Angeles);
+        assert_eq!(
+            dt.naive_local(),
+            NaiveDate::from_ymd_opt(2024, 1, 15)
+                .unwrap()
+                .and_hms_opt(4, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(dt.offset().fix().local_minus_utc(), -8 * 3600);
+        assert_eq!(dt.with_timezone(&Utc).timestamp(), 1_705_320_000);
+    }
+
+    #[test]
+    fn dynamic_rows_keep_ltz_anchored_to_utc() {
+        let table = ltz_table(Some("Asia/Tokyo"), "1705320000");
+        let row = table.rows::<DynamicRow>().unwrap().next().unwrap().unwrap();
+        match row.value_at(0).unwrap() {
+            CellValue::TimestampLtz(dt) => assert_eq!(dt.to_rfc3339(), "2024-01-15T12:00:00+00:00"),
+            other => panic!("expected TimestampLtz, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn ltz_renders_in_the_session_zone_when_asked() {
+        let table = ltz_table(Some("Asia/Tokyo"), "1705320000");
+        let row = table.rows::<DynamicRow>().unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            row.ltz_in_session_timezone_at(0)
+                .unwrap()
+                .unwrap()
+                .to_rfc3339(),
+            "2024-01-15T21:00:00+09:00"
+        );
+
+        let zoned = SerializeOptions::new().with_ltz_in_session_timezone(true);
+        assert_eq!(
+            serde_json::to_value(table.serialize_with(&zoned)).unwrap(),
+            serde_json::json!([{"X": "2024-01-15T21:00:00+09:00"}])
+        );
+        assert_eq!(
+            serde_json::to_value(row.serialize_with(&SerializeOptions::new())).unwrap(),
+            serde_json::json!({"X": "2024-01-15T12:00:00+00:00"})
+        );
+        assert!(
+            table
+                .pretty()
+                .with_ltz_in_session_timezone(true)
+                .to_string()
+                .contains("| 2024-01-15T21:00:00+09:00 |")
+        );
+
+        // Without a resolvable zone the value stays in UTC.
+        let table = ltz_table(None, "1705320000");
+        let row = table.rows::<DynamicRow>().unwrap().next().unwrap().unwrap();
+        assert_eq!(row.ltz_in_session_timezone_at(0).unwrap(), None);
+        assert_eq!(
+            serde_json::to_value(row.serialize_with(&zoned)).unwrap(),
+            serde_json::json!({"X": "2024-01-15T12:00:00+00:00"})
+        );
+    }
+
+    #[test]
+    fn plan_rejects_missing_or_unknown_session_timezone() {
+        for (timezone, expected) in [
+            (None, "does not report a session TIMEZONE"),
+            (
+                Some("Mars/Olympus_Mons"),
+                "'Mars/Olympus_Mons' is not an IANA time zone",
+            ),
+        ] {
+            let err = ltz_table(timezone, "0")
+                .rows::<(DateTime<Tz>,)>()
+                .err()
+                .expect("planning must fail");
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+}
@@ -0,0 +1,267 @@
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+use super::{FromCell, incompatible_column, required_raw};
+use crate::{
+    decode::PlanBuildResult,
+    result_table::{
+        CellConversionError, CellDecodeResult, plan::CellPlanContext, schema::ColumnType,
+    },
+};
+
+/// Most digits every `rust_decimal::Decimal` can hold; its 96-bit mantissa tops out at
+/// 79,228,162,514,264,337,593,543,950,335.
+#[cfg(feature = "rust_decimal")]
+const DECIMAL_MAX_PRECISION: u8 = 28;
+
+#[cfg(feature = "rust_decimal")]
+impl FromCell for Decimal {
+    type Plan = ();
+
+    /// Accepts `NUMBER(p, s)` columns whose every value fits: `p <= 28` and `s <= 28`.
+    ///
+    /// Wider columns, including the default `NUMBER(38, 0)` and columns whose precision Snowflake did not report, are
+    /// rejected up front; cast them in SQL or decode as `BigDecimal` or [`DecimalValue`](crate::DecimalValue) instead.
+    /// `DECFLOAT` columns are accepted and checked per cell, failing on values that do not fit exactly.
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        match column.ty() {
+            ColumnType::Fixed { precision, scale } => {
+                // An unreported precision may be anything up to Snowflake's maximum of 38.
+                let precision = precision.unwrap_or(38);
+                let scale = scale.unwrap_or(0);
+                if precision > DECIMAL_MAX_PRECISION || u32::from(scale) > Decimal::MAX_SCALE {
+                    return Err(incompatible_column::<Self>(
+                        column,
+                        Some(format!(
+                            "NUMBER({precision}, {scale}) may not fit rust_decimal::Decimal, which holds at most \
+                             {DECIMAL_MAX_PRECISION} digits and scale {}",
+                            Decimal::MAX_SCALE
+                        )),
+                    ));
+                }
+                Ok(())
+            }
+            ColumnType::DecFloat => Ok(()),
+            _ => Err(incompatible_column::<Self>(column, None)),
+        }
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let raw = required_raw(raw)?;
+        let (mantissa, exponent) = match raw.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (
+                mantissa,
+                exponent.parse::<i64>().map_err(|e| {
+                    CellConversionError::builder(format!("parse error: {e}"))
+                        .source(e)
+                        .build()
+                })?,
+            ),
+            None => (raw, 0),
+        };
+        // `from_str_exact` fails instead of rounding when the value does not fit.
+        let mut value = Decimal::from_str_exact(mantissa).map_err(|e| {
+            CellConversionError::builder(format!("parse error: {e}"))
+                .source(e)
+                .build()
+        })?;
+        if exponent == 0 || value.is_zero() {
+            return Ok(value);
+        }
+        // Apply a `DECFLOAT` exponent by moving the scale, dropping trailing zeros first so `1.000e-26` still fits.
+        value = value.normalize();
+        let scale = i64::from(value.scale()) - exponent;
+        let out_of_range = || {
+            CellConversionError::builder(format!("'{raw}' does not fit rust_decimal::Decimal"))
+                .build()
+        };
+        if scale >= 0 {
+            let scale = u32::try_from(scale).map_err(|_| out_of_range())?;
+            value.set_scale(scale).map_err(|_| out_of_range())?;
+        } else {
+            value.set_scale(0).map_err(|_| out_of_range())?;
+            for _ in 0..-scale {
+                value = value.checked_mul(Decimal::TEN).ok_or_else(out_of_range)?;
+            }
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl FromCell for BigDecimal {
+    type Plan = ();
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        match column.ty() {
+            ColumnType::Fixed { .. } | ColumnType::DecFloat => Ok(()),
+            _ => Err(incompatible_column::<Self>(column, None)),
+        }
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let raw = required_raw(raw)?;
+        raw.parse::<BigDecimal>().map_err(|e| {
+            CellConversionError::builder(format!("parse error: {e}"))
+                .source(e)
+                .build()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ResultTable,
+        result_table::test_data::{make_result_table_from_rows, make_schema},
+    };
+
+    fn fixed_table(precision: u8, scale: u8, values: &[&str]) -> ResultTable {
+        let ty = ColumnType::Fixed {
+            precision: Some(precision),
+            scale: Some(scale),
+        };
+        let schema = make_schema(vec![("X".to_string(), ty, true)]);
+        let rows = values.iter().map(|v| vec![Some(v.to_string())]).collect();
+        make_result_table_from_rows(schema, rows).unwrap()
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_decodes_exactly_and_keeps_scale() {
+        let table = fixed_table(12, 4, &["1234.5000", "-0.0001"]);
+
+        let values = table
+            .rows::<(Decimal,)>()
+            .unwrap()
+            .map(|row| row.unwrap().0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(values[0].to_string(), "1234.5000");
+        assert_eq!(values[1], Decimal::new(-1, 4));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_rejects_columns_it_cannot_hold() {
+        for (precision, scale) in [(38, 0), (29, 2), (30, 29)] {
+            let table = fixed_table(precision, scale, &["1"]);
+            let err = match table.rows::<(Decimal,)>() {
+                Ok(_) => panic!("NUMBER({precision}, {scale}) unexpectedly planned as Decimal"),
+                Err(err) => err,
+            };
+            assert!(
+                matches!(
+                    err.as_schema_error(),
+                    Some(crate::error::SchemaError::IncompatibleColumnType(_))
+                ),
+                "{err:?}"
+            );
+        }
+        assert!(fixed_table(28, 28, &["0.1"]).rows::<(Decimal,)>().is_ok());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_rejects_columns_without_reported_precision() {
+        let ty = ColumnType::Fixed {
+            precision: None,
+            scale: Some(0),
+        };
+        let schema = make_schema(vec![("X".to_string(), ty, true)]);
+        let table = make_result_table_from_rows(schema, vec![vec![Some("1".to_string())]]).unwrap();
+        let err = match table.rows::<(Decimal,)>() {
+            Ok(_) => panic!("NUMBER without precision unexpectedly planned as Decimal"),
+            Err(err) => err,
+        };
+        assert!(
+            matches!(
+                err.as_schema_error(),
+                Some(crate::error::SchemaError::IncompatibleColumnType(_))
+            ),
+            "{err:?}"
+        );
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_applies_decfloat_exponents_exactly() {
+        let schema = make_schema(vec![("X".to_string(), ColumnType::DecFloat, true)]);
+        let decode = |value: &str| {
+            make_result_table_from_rows(schema.clone(), vec![vec![Some(value.to_string())]])
+                .unwrap()
+                .rows::<(Decimal,)>()
+                .unwrap()
+                .next()
+                .unwrap()
+                .map(|(value,)| value)
+        };
+
+        assert_eq!(decode("12.5").unwrap(), Decimal::new(125, 1));
+        assert_eq!(decode("1.25e-3").unwrap(), Decimal::new(125, 5));
+        assert_eq!(decode("1.000e-26").unwrap(), Decimal::new(1, 26));
+        assert_eq!(decode("1.5E+4").unwrap(), Decimal::new(15000, 0));
+        assert_eq!(decode("0e-16000").unwrap(), Decimal::ZERO);
+        assert!(decode("1.5e-40").is_err());
+        assert!(decode("1e30").is_err());
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn big_decimal_decodes_full_number_range() {
+        let max = "9".repeat(38);
+        let table = fixed_table(38, 0, &[&max]);
+
+        let value = table
+            .rows::<(BigDecimal,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .0;
+
+        assert_eq!(value.to_plain_string(), max);
+        assert_eq!(value.digits(), 38);
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn big_decimal_decodes_decfloat() {
+        let schema = make_schema(vec![("X".to_string(), ColumnType::DecFloat, true)]);
+        let table =
+            make_result_table_from_rows(schema, vec![vec![Some("-1.5e-40".to_string())]]).unwrap();
+
+        let (value,) = table
+            .rows::<(BigDecimal,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            value,
+            "-0.00000000000000000000000000000000000000015"
+                .parse::<BigDecimal>()
+                .unwrap()
+        );
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn big_decimal_keeps_scale_and_reports_bad_text() {
+        let table = fixed_table(10, 3, &["-12.500", "twelve"]);
+        let mut rows = table.rows::<(BigDecimal,)>().unwrap();
+
+        let value = rows.next().unwrap().unwrap().0;
+        assert_eq!(value.to_plain_string(), "-12.500");
+        assert_eq!(value.fractional_digit_count(), 3);
+
+        let err = rows.next().unwrap().unwrap_err();
+        assert!(err.as_cell_decode_error().is_some(), "{err:?}");
+    }
+}
@@ -0,0 +1,472 @@
+use std::{result::Result as StdResult, str::FromStr};
+
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use geojson::GeoJson;
+use wkt::{ToWkt, Wkt};
+
+use super::{FromCell, decode_hex, incompatible_column, required_raw};
+use crate::{
+    decode::PlanBuildResult,
+    result_table::{
+        CellConversionError, CellDecodeResult, plan::CellPlanContext, schema::ColumnType,
+    },
+};
+
+/// Payload format of a `GEOGRAPHY` / `GEOMETRY` cell, chosen from the column metadata.
+///
+/// Snowflake renders geospatial values in the session's `GEOGRAPHY_OUTPUT_FORMAT` / `GEOMETRY_OUTPUT_FORMAT` and
+/// reports the column as `OBJECT` (GeoJSON), `TEXT` (WKT/EWKT), or `BINARY` (WKB/EWKB) accordingly. A column reported
+/// as `GEOGRAPHY` / `GEOMETRY` itself is sniffed per cell instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GeoPlan {
+    /// GeoJSON geometry object.
+    GeoJson,
+    /// WKT, or EWKT with a leading `SRID=<n>;`.
+    Wkt,
+    /// Hex-encoded WKB or EWKB.
+    Wkb,
+    /// Format detected from each cell's payload.
+    Detect,
+}
+
+impl GeoPlan {
+    fn for_column<T>(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self> {
+        let column = ctx.column();
+        match column.ty() {
+            ColumnType::Geography | ColumnType::Geometry => Ok(Self::Detect),
+            ColumnType::Object { fields: None } | ColumnType::Variant => Ok(Self::GeoJson),
+            ColumnType::Text { .. } => Ok(Self::Wkt),
+            ColumnType::Binary { .. } => Ok(Self::Wkb),
+            _ => Err(incompatible_column::<T>(column, None)),
+        }
+    }
+
+    fn resolve(self, raw: &str) -> Self {
+        match self {
+            Self::Detect if raw.trim_start().starts_with('{') => Self::GeoJson,
+            Self::Detect if !raw.is_empty() && raw.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                Self::Wkb
+            }
+            Self::Detect => Self::Wkt,
+            plan => plan,
+        }
+    }
+}
+
+/// Decodes GeoJSON, WKT/EWKT, and WKB/EWKB payloads. EWKT and EWKB SRIDs and any Z/M ordinates are dropped, since
+/// `geo_types` geometries are two-dimensional and carry no reference system.
+impl FromCell for Geometry<f64> {
+    type Plan = GeoPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        GeoPlan::for_column::<Self>(ctx)
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let raw = required_raw(raw)?;
+        match plan.resolve(raw) {
+            GeoPlan::GeoJson => {
+                let geojson = GeoJson::from_str(raw).map_err(geojson_error)?;
+                Geometry::try_from(geojson).map_err(geojson_error)
+            }
+            GeoPlan::Wkb => {
+                let bytes = decode_hex(raw).map_err(|m| CellConversionError::builder(m).build())?;
+                read_wkb(&bytes).map_err(|m| CellConversionError::builder(m).build())
+            }
+            GeoPlan::Wkt | GeoPlan::Detect => Geometry::try_from(parse_wkt(raw)?).map_err(|e| {
+                CellConversionError::builder(format!("unsupported WKT geometry: {e}")).build()
+            }),
+        }
+    }
+}
+
+/// Decodes WKT/EWKT payloads as written, and GeoJSON or WKB payloads through [`geo_types::Geometry`].
+impl FromCell for Wkt<f64> {
+    type Plan = GeoPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        GeoPlan::for_column::<Self>(ctx)
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let raw = required_raw(raw)?;
+        match plan.resolve(raw) {
+            GeoPlan::Wkt | GeoPlan::Detect => parse_wkt(raw),
+            resolved => Geometry::from_cell_with_plan(Some(raw), &resolved).map(|g| g.to_wkt()),
+        }
+    }
+}
+
+/// Raw WKB or EWKB bytes of a `GEOGRAPHY` / `GEOMETRY` cell.
+///
+/// Only WKB payloads decode into this type; set `GEOGRAPHY_OUTPUT_FORMAT` / `GEOMETRY_OUTPUT_FORMAT` to `'WKB'` or
+/// `'EWKB'` for the session. Use [`Wkb::to_geometry`] to read the bytes as a [`geo_types::Geometry`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Wkb(Vec<u8>);
+
+impl Wkb {
+    /// Borrow the WKB bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Consume the wrapper, returning the WKB bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Read the bytes as a two-dimensional geometry, dropping any SRID and Z/M ordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the bytes are not well-formed WKB.
+    pub fn to_geometry(&self) -> crate::Result<Geometry<f64>> {
+        read_wkb(&self.0).map_err(crate::Error::other)
+    }
+}
+
+impl AsRef<[u8]> for Wkb {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromCell for Wkb {
+    type Plan = ();
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        match GeoPlan::for_column::<Self>(ctx)? {
+            GeoPlan::Wkb | GeoPlan::Detect => Ok(()),
+            _ => Err(incompatible_column::<Self>(
+                column,
+                Some("set GEOGRAPHY_OUTPUT_FORMAT or GEOMETRY_OUTPUT_FORMAT to 'WKB' for WKB payloads".into()),
+            )),
+        }
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, _plan: &Self::Plan) -> CellDecodeResult<Self> {
+        decode_hex(required_raw(raw)?).map(Wkb).map_err(|m| {
+            CellConversionError::builder(format!("payload is not hex WKB: {m}")).build()
+        })
+    }
+}
+
+fn parse_wkt(raw: &str) -> CellDecodeResult<Wkt<f64>> {
+    // EWKT prefixes the geometry with `SRID=<n>;`.
+    let wkt = match raw.split_once(';') {
+        Some((srid, wkt)) if srid.trim_start().to_ascii_uppercase().starts_with("SRID=") => wkt,
+        _ => raw,
+    };
+    Wkt::from_str(wkt)
+        .map_err(|m| CellConversionError::builder(format!("invalid WKT: {m}")).build())
+}
+
+fn geojson_error(e: geojson::Error) -> CellConversionError {
+    CellConversionError::builder(format!("invalid GeoJSON geometry: {e}"))
+        .source(e)
+        .build()
+}
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+fn read_wkb(bytes: &[u8]) -> StdResult<Geometry<f64>, String> {
+    let mut reader = WkbReader { bytes, pos: 0 };
+    let geometry = reader.geometry()?;
+    if reader.pos != bytes.len() {
+        return Err(format!(
+            "{} trailing bytes after WKB geometry",
+            bytes.len() - reader.pos
+        ));
+    }
+    Ok(geometry)
+}
+
+/// Reader for OGC WKB, including ISO Z/M type codes and PostGIS-style EWKB flags.
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+/// Per-geometry header state: byte order and ordinates per coordinate.
+#[derive(Clone, Copy)]
+struct WkbHeader {
+    little_endian: bool,
+    dimensions: usize,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> StdResult<[u8; N], String> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos + N)
+            .ok_or("WKB ends mid-geometry")?;
+        self.pos += N;
+        Ok(bytes.try_into().expect("slice has length N"))
+    }
+
+    fn u32(&mut self, header: WkbHeader) -> StdResult<u32, String> {
+        let bytes = self.take::<4>()?;
+        Ok(if header.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self, header: WkbHeader) -> StdResult<f64, String> {
+        let bytes = self.take::<8>()?;
+        Ok(if header.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    /// Read an element count, bounded by the bytes left so corrupt input cannot request a huge allocation.
+    fn count(&mut self, header: WkbHeader, min_element_bytes: usize) -> StdResult<usize, String> {
+        let count = self.u32(header)? as usize;
+        let remaining = self.bytes.len() - self.pos;
+        if count.saturating_mul(min_element_bytes) > remaining {
+            return Err(format!(
+                "WKB count {count} exceeds the remaining {remaining} bytes"
+            ));
+        }
+        Ok(count)
+    }
+
+    fn coord(&mut self, header: WkbHeader) -> StdResult<Coord<f64>, String> {
+        let x = self.f64(header)?;
+        let y = self.f64(header)?;
+        for _ in 2..header.dimensions {
+            self.f64(header)?;
+        }
+        Ok(Coord { x, y })
+    }
+
+    fn line_string(&mut self, header: WkbHeader) -> StdResult<LineString<f64>, String> {
+        let count = self.count(header, 8 * header.dimensions)?;
+        (0..count)
+            .map(|_| self.coord(header))
+            .collect::<StdResult<Vec<_>, _>>()
+            .map(LineString::new)
+    }
+
+    fn polygon(&mut self, header: WkbHeader) -> StdResult<Polygon<f64>, String> {
+        let count = self.count(header, 4)?;
+        let mut rings = (0..count)
+            .map(|_| self.line_string(header))
+            .collect::<StdResult<Vec<_>, _>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(Vec::new()));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    fn header(&mut self) -> StdResult<(WkbHeader, u32), String> {
+        let little_endian = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            [b] => return Err(format!("invalid WKB byte order {b}")),
+        };
+        let mut header = WkbHeader {
+            little_endian,
+            dimensions: 2,
+        };
+        let code = self.u32(header)?;
+        let iso = code & 0x0FFF_FFFF;
+        header.dimensions += match iso / 1000 {
+            0 => 0,
+            1 | 2 => 1,
+            3 => 2,
+            _ => return Err(format!("unsupported WKB geometry type {code:#x}")),
+        };
+        header.dimensions += usize::from(code & EWKB_Z != 0) + usize::from(code & EWKB_M != 0);
+        if code & EWKB_SRID != 0 {
+            self.u32(header)?;
+        }
+        Ok((header, iso % 1000))
+    }
+
+    /// Read a nested member of a multi-geometry or collection.
+    fn member<T>(
+        &mut self,
+        expected: &'static str,
+        extract: impl Fn(Geometry<f64>) -> Option<T>,
+    ) -> StdResult<T, String> {
+        extract(self.geometry()?)
+            .ok_or_else(|| format!("WKB collection member is not a {expected}"))
+    }
+
+    fn geometry(&mut self) -> StdResult<Geometry<f64>, String> {
+        let (header, kind) = self.header()?;
+        // Each nested member carries at least a byte-order byte and a type code.
+        const MEMBER_BYTES: usize = 5;
+        Ok(match kind {
+            1 => Geometry::Point(Point(self.coord(header)?)),
+            2 => Geometry::LineString(self.line_string(header)?),
+            3 => Geometry::Polygon(self.polygon(header)?),
+            4 => {
+                let count = self.count(header, MEMBER_BYTES)?;
+                let points = (0..count)
+                    .map(|_| {
+                        self.member("POINT", |g| match g {
+                            Geometry::Point(p) => Some(p),
+                            _ => None,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>, _>>()?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            5 => {
+                let count = self.count(header, MEMBER_BYTES)?;
+                let lines = (0..count)
+                    .map(|_| {
+                        self.member("LINESTRING", |g| match g {
+                            Geometry::LineString(l) => Some(l),
+                            _ => None,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>, _>>()?;
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            6 => {
+                let count = self.count(header, MEMBER_BYTES)?;
+                let polygons = (0..count)
+                    .map(|_| {
+                        self.member("POLYGON", |g| match g {
+                            Geometry::Polygon(p) => Some(p),
+                            _ => None,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            7 => {
+                let count = self.count(header, MEMBER_BYTES)?;
+                let members = (0..count)
+                    .map(|_| self.geometry())
+                    .collect::<StdResult<Vec<_>, _>>()?;
+                Geometry::GeometryCollection(GeometryCollection(members))
+            }
+            _ => return Err(format!("unsupported WKB geometry type {kind}")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geo_types::{line_string, point, polygon};
+
+    use super::*;
+    use crate::result_table::test_data::one_cell_table;
+
+    // POINT(1 2) as little-endian WKB.
+    const POINT_WKB: &str = "0101000000000000000000F03F0000000000000040";
+
+    fn decode<T: FromCell>(ty: ColumnType, value: &str) -> crate::Result<T> {
+        one_cell_table(ty, value)
+            .rows::<(T,)>()?
+            .next()
+            .unwrap()
+            .map(|(g,)| g)
+    }
+
+    #[test]
+    fn geometry_detects_each_output_format() {
+        let expected = Geometry::Point(point!(x: 1.0, y: 2.0));
+        for value in [
+            r#"{"coordinates": [1, 2], "type": "Point"}"#,
+            "POINT(1 2)",
+            "SRID=4326;POINT(1 2)",
+            POINT_WKB,
+        ] {
+            for ty in [ColumnType::Geography, ColumnType::Geometry] {
+                assert_eq!(decode::<Geometry<f64>>(ty, value).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn geometry_follows_reported_column_type() {
+        let expected = Geometry::Point(point!(x: 1.0, y: 2.0));
+        assert_eq!(
+            decode::<Geometry<f64>>(
+                ColumnType::Object { fields: None },
+                r#"{"coordinates": [1, 2], "type": "Point"}"#
+            )
+            .unwrap(),
+            expected
+        );
+        assert_eq!(
+            decode::<Geometry<f64>>(ColumnType::Text { length: None }, "POINT(1 2)").unwrap(),
+            expected
+        );
+        assert_eq!(
+            decode::<Geometry<f64>>(ColumnType::Binary { length: None }, POINT_WKB).unwrap(),
+            expected
+        );
+        assert!(decode::<Geometry<f64>>(ColumnType::Boolean, "true").is_err());
+    }
+
+    #[test]
+    fn wkb_reads_ewkb_and_nested_geometries() {
+        // EWKB POINT Z with SRID 4326; the SRID and Z ordinate are dropped.
+        let ewkb = "01010000A0E6100000000000000000F03F00000000000000400000000000000840";
+        assert_eq!(
+            read_wkb(&decode_hex(ewkb).unwrap()).unwrap(),
+            Geometry::Point(point!(x: 1.0, y: 2.0))
+        );
+
+        let polygon: Geometry<f64> =
+            polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 0.0)].into();
+        let collection = Geometry::GeometryCollection(GeometryCollection(vec![
+            polygon,
+            line_string![(x: 0.0, y: 0.0), (x: 2.0, y: 2.0)].into(),
+        ]));
+        let wkt = collection.wkt_string();
+        let from_wkt = decode::<Geometry<f64>>(ColumnType::Geography, &wkt).unwrap();
+        assert_eq!(from_wkt, collection);
+
+        // Big-endian MULTIPOINT holding a little-endian POINT(1 2).
+        let multi = format!("000000000400000001{POINT_WKB}");
+        assert_eq!(
+            read_wkb(&decode_hex(&multi).unwrap()).unwrap(),
+            Geometry::MultiPoint(MultiPoint(vec![point!(x: 1.0, y: 2.0)]))
+        );
+
+        assert!(read_wkb(&decode_hex("0101000000000000000000F03F").unwrap()).is_err());
+        let huge_count = "0102000000FFFFFFFF";
+        let err = read_wkb(&decode_hex(huge_count).unwrap()).unwrap_err();
+        assert!(err.contains("exceeds the remaining"), "{err}");
+    }
+
+    #[test]
+    fn wkt_keeps_text_and_converts_other_formats() {
+        let wkt =
+            decode::<Wkt<f64>>(ColumnType::Geography, "SRID=4326;LINESTRING(0 0, 1 1)").unwrap();
+        assert_eq!(wkt.to_string(), "LINESTRING(0 0,1 1)");
+
+        let wkt = decode::<Wkt<f64>>(ColumnType::Geography, POINT_WKB).unwrap();
+        assert_eq!(wkt.to_string(), "POINT(1 2)");
+    }
+
+    #[test]
+    fn wkb_requires_wkb_payloads() {
+        let wkb = decode::<Wkb>(ColumnType::Binary { length: None }, POINT_WKB).unwrap();
+        assert_eq!(wkb.as_bytes().len(), 21);
+        assert_eq!(
+            wkb.to_geometry().unwrap(),
+            Geometry::Point(point!(x: 1.0, y: 2.0))
+        );
+
+        let err = decode::<Wkb>(ColumnType::Text { length: None }, "POINT(1 2)").unwrap_err();
+        assert!(err.to_string().contains("GEOGRAPHY_OUTPUT_FORMAT"), "{err}");
+        assert!(decode::<Wkb>(ColumnType::Geography, "POINT(1 2)").is_err());
+    }
+}
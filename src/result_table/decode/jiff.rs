@@ -0,0 +1,189 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use jiff::{
+    Timestamp, Zoned,
+    civil::{self, Date, DateTime as CivilDateTime},
+    tz::{Offset, TimeZone},
+};
+
+use super::{
+    FromCell, TimePlan, TimestampPlan, UtcTimestampPlan, date_plan, out_of_range,
+    parse_timestamp_with_offset, required_raw, time_plan, timestamp_ntz_plan, utc_timestamp_plan,
+};
+use crate::{
+    decode::PlanBuildResult,
+    result_table::{CellDecodeResult, plan::CellPlanContext},
+};
+
+fn to_date(date: NaiveDate) -> Result<Date, jiff::Error> {
+    // Out-of-range years fail in `Date::new`, so the narrowing casts cannot alias a valid date.
+    let year = i16::try_from(date.year()).unwrap_or(i16::MAX);
+    Date::new(year, date.month() as i8, date.day() as i8)
+}
+
+fn to_time(time: NaiveTime) -> Result<civil::Time, jiff::Error> {
+    civil::Time::new(
+        time.hour() as i8,
+        time.minute() as i8,
+        time.second() as i8,
+        time.nanosecond() as i32,
+    )
+}
+
+fn to_timestamp(datetime: DateTime<Utc>) -> Result<Timestamp, jiff::Error> {
+    Timestamp::new(
+        datetime.timestamp(),
+        datetime.timestamp_subsec_nanos() as i32,
+    )
+}
+
+fn to_zoned(datetime: DateTime<FixedOffset>) -> Result<Zoned, jiff::Error> {
+    let offset = Offset::from_seconds(datetime.offset().local_minus_utc())?;
+    Ok(to_timestamp(datetime.to_utc())?.to_zoned(TimeZone::fixed(offset)))
+}
+
+impl FromCell for Date {
+    type Plan = ();
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        date_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let date = NaiveDate::from_cell_with_plan(raw, plan)?;
+        to_date(date).map_err(|e| out_of_range::<Self>(raw).source(e).build())
+    }
+}
+
+impl FromCell for civil::Time {
+    type Plan = TimePlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        time_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let time = NaiveTime::from_cell_with_plan(raw, plan)?;
+        to_time(time).map_err(|e| out_of_range::<Self>(raw).source(e).build())
+    }
+}
+
+impl FromCell for CivilDateTime {
+    type Plan = TimestampPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        timestamp_ntz_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let datetime = NaiveDateTime::from_cell_with_plan(raw, plan)?;
+        to_date(datetime.date())
+            .and_then(|date| Ok(date.to_datetime(to_time(datetime.time())?)))
+            .map_err(|e| out_of_range::<Self>(raw).source(e).build())
+    }
+}
+
+/// Decodes `TIMESTAMP_LTZ` and `TIMESTAMP_TZ` columns as the instant they denote.
+impl FromCell for Timestamp {
+    type Plan = UtcTimestampPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        utc_timestamp_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let datetime = DateTime::<Utc>::from_cell_with_plan(raw, plan)?;
+        to_timestamp(datetime).map_err(|e| out_of_range::<Self>(raw).source(e).build())
+    }
+}
+
+/// `TIMESTAMP_TZ` cells decode in a fixed-offset zone at their own offset; `TIMESTAMP_LTZ` cells decode in UTC.
+impl FromCell for Zoned {
+    type Plan = UtcTimestampPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        utc_timestamp_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let datetime = parse_timestamp_with_offset(required_raw(raw)?, plan)?;
+        to_zoned(datetime).map_err(|e| out_of_range::<Self>(raw).source(e).build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::{date, time};
+
+    use super::*;
+    use crate::result_table::{schema::ColumnType, test_data::one_cell_table};
+
+    fn decode<T: FromCell>(ty: ColumnType, value: &str) -> T {
+        one_cell_table(ty, value)
+            .rows::<(T,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn decodes_civil_values() {
+        assert_eq!(decode::<Date>(ColumnType::Date, "19889"), date(2024, 6, 15));
+        assert_eq!(
+            decode::<civil::Time>(ColumnType::Time { scale: Some(3) }, "45045.123"),
+            time(12, 30, 45, 123_000_000)
+        );
+        assert_eq!(
+            decode::<CivilDateTime>(
+                ColumnType::TimestampNtz { scale: Some(9) },
+                "1718454645.000000001"
+            ),
+            date(2024, 6, 15).at(12, 30, 45, 1)
+        );
+    }
+
+    #[test]
+    fn decodes_instants_and_zoned_values() {
+        let expected = Timestamp::new(1_718_454_645, 0).unwrap();
+        assert_eq!(
+            decode::<Timestamp>(ColumnType::TimestampLtz { scale: Some(0) }, "1718454645"),
+            expected
+        );
+        assert_eq!(
+            decode::<Timestamp>(
+                ColumnType::TimestampTz { scale: Some(0) },
+                "1718454645 1980"
+            ),
+            expected
+        );
+
+        let zoned = decode::<Zoned>(
+            ColumnType::TimestampTz { scale: Some(0) },
+            "1718454645 1980",
+        );
+        assert_eq!(zoned.timestamp(), expected);
+        assert_eq!(zoned.offset(), Offset::constant(9));
+        assert_eq!(zoned.datetime(), date(2024, 6, 15).at(21, 30, 45, 0));
+
+        let zoned = decode::<Zoned>(ColumnType::TimestampLtz { scale: Some(0) }, "1718454645");
+        assert_eq!(zoned.offset(), Offset::UTC);
+    }
+
+    #[test]
+    fn rejects_wrong_columns_and_out_of_range_dates() {
+        let table = one_cell_table(ColumnType::Date, "0");
+        assert!(table.rows::<(Timestamp,)>().is_err());
+        assert!(table.rows::<(CivilDateTime,)>().is_err());
+
+        // Year 10000 is past jiff's range.
+        let table = one_cell_table(ColumnType::Date, "2932897");
+        let err = table
+            .rows::<(Date,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+    }
+}
@@ -0,0 +1,596 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+    sync::Arc,
+};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use serde_json::value::RawValue;
+
+use super::{FromCell, FromRow, incompatible_column, required_raw};
+use crate::{
+    decode::{PlanBuildResult, RowDecodeError},
+    result_table::{
+        CellConversionError, CellDecodeResult,
+        plan::{CellPlanContext, RowPlanContext},
+        row::RowRef,
+        schema::{Column, ColumnType, Schema},
+    },
+};
+
+/// How a structured element's JSON text maps onto the scalar wire text its own `FromCell` plan reads.
+///
+/// Snowflake renders temporal values inside structured types as text in the session's output formats, while the scalar
+/// plans read the epoch-based wire form used for top-level columns. Temporal elements in the default ISO-style formats
+/// are rewritten into that wire form, and temporal text in any other format is rejected; everything else is handed to
+/// the element plan unchanged.
+#[derive(Clone, Copy, Debug)]
+enum ElementText {
+    Verbatim,
+    Date,
+    Time { scale: u8 },
+    TimestampNtz { scale: u8 },
+    TimestampLtz { scale: u8 },
+    TimestampTz { scale: u8 },
+}
+
+impl ElementText {
+    fn for_type(ty: &ColumnType) -> Self {
+        // Out-of-range scales are left for the element plan to reject.
+        let scale = |scale: &Option<u8>| scale.unwrap_or(9).min(9);
+        match ty {
+            ColumnType::Date => Self::Date,
+            ColumnType::Time { scale: s } => Self::Time { scale: scale(s) },
+            ColumnType::TimestampNtz { scale: s } => Self::TimestampNtz { scale: scale(s) },
+            ColumnType::TimestampLtz { scale: s } => Self::TimestampLtz { scale: scale(s) },
+            ColumnType::TimestampTz { scale: s } => Self::TimestampTz { scale: scale(s) },
+            _ => Self::Verbatim,
+        }
+    }
+
+    /// The raw cell text for one element: `None` for JSON `null`, the contents of a JSON string, or the JSON text of
+    /// any other value.
+    fn wire<'a>(self, value: &'a RawValue) -> CellDecodeResult<Option<Cow<'a, str>>> {
+        let json = value.get();
+        let text = match json.as_bytes().first() {
+            Some(b'n') => return Ok(None),
+            Some(b'"') => match serde_json::from_str::<&str>(json) {
+                Ok(text) => Cow::Borrowed(text),
+                Err(_) => {
+                    Cow::Owned(serde_json::from_str::<String>(json).map_err(invalid_payload)?)
+                }
+            },
+            _ => return Ok(Some(Cow::Borrowed(json))),
+        };
+        let rewritten = match self {
+            Self::Verbatim => return Ok(Some(text)),
+            Self::Date => NaiveDate::parse_from_str(&text, "%Y-%m-%d")
+                .ok()
+                .map(|date| {
+                    (date - DateTime::UNIX_EPOCH.date_naive())
+                        .num_days()
+                        .to_string()
+                }),
+            Self::Time { scale } => parse_iso_time(&text).map(|time| {
+                epoch_text(
+                    i64::from(time.num_seconds_from_midnight()),
+                    time.nanosecond(),
+                    scale,
+                )
+            }),
+            Self::TimestampNtz { scale } => {
+                parse_iso_datetime(&text).map(|dt| utc_epoch_text(dt.and_utc(), scale))
+            }
+            Self::TimestampLtz { scale } => {
+                parse_iso_offset_datetime(&text).map(|dt| utc_epoch_text(dt.to_utc(), scale))
+            }
+            Self::TimestampTz { scale } => parse_iso_offset_datetime(&text)
+                .filter(|dt| dt.offset().local_minus_utc() % 60 == 0)
+                .map(|dt| {
+                    let tz_index = 1440 + dt.offset().local_minus_utc() / 60;
+                    format!("{} {tz_index}", utc_epoch_text(dt.to_utc(), scale))
+                }),
+        };
+        match rewritten {
+            Some(wire) => Ok(Some(Cow::Owned(wire))),
+            None => Err(CellConversionError::builder(format!(
+                "'{text}' is not a {} in the default output format",
+                self.type_name()
+            ))
+            .build()),
+        }
+    }
+
+    fn type_name(self) -> &'static str {
+        match self {
+            Self::Verbatim => "value",
+            Self::Date => "DATE",
+            Self::Time { .. } => "TIME",
+            Self::TimestampNtz { .. } => "TIMESTAMP_NTZ",
+            Self::TimestampLtz { .. } => "TIMESTAMP_LTZ",
+            Self::TimestampTz { .. } => "TIMESTAMP_TZ",
+        }
+    }
+}
+
+fn parse_iso_time(text: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(text, "%H:%M:%S%.f").ok()
+}
+
+fn parse_iso_datetime(text: &str) -> Option<NaiveDateTime> {
+    ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .into_iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+}
+
+fn parse_iso_offset_datetime(text: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(text).ok().or_else(|| {
+        ["%Y-%m-%d %H:%M:%S%.f %z", "%Y-%m-%d %H:%M:%S%.f%z"]
+            .into_iter()
+            .find_map(|format| DateTime::parse_from_str(text, format).ok())
+    })
+}
+
+fn utc_epoch_text(dt: DateTime<Utc>, scale: u8) -> String {
+    epoch_text(dt.timestamp(), dt.timestamp_subsec_nanos(), scale)
+}
+
+/// Format `secs + nanos / 10^9` as a decimal with exactly `scale` fractional digits, the wire form of `TIME` and
+/// epoch-based timestamps.
+fn epoch_text(secs: i64, nanos: u32, scale: u8) -> String {
+    let total = i128::from(secs) * 1_000_000_000 + i128::from(nanos);
+    let sign = if total < 0 { "-" } else { "" };
+    let whole = total.unsigned_abs() / 1_000_000_000;
+    if scale == 0 {
+        return format!("{sign}{whole}");
+    }
+    let fraction = total.unsigned_abs() % 1_000_000_000 / 10u128.pow(9 - u32::from(scale));
+    format!(
+        "{sign}{whole}.{fraction:0width$}",
+        width = usize::from(scale)
+    )
+}
+
+fn invalid_payload(e: serde_json::Error) -> CellConversionError {
+    CellConversionError::builder(format!("invalid structured payload: {e}"))
+        .source(e)
+        .build()
+}
+
+/// Prefix a nested failure with the element, key, or field it came from.
+fn nested_error(location: impl std::fmt::Display, e: CellConversionError) -> CellConversionError {
+    CellConversionError::builder(format!("{location}: {}", e.reason()))
+        .source(e)
+        .build()
+}
+
+/// A column standing in for a structured column's element, key, or value while its plan is built.
+fn nested_column(parent: &Column, suffix: &str, ty: &ColumnType) -> Column {
+    Column::new(
+        format!("{}{suffix}", parent.name()),
+        parent.index(),
+        true,
+        ty.clone(),
+    )
+}
+
+/// Plan for a structured `ARRAY(T)` column decoded as `Vec<T>`.
+pub struct ArrayPlan<T: FromCell> {
+    element: T::Plan,
+    text: ElementText,
+}
+
+/// Accepts structured `ARRAY(T)` columns whose element type `T` decodes from, checked with `T`'s own plan.
+///
+/// Temporal elements must be rendered in the default `DATE_OUTPUT_FORMAT`, `TIME_OUTPUT_FORMAT`, and
+/// `TIMESTAMP_*_OUTPUT_FORMAT` session formats; elements in any other format fail to decode. The same holds for
+/// temporal `MAP` values and [`Object`] fields.
+///
+/// Semi-structured `ARRAY` columns report no element type; decode them as [`Json<Vec<T>>`](crate::decode::Json).
+impl<T: FromCell> FromCell for Vec<T> {
+    type Plan = ArrayPlan<T>;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        let ColumnType::Array {
+            element: Some(element),
+        } = column.ty()
+        else {
+            let detail = matches!(column.ty(), ColumnType::Array { element: None }).then(|| {
+                "semi-structured ARRAY has no element type; decode it as Json<Vec<T>>".into()
+            });
+            return Err(incompatible_column::<Self>(column, detail));
+        };
+        let element_column = nested_column(column, "[]", element);
+        Ok(ArrayPlan {
+            element: T::build_plan(ctx.nested(&element_column))?,
+            text: ElementText::for_type(element),
+        })
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let elements: Vec<&RawValue> =
+            serde_json::from_str(required_raw(raw)?).map_err(invalid_payload)?;
+        elements
+            .into_iter()
+            .enumerate()
+            .map(|(i, element)| {
+                plan.text
+                    .wire(element)
+                    .and_then(|text| T::from_cell_with_plan(text.as_deref(), &plan.element))
+                    .map_err(|e| nested_error(format_args!("element {i}"), e))
+            })
+            .collect()
+    }
+}
+
+/// Plan for a structured `MAP(K, V)` column decoded as `HashMap<K, V>`.
+pub struct MapPlan<K: FromCell, V: FromCell> {
+    key: K::Plan,
+    value: V::Plan,
+    value_text: ElementText,
+}
+
+/// Accepts structured `MAP(K, V)` columns whose key and value types decode into `K` and `V`.
+///
+/// Snowflake map keys are `VARCHAR` or `NUMBER`; numeric keys arrive as their decimal text and decode through `K`'s
+/// plan like any other cell.
+impl<K, V, S> FromCell for HashMap<K, V, S>
+where
+    K: FromCell + Eq + Hash,
+    V: FromCell,
+    S: BuildHasher + Default,
+{
+    type Plan = MapPlan<K, V>;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        let ColumnType::Map { key, value } = column.ty() else {
+            return Err(incompatible_column::<Self>(column, None));
+        };
+        let key_column = nested_column(column, "[key]", key);
+        let value_column = nested_column(column, "[value]", value);
+        Ok(MapPlan {
+            key: K::build_plan(ctx.nested(&key_column))?,
+            value: V::build_plan(ctx.nested(&value_column))?,
+            value_text: ElementText::for_type(value),
+        })
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let entries: HashMap<String, &RawValue> =
+            serde_json::from_str(required_raw(raw)?).map_err(invalid_payload)?;
+        entries
+            .into_iter()
+            .map(|(key, value)| {
+                let decoded_key = K::from_cell_with_plan(Some(&key), &plan.key)
+                    .map_err(|e| nested_error(format_args!("key {key:?}"), e))?;
+                let decoded_value = plan
+                    .value_text
+                    .wire(value)
+                    .and_then(|text| V::from_cell_with_plan(text.as_deref(), &plan.value))
+                    .map_err(|e| nested_error(format_args!("value for key {key:?}"), e))?;
+                Ok((decoded_key, decoded_value))
+            })
+            .collect()
+    }
+}
+
+/// Decode wrapper for a structured `OBJECT(...)` cell, read through `T`'s [`FromRow`] plan.
+///
+/// The object's declared fields act as the columns of a one-row result, so a `#[derive(FromRow)]` struct decodes a
+/// structured object the same way it decodes a row, with every field type checked when the plan is built. Fields
+/// absent from a value read as SQL `NULL`. Column names resolve with the enclosing plan's
+/// [`ColumnLookup`](crate::ColumnLookup) mode.
+///
+/// Semi-structured `OBJECT` columns report no fields; decode them as [`Json<T>`](crate::decode::Json).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Object<T>(T);
+
+impl<T> Object<T> {
+    /// Consume the wrapper, returning the decoded value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Object<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Plan for a structured `OBJECT(...)` column decoded as [`Object<T>`].
+pub struct ObjectPlan<T: FromRow> {
+    schema: Arc<Schema>,
+    fields: Box<[ElementText]>,
+    row: T::Plan,
+}
+
+impl<T: FromRow> FromCell for Object<T> {
+    type Plan = ObjectPlan<T>;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        let ColumnType::Object {
+            fields: Some(fields),
+        } = column.ty()
+        else {
+            let detail = matches!(column.ty(), ColumnType::Object { fields: None })
+                .then(|| "semi-structured OBJECT has no fields; decode it as Json<T>".into());
+            return Err(incompatible_column::<Self>(column, detail));
+        };
+        let schema = Arc::new(
+            Schema::from_columns(fields.to_vec())
+                .with_session_timezone(ctx.schema().session_timezone().map(Arc::from)),
+        );
+        let row_ctx =
+            RowPlanContext::new(&schema).with_column_lookup(ctx.row_context().column_lookup());
+        let row = T::build_plan(row_ctx)?;
+        Ok(ObjectPlan {
+            fields: fields
+                .iter()
+                .map(|field| ElementText::for_type(field.ty()))
+                .collect(),
+            row,
+            schema,
+        })
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let mut values: HashMap<String, &RawValue> =
+            serde_json::from_str(required_raw(raw)?).map_err(invalid_payload)?;
+        let texts = plan
+            .schema
+            .columns()
+            .iter()
+            .zip(&plan.fields)
+            .map(|(field, text)| match values.remove(field.name()) {
+                Some(value) => text
+                    .wire(value)
+                    .map_err(|e| nested_error(format_args!("field {}", field.name()), e)),
+                None => Ok(None),
+            })
+            .collect::<CellDecodeResult<Vec<_>>>()?;
+        let texts: Vec<Option<&str>> = texts.iter().map(Option::as_deref).collect();
+        let row = RowRef::from_texts(&plan.schema, &texts);
+
+        T::from_row_with_plan(row, &plan.row)
+            .map(Object)
+            .map_err(|e| match e {
+                RowDecodeError::Cell(e) => CellConversionError::builder(format!(
+                    "field {}: {}",
+                    e.column_name(),
+                    e.conversion_error().reason()
+                ))
+                .source(e)
+                .build(),
+                e => CellConversionError::builder(e.to_string())
+                    .source(e)
+                    .build(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::result_table::test_data::one_cell_table;
+
+    fn decode<T: FromCell>(ty: ColumnType, value: &str) -> crate::Result<T> {
+        one_cell_table(ty, value)
+            .rows::<(T,)>()?
+            .next()
+            .unwrap()
+            .map(|(value,)| value)
+    }
+
+    fn array_of(element: ColumnType) -> ColumnType {
+        ColumnType::Array {
+            element: Some(Box::new(element)),
+        }
+    }
+
+    fn number() -> ColumnType {
+        ColumnType::Fixed {
+            precision: Some(38),
+            scale: Some(0),
+        }
+    }
+
+    fn text() -> ColumnType {
+        ColumnType::Text { length: None }
+    }
+
+    fn field(name: &str, index: usize, ty: ColumnType) -> Column {
+        Column::new(name, index, true, ty)
+    }
+
+    #[test]
+    fn array_elements_decode_through_element_plans() {
+        let dates: Vec<NaiveDate> =
+            decode(array_of(ColumnType::Date), r#"["2024-01-15","1969-12-31"]"#).unwrap();
+        assert_eq!(
+            dates,
+            [
+                NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+            ]
+        );
+
+        let numbers: Vec<Option<i64>> = decode(array_of(number()), "[1, null, -3]").unwrap();
+        assert_eq!(numbers, [Some(1), None, Some(-3)]);
+
+        let nested: Vec<Vec<String>> =
+            decode(array_of(array_of(text())), r#"[["a","b \"quoted\""],[]]"#).unwrap();
+        assert_eq!(nested, [vec!["a", "b \"quoted\""], vec![]]);
+    }
+
+    #[test]
+    fn array_timestamps_decode_from_rendered_text() {
+        let ntz: Vec<NaiveDateTime> = decode(
+            array_of(ColumnType::TimestampNtz { scale: Some(3) }),
+            r#"["2024-01-15 12:30:45.500"]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ntz[0],
+            NaiveDate::from_ymd_opt(2024, 1, 15)
+                .unwrap()
+                .and_hms_milli_opt(12, 30, 45, 500)
+                .unwrap()
+        );
+
+        let tz: Vec<DateTime<FixedOffset>> = decode(
+            array_of(ColumnType::TimestampTz { scale: Some(9) }),
+            r#"["2024-01-15 21:00:00.000 +0900"]"#,
+        )
+        .unwrap();
+        assert_eq!(tz[0].to_rfc3339(), "2024-01-15T21:00:00+09:00");
+
+        let times: Vec<NaiveTime> = decode(
+            array_of(ColumnType::Time { scale: Some(2) }),
+            r#"["23:59:59.25"]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            times[0],
+            NaiveTime::from_hms_milli_opt(23, 59, 59, 250).unwrap()
+        );
+    }
+
+    #[test]
+    fn epoch_text_matches_wire_form() {
+        assert_eq!(epoch_text(-2, 500_000_000, 3), "-1.500");
+        assert_eq!(epoch_text(0, 123_456_789, 9), "0.123456789");
+        assert_eq!(epoch_text(1_705_320_000, 999_999_999, 0), "1705320000");
+    }
+
+    #[test]
+    fn array_element_failures_name_the_element() {
+        let err = decode::<Vec<i64>>(array_of(number()), "[1, null]").unwrap_err();
+        assert!(
+            err.to_string().contains("element 1: value is NULL"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn array_plan_checks_element_type() {
+        let err = decode::<Vec<i64>>(array_of(text()), "[]").unwrap_err();
+        assert!(err.to_string().contains("X[]"), "{err}");
+
+        let err = decode::<Vec<i64>>(ColumnType::Array { element: None }, "[]").unwrap_err();
+        assert!(err.to_string().contains("Json<Vec<T>>"), "{err}");
+    }
+
+    #[test]
+    fn map_decodes_keys_and_values() {
+        let ty = ColumnType::Map {
+            key: Box::new(number()),
+            value: Box::new(ColumnType::Date),
+        };
+        let map: HashMap<i32, Option<NaiveDate>> =
+            decode(ty.clone(), r#"{"1":"2024-01-15","2":null}"#).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&1], NaiveDate::from_ymd_opt(2024, 1, 15));
+        assert_eq!(map[&2], None);
+
+        let err = decode::<HashMap<i32, Option<NaiveDate>>>(ty, r#"{"x":null}"#).unwrap_err();
+        assert!(err.to_string().contains(r#"key "x""#), "{err}");
+
+        let err = decode::<HashMap<String, String>>(array_of(text()), "[]").unwrap_err();
+        assert!(err.to_string().contains("HashMap"), "{err}");
+    }
+
+    struct Address {
+        city: String,
+        since: Option<NaiveDate>,
+    }
+
+    impl FromRow for Address {
+        type Plan = (crate::CellPlan<String>, crate::CellPlan<Option<NaiveDate>>);
+
+        fn build_plan(ctx: RowPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+            Ok((
+                crate::CellPlan::by_name(ctx, "CITY")?,
+                crate::CellPlan::by_name(ctx, "SINCE")?,
+            ))
+        }
+
+        fn from_row_with_plan(
+            row: crate::RowRef<'_>,
+            plan: &Self::Plan,
+        ) -> crate::decode::RowDecodeResult<Self> {
+            Ok(Self {
+                city: row.get_with_plan(&plan.0)?,
+                since: row.get_with_plan(&plan.1)?,
+            })
+        }
+    }
+
+    fn address_type() -> ColumnType {
+        ColumnType::Object {
+            fields: Some(Box::new([
+                field("CITY", 0, text()),
+                field("SINCE", 1, ColumnType::Date),
+            ])),
+        }
+    }
+
+    #[test]
+    fn object_decodes_through_from_row() {
+        let addresses: Vec<Object<Address>> = decode(
+            array_of(address_type()),
+            r#"[{"CITY":"Tokyo","SINCE":"2024-01-15"},{"CITY":"Oslo"}]"#,
+        )
+        .unwrap();
+        let [tokyo, oslo] = &addresses[..] else {
+            panic!("expected two addresses");
+        };
+        assert_eq!(tokyo.as_ref().city, "Tokyo");
+        assert_eq!(tokyo.as_ref().since, NaiveDate::from_ymd_opt(2024, 1, 15));
+        assert_eq!(oslo.as_ref().city, "Oslo");
+        assert_eq!(oslo.as_ref().since, None);
+    }
+
+    #[test]
+    fn object_failures_name_the_field_and_semi_structured_objects_are_rejected() {
+        let err = decode::<Object<Address>>(address_type(), r#"{"SINCE":"2024-01-15"}"#)
+            .err()
+            .expect("CITY is missing");
+        assert!(
+            err.to_string().contains("field CITY: value is NULL"),
+            "{err}"
+        );
+
+        let err = decode::<Object<Address>>(ColumnType::Object { fields: None }, "{}")
+            .err()
+            .expect("semi-structured OBJECT must be rejected");
+        assert!(err.to_string().contains("decode it as Json<T>"), "{err}");
+    }
+
+    #[test]
+    fn temporal_elements_outside_the_default_output_formats_are_rejected() {
+        let err =
+            decode::<Vec<NaiveDate>>(array_of(ColumnType::Date), r#"["15-Jan-2024"]"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("element 0: '15-Jan-2024' is not a DATE in the default output format"),
+            "{err}"
+        );
+
+        let err =
+            decode::<Object<Address>>(address_type(), r#"{"CITY":"Oslo","SINCE":"01/15/2024"}"#)
+                .err()
+                .expect("SINCE is not in the default DATE format");
+        assert!(
+            err.to_string()
+                .contains("field SINCE: '01/15/2024' is not a DATE"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use super::{
+    FromCell, TimePlan, TimestampPlan, UtcTimestampPlan, date_plan, out_of_range,
+    parse_timestamp_with_offset, required_raw, time_plan, timestamp_ntz_plan, utc_timestamp_plan,
+};
+use crate::{
+    decode::PlanBuildResult,
+    result_table::{CellDecodeResult, plan::CellPlanContext},
+};
+
+/// Julian day number of 0000-12-31, chrono's `num_days_from_ce` day 0, so that 0001-01-01 (day 1) maps to 1_721_426.
+const JULIAN_DAY_OF_CE: i32 = 1_721_425;
+
+fn to_date(date: NaiveDate) -> Option<Date> {
+    Date::from_julian_day(date.num_days_from_ce() + JULIAN_DAY_OF_CE).ok()
+}
+
+fn to_time(time: NaiveTime) -> Option<Time> {
+    // Cells never carry leap seconds, so every decoded `NaiveTime` fits.
+    Time::from_hms_nano(
+        time.hour() as u8,
+        time.minute() as u8,
+        time.second() as u8,
+        time.nanosecond(),
+    )
+    .ok()
+}
+
+fn to_primitive(datetime: NaiveDateTime) -> Option<PrimitiveDateTime> {
+    Some(PrimitiveDateTime::new(
+        to_date(datetime.date())?,
+        to_time(datetime.time())?,
+    ))
+}
+
+fn to_offset_date_time(datetime: DateTime<FixedOffset>) -> Option<OffsetDateTime> {
+    let offset = UtcOffset::from_whole_seconds(datetime.offset().local_minus_utc()).ok()?;
+    Some(to_primitive(datetime.naive_local())?.assume_offset(offset))
+}
+
+impl FromCell for Date {
+    type Plan = ();
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        date_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let date = NaiveDate::from_cell_with_plan(raw, plan)?;
+        to_date(date).ok_or_else(|| out_of_range::<Self>(raw).build())
+    }
+}
+
+impl FromCell for Time {
+    type Plan = TimePlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        time_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let time = NaiveTime::from_cell_with_plan(raw, plan)?;
+        to_time(time).ok_or_else(|| out_of_range::<Self>(raw).build())
+    }
+}
+
+impl FromCell for PrimitiveDateTime {
+    type Plan = TimestampPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        timestamp_ntz_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let datetime = NaiveDateTime::from_cell_with_plan(raw, plan)?;
+        to_primitive(datetime).ok_or_else(|| out_of_range::<Self>(raw).build())
+    }
+}
+
+/// `TIMESTAMP_TZ` cells keep their offset; `TIMESTAMP_LTZ` cells decode at UTC.
+impl FromCell for OffsetDateTime {
+    type Plan = UtcTimestampPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        utc_timestamp_plan::<Self>(ctx.column())
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let datetime = parse_timestamp_with_offset(required_raw(raw)?, plan)?;
+        to_offset_date_time(datetime).ok_or_else(|| out_of_range::<Self>(raw).build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+    use crate::result_table::{schema::ColumnType, test_data::one_cell_table};
+
+    fn decode<T: FromCell>(ty: ColumnType, value: &str) -> T {
+        one_cell_table(ty, value)
+            .rows::<(T,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn decodes_dates_times_and_naive_timestamps() {
+        assert_eq!(
+            decode::<Date>(ColumnType::Date, "19889"),
+            Date::from_calendar_date(2024, Month::June, 15).unwrap()
+        );
+        assert_eq!(
+            decode::<Date>(ColumnType::Date, "-719162"),
+            Date::from_calendar_date(1, Month::January, 1).unwrap()
+        );
+        assert_eq!(
+            decode::<Time>(ColumnType::Time { scale: Some(3) }, "45045.123"),
+            Time::from_hms_milli(12, 30, 45, 123).unwrap()
+        );
+        assert_eq!(
+            decode::<PrimitiveDateTime>(
+                ColumnType::TimestampNtz { scale: Some(9) },
+                "1718454645.000000001"
+            ),
+            PrimitiveDateTime::new(
+                Date::from_calendar_date(2024, Month::June, 15).unwrap(),
+                Time::from_hms_nano(12, 30, 45, 1).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn offset_date_time_keeps_tz_offset_and_reads_ltz_as_utc() {
+        // 2024-06-15T12:30:45Z, shown at +09:00 (1440 + 540).
+        let tz = decode::<OffsetDateTime>(
+            ColumnType::TimestampTz { scale: Some(0) },
+            "1718454645 1980",
+        );
+        assert_eq!(tz.offset(), UtcOffset::from_hms(9, 0, 0).unwrap());
+        assert_eq!(tz.unix_timestamp(), 1_718_454_645);
+        assert_eq!(tz.hour(), 21);
+
+        let ltz =
+            decode::<OffsetDateTime>(ColumnType::TimestampLtz { scale: Some(0) }, "1718454645");
+        assert_eq!(ltz.offset(), UtcOffset::UTC);
+        assert_eq!(ltz.unix_timestamp(), 1_718_454_645);
+    }
+
+    #[test]
+    fn rejects_wrong_columns_and_out_of_range_dates() {
+        let table = one_cell_table(ColumnType::TimestampNtz { scale: Some(0) }, "0");
+        assert!(table.rows::<(OffsetDateTime,)>().is_err());
+        assert!(table.rows::<(Date,)>().is_err());
+
+        // Year 10000 is past `time`'s default range.
+        let table = one_cell_table(ColumnType::Date, "2932897");
+        let err = table
+            .rows::<(Date,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{err}");
+    }
+}
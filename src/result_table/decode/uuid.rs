@@ -0,0 +1,129 @@
+use uuid::Uuid;
+
+use super::{FromCell, decode_hex, incompatible_column, required_raw};
+use crate::{
+    decode::PlanBuildResult,
+    result_table::{
+        CellConversionError, CellDecodeResult, plan::CellPlanContext, schema::ColumnType,
+    },
+};
+
+/// Byte length of a UUID stored as `BINARY`.
+const UUID_BYTES: u32 = 16;
+
+/// How a [`Uuid`] is stored in the column being decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UuidPlan {
+    /// `TEXT` in hyphenated or simple form.
+    Text,
+    /// `BINARY(16)` holding the UUID's bytes in big-endian order.
+    Binary,
+}
+
+/// Accepts `TEXT` columns and `BINARY(16)` columns.
+///
+/// `BINARY` columns reporting any other length are rejected up front; when the length is not reported, each cell is
+/// checked for exactly 16 bytes instead.
+impl FromCell for Uuid {
+    type Plan = UuidPlan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        let column = ctx.column();
+        match column.ty() {
+            ColumnType::Text { .. } => Ok(UuidPlan::Text),
+            ColumnType::Binary { length } => match length {
+                None | Some(UUID_BYTES) => Ok(UuidPlan::Binary),
+                Some(length) => Err(incompatible_column::<Self>(
+                    column,
+                    Some(format!(
+                        "BINARY({length}) cannot hold a UUID, which needs BINARY({UUID_BYTES})"
+                    )),
+                )),
+            },
+            _ => Err(incompatible_column::<Self>(column, None)),
+        }
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let raw = required_raw(raw)?;
+        match plan {
+            UuidPlan::Text => Uuid::try_parse(raw).map_err(|e| {
+                CellConversionError::builder(format!("parse error: {e}"))
+                    .source(e)
+                    .build()
+            }),
+            UuidPlan::Binary => {
+                let bytes = decode_hex(raw).map_err(|m| CellConversionError::builder(m).build())?;
+                Uuid::from_slice(&bytes).map_err(|_| {
+                    CellConversionError::builder(format!(
+                        "expected {UUID_BYTES} bytes for a UUID, got {}",
+                        bytes.len()
+                    ))
+                    .build()
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result_table::test_data::one_cell_table;
+
+    const ID: Uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+    fn decode(ty: ColumnType, value: &str) -> crate::Result<Uuid> {
+        one_cell_table(ty, value)
+            .rows::<(Uuid,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .map(|(id,)| id)
+    }
+
+    #[test]
+    fn text_accepts_hyphenated_and_simple_forms() {
+        for value in [
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "67E55044-10B1-426F-9247-BB680E5FE0C8",
+            "67e5504410b1426f9247bb680e5fe0c8",
+        ] {
+            assert_eq!(
+                decode(ColumnType::Text { length: None }, value).unwrap(),
+                ID
+            );
+        }
+        assert!(decode(ColumnType::Text { length: None }, "not-a-uuid").is_err());
+    }
+
+    #[test]
+    fn binary_decodes_sixteen_bytes() {
+        let hex = "67E5504410B1426F9247BB680E5FE0C8";
+        for length in [Some(16), None] {
+            assert_eq!(decode(ColumnType::Binary { length }, hex).unwrap(), ID);
+        }
+
+        let err = decode(ColumnType::Binary { length: None }, "67E55044").unwrap_err();
+        assert!(err.to_string().contains("expected 16 bytes"), "{err}");
+    }
+
+    #[test]
+    fn plan_rejects_other_binary_lengths_and_types() {
+        let err = one_cell_table(ColumnType::Binary { length: Some(8) }, "00")
+            .rows::<(Uuid,)>()
+            .err()
+            .expect("planning must fail");
+        assert!(
+            err.to_string().contains("BINARY(8) cannot hold a UUID"),
+            "{err}"
+        );
+
+        assert!(
+            one_cell_table(ColumnType::Boolean, "true")
+                .rows::<(Uuid,)>()
+                .is_err()
+        );
+    }
+}
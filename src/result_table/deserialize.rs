@@ -0,0 +1,672 @@
+use std::{any::type_name, fmt, iter::FusedIterator, result::Result as StdResult, vec};
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+    value::SeqDeserializer,
+};
+
+use crate::{
+    Result,
+    error::{PlanBuildResult, RowConversionError, RowDecodeResult},
+    result_table::{
+        CellConversionError, CellDecodeResult, CellPlan, CellPlanContext, CellValue, FromCell,
+        FromRow, RowPlanContext, Rows, dynamic::VectorValue, row::RowRef, table::ResultTable,
+    },
+};
+
+/// Row and cell adapter that decodes through `T`'s [`serde::Deserialize`] implementation.
+///
+/// As a row ([`FromRow`]), `T` sees a map from raw column label to cell value, or the cells in column order when it
+/// deserializes from a sequence (tuples and tuple structs). As a cell ([`FromCell`]), `T` deserializes from that
+/// single cell. Use [`ResultTable::deserialize_rows`] to skip the wrapper when iterating a table.
+///
+/// Every column is first decoded through a [`CellPlan`] for [`CellValue`], so values keep their column type:
+///
+/// - `NUMBER` with scale 0 and `FLOAT` are numbers. Other `NUMBER` cells are their exact decimal text, parsed only
+///   when `T` asks for a number.
+/// - `DATE`, `TIME`, and `TIMESTAMP_NTZ` are ISO 8601 strings, and `TIMESTAMP_LTZ` / `TIMESTAMP_TZ` are RFC 3339
+///   strings, which chrono's `Deserialize` implementations accept.
+/// - `VARIANT`, `OBJECT`, and `ARRAY` are nested JSON. A string target receives the JSON text.
+/// - `BINARY` is bytes, or a sequence of `u8`; `VECTOR` is a sequence of numbers.
+/// - SQL `NULL` is `None` for `Option` targets and unit otherwise.
+///
+/// A failure while deserializing one column's value is reported as a cell decode error for that column; other
+/// failures, such as a missing field, are row conversion errors.
+///
+/// # Example
+///
+/// ```
+/// use snowflake_connector_rs::{ResultTable, Result};
+///
+/// #[derive(serde::Deserialize)]
+/// struct User {
+///     #[serde(rename = "ID")]
+///     id: i64,
+///     #[serde(rename = "NAME")]
+///     name: String,
+/// }
+///
+/// fn users(table: &ResultTable) -> Result<Vec<User>> {
+///     table.deserialize_rows::<User>()?.collect()
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Serde<T>(T);
+
+impl<T> Serde<T> {
+    /// Consume the wrapper, returning the decoded value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Serde<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: DeserializeOwned> FromRow for Serde<T> {
+    type Plan = Box<[CellPlan<CellValue>]>;
+
+    fn build_plan(ctx: RowPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        (0..ctx.schema().len())
+            .map(|index| CellPlan::by_position(ctx, index))
+            .collect()
+    }
+
+    fn from_row_with_plan(row: RowRef<'_>, plan: &Self::Plan) -> RowDecodeResult<Self> {
+        let mut values = Vec::with_capacity(plan.len());
+        for cell_plan in plan.iter() {
+            values.push(row.get_with_plan(cell_plan)?);
+        }
+
+        let mut failed_column = None;
+        let deserializer = RowDeserializer {
+            plan,
+            values: values.into_iter(),
+            index: 0,
+            failed_column: &mut failed_column,
+        };
+        match T::deserialize(deserializer) {
+            Ok(value) => Ok(Serde(value)),
+            Err(error) => {
+                let reason = format!("deserialization failed: {error}");
+                Err(match failed_column {
+                    Some(index) => row
+                        .cell_error_with_plan(
+                            &plan[index],
+                            type_name::<T>(),
+                            CellConversionError::builder(reason).source(error).build(),
+                        )
+                        .into(),
+                    None => RowConversionError::builder(reason)
+                        .source(error)
+                        .build()
+                        .into(),
+                })
+            }
+        }
+    }
+}
+
+impl<T: DeserializeOwned> FromCell for Serde<T> {
+    type Plan = <CellValue as FromCell>::Plan;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        CellValue::build_plan(ctx)
+    }
+
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        let value = CellValue::from_cell_with_plan(raw, plan)?;
+        T::deserialize(CellDeserializer(value))
+            .map(Serde)
+            .map_err(|error| {
+                CellConversionError::builder(format!("deserialization failed: {error}"))
+                    .source(error)
+                    .build()
+            })
+    }
+}
+
+/// Iterator over rows deserialized as `T`, returned by [`ResultTable::deserialize_rows`].
+pub struct DeserializeRows<'a, T: DeserializeOwned> {
+    rows: Rows<'a, Serde<T>>,
+}
+
+impl<'a, T: DeserializeOwned> DeserializeRows<'a, T> {
+    pub(crate) fn new(table: &'a ResultTable) -> Result<Self> {
+        Ok(Self {
+            rows: Rows::new(table)?,
+        })
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for DeserializeRows<'_, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| row.map(Serde::into_inner))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<T: DeserializeOwned> ExactSizeIterator for DeserializeRows<'_, T> {}
+
+impl<T: DeserializeOwned> FusedIterator for DeserializeRows<'_, T> {}
+
+/// Error raised by the row and cell deserializers.
+#[derive(Debug)]
+struct DeError(Box<str>);
+
+impl fmt::Display for DeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for DeError {}
+
+impl de::Error for DeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string().into_boxed_str())
+    }
+}
+
+/// Presents one decoded row as a map keyed by column label, or as a sequence in column order.
+struct RowDeserializer<'p> {
+    plan: &'p [CellPlan<CellValue>],
+    values: vec::IntoIter<CellValue>,
+    index: usize,
+    /// Set to the column whose value failed to deserialize, so the error can carry that column's context.
+    failed_column: &'p mut Option<usize>,
+}
+
+impl RowDeserializer<'_> {
+    fn next_value<'de, S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> StdResult<Option<S::Value>, DeError> {
+        let Some(value) = self.values.next() else {
+            return Ok(None);
+        };
+        let index = self.index;
+        self.index += 1;
+        seed.deserialize(CellDeserializer(value))
+            .map(Some)
+            .inspect_err(|_| *self.failed_column = Some(index))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for RowDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        visitor.visit_map(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> StdResult<V::Value, DeError> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> StdResult<V::Value, DeError> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> StdResult<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+        unit_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> MapAccess<'de> for RowDeserializer<'_> {
+    type Error = DeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> StdResult<Option<K::Value>, DeError> {
+        match self.plan.get(self.index) {
+            Some(cell_plan) => seed
+                .deserialize(cell_plan.column.name().into_deserializer())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> StdResult<V::Value, DeError> {
+        self.next_value(seed)?
+            .ok_or_else(|| de::Error::custom("value requested past the last column"))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+impl<'de> SeqAccess<'de> for RowDeserializer<'_> {
+    type Error = DeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> StdResult<Option<T::Value>, DeError> {
+        self.next_value(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+/// Presents one decoded cell according to its column type.
+struct CellDeserializer(CellValue);
+
+impl CellDeserializer {
+    /// The cell as text for string targets: decimals, numbers, temporal values, and JSON documents all have one.
+    fn into_text(self) -> StdResult<String, CellValue> {
+        match self.0 {
+            CellValue::String(s) => Ok(s),
+            CellValue::Decimal(d) | CellValue::DecFloat(d) => Ok(d.raw().to_string()),
+            CellValue::Integer(i) => Ok(i.to_string()),
+            CellValue::Float(f) => Ok(f.to_string()),
+            CellValue::Date(d) => Ok(d.format("%Y-%m-%d").to_string()),
+            CellValue::Time(t) => Ok(t.format("%H:%M:%S%.f").to_string()),
+            CellValue::TimestampNtz(dt) => Ok(dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string()),
+            CellValue::TimestampLtz(dt) => Ok(dt.to_rfc3339()),
+            CellValue::TimestampTz(dt) => Ok(dt.to_rfc3339()),
+            CellValue::Json(serde_json::Value::String(s)) => Ok(s),
+            CellValue::Json(v) => Ok(v.to_string()),
+            other => Err(other),
+        }
+    }
+
+    /// Numeric targets parse `NUMBER` cells that carry a scale and `DECFLOAT` cells; everything else follows the column
+    /// type.
+    fn deserialize_number<'de, V: Visitor<'de>>(
+        self,
+        float: bool,
+        visitor: V,
+    ) -> StdResult<V::Value, DeError> {
+        let (CellValue::Decimal(decimal) | CellValue::DecFloat(decimal)) = &self.0 else {
+            return de::Deserializer::deserialize_any(self, visitor);
+        };
+        let raw = decimal.raw();
+        if float {
+            return raw
+                .parse::<f64>()
+                .map_err(|e| de::Error::custom(format!("'{raw}' is not a number: {e}")))
+                .and_then(|value| visitor.visit_f64(value));
+        }
+        if let Ok(value) = raw.parse::<i64>() {
+            visitor.visit_i64(value)
+        } else if let Ok(value) = raw.parse::<u64>() {
+            visitor.visit_u64(value)
+        } else {
+            raw.parse::<i128>()
+                .map_err(|e| de::Error::custom(format!("'{raw}' is not an integer: {e}")))
+                .and_then(|value| visitor.visit_i128(value))
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for CellDeserializer {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        match self.0 {
+            CellValue::Null => visitor.visit_unit(),
+            CellValue::Boolean(b) => visitor.visit_bool(b),
+            CellValue::Integer(i) => {
+                if let Ok(value) = i64::try_from(i) {
+                    visitor.visit_i64(value)
+                } else if let Ok(value) = u64::try_from(i) {
+                    visitor.visit_u64(value)
+                } else {
+                    visitor.visit_i128(i)
+                }
+            }
+            CellValue::Float(f) => visitor.visit_f64(f),
+            CellValue::Json(value) => value.deserialize_any(visitor).map_err(de::Error::custom),
+            // A seq of `u8` is what self-describing targets such as `serde_json::Value`
+            // understand; `deserialize_bytes` hands out the buffer directly instead.
+            CellValue::Binary(bytes) => {
+                visitor.visit_seq(SeqDeserializer::new(bytes.into_bytes().into_iter()))
+            }
+            CellValue::Vector(VectorValue::Int(vector)) => {
+                visitor.visit_seq(SeqDeserializer::new(vector.into_iter()))
+            }
+            CellValue::Vector(VectorValue::Float(vector)) => {
+                visitor.visit_seq(SeqDeserializer::new(vector.into_iter()))
+            }
+            other => match CellDeserializer(other).into_text() {
+                Ok(text) => visitor.visit_string(text),
+                Err(other) => Err(de::Error::custom(format!(
+                    "unsupported cell value: {other:?}"
+                ))),
+            },
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        match self.0 {
+            CellValue::Null => visitor.visit_none(),
+            CellValue::Json(value) => value.deserialize_option(visitor).map_err(de::Error::custom),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        match self.into_text() {
+            Ok(text) => visitor.visit_string(text),
+            Err(other) => CellDeserializer(other).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        match self.0 {
+            CellValue::Binary(bytes) => visitor.visit_byte_buf(bytes.into_bytes().to_vec()),
+            other => CellDeserializer(other).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> StdResult<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> StdResult<V::Value, DeError> {
+        match self.0 {
+            CellValue::String(s) => visitor.visit_enum(s.into_deserializer()),
+            CellValue::Json(value) => value
+                .deserialize_enum(name, variants, visitor)
+                .map_err(de::Error::custom),
+            other => CellDeserializer(other).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(false, visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(true, visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> StdResult<V::Value, DeError> {
+        self.deserialize_number(true, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool char seq unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{
+        ColumnType,
+        result_table::test_data::{make_result_table_from_rows, make_schema},
+    };
+
+    fn fixed(scale: u8) -> ColumnType {
+        ColumnType::Fixed {
+            precision: None,
+            scale: Some(scale),
+        }
+    }
+
+    fn order_table() -> ResultTable {
+        let schema = make_schema(vec![
+            ("ID".to_string(), fixed(0), false),
+            ("PRICE".to_string(), fixed(2), false),
+            ("TOTAL".to_string(), fixed(2), false),
+            (
+                "PLACED_AT".to_string(),
+                ColumnType::TimestampNtz { scale: Some(3) },
+                false,
+            ),
+            ("ATTRS".to_string(), ColumnType::Variant, true),
+            ("NOTE".to_string(), ColumnType::Text { length: None }, true),
+            (
+                "PAYLOAD".to_string(),
+                ColumnType::Binary { length: None },
+                true,
+            ),
+        ]);
+        make_result_table_from_rows(
+            schema,
+            vec![vec![
+                Some("7".to_string()),
+                Some("12.50".to_string()),
+                Some("99.95".to_string()),
+                Some("1700000000.250".to_string()),
+                Some(r#"{"color":"red","tags":["a","b"]}"#.to_string()),
+                None,
+                Some("CAFE".to_string()),
+            ]],
+        )
+        .unwrap()
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Attrs {
+        color: String,
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    struct Order {
+        id: u32,
+        price: String,
+        total: f64,
+        placed_at: String,
+        attrs: Attrs,
+        note: Option<String>,
+        payload: Vec<u8>,
+    }
+
+    #[test]
+    fn rows_deserialize_typed_columns_with_full_fidelity() {
+        let table = order_table();
+        let orders = table
+            .deserialize_rows::<Order>()
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            orders,
+            vec![Order {
+                id: 7,
+                price: "12.50".to_string(),
+                total: 99.95,
+                placed_at: "2023-11-14T22:13:20.250".to_string(),
+                attrs: Attrs {
+                    color: "red".to_string(),
+                    tags: vec!["a".to_string(), "b".to_string()],
+                },
+                note: None,
+                payload: vec![0xCA, 0xFE],
+            }]
+        );
+    }
+
+    #[test]
+    fn rows_deserialize_as_maps_and_tuples() {
+        let table = order_table();
+
+        let map = table
+            .deserialize_rows::<BTreeMap<String, serde_json::Value>>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(map["ID"], serde_json::json!(7));
+        assert_eq!(map["PRICE"], serde_json::json!("12.50"));
+        assert_eq!(map["NOTE"], serde_json::Value::Null);
+
+        let (id, price): (i64, f64) = table
+            .rows::<Serde<(i64, f64)>>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .into_inner();
+        assert_eq!((id, price), (7, 12.5));
+    }
+
+    #[test]
+    fn serde_cells_decode_inside_tuple_rows() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "lowercase")]
+        enum Status {
+            Open,
+        }
+
+        let schema = make_schema(vec![(
+            "STATUS".to_string(),
+            ColumnType::Text { length: None },
+            false,
+        )]);
+        let table =
+            make_result_table_from_rows(schema, vec![vec![Some("open".to_string())]]).unwrap();
+        let (status,) = table
+            .rows::<(Serde<Status>,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(status.into_inner(), Status::Open);
+    }
+
+    #[test]
+    fn value_failures_point_at_the_column_and_missing_fields_at_the_row() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct WrongType {
+            #[serde(rename = "NOTE")]
+            note: String,
+            #[serde(rename = "ATTRS")]
+            attrs: u8,
+        }
+
+        let table = order_table();
+        let err = table
+            .deserialize_rows::<WrongType>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        let cell = err
+            .as_cell_decode_error()
+            .expect("value failure should be a cell decode error");
+        assert_eq!(cell.column_name(), "ATTRS");
+        assert!(
+            cell.conversion_error()
+                .reason()
+                .starts_with("deserialization failed:")
+        );
+
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Missing {
+            #[serde(rename = "ABSENT")]
+            absent: i64,
+        }
+
+        let err = table
+            .deserialize_rows::<Missing>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(err.as_cell_decode_error().is_none());
+        assert!(err.to_string().contains("missing field `ABSENT`"));
+    }
+}
@@ -10,22 +10,22 @@ use crate::{
         RowDecodeResult, SchemaError,
     },
     result_table::{
-        CellConversionError, CellDecodeResult, FromRow, RowPlanContext,
-        cell::CellRef,
+        CellConversionError, CellDecodeResult, CellPlanContext, FromCell, FromRow, RowPlanContext,
         decode::{
             Vector, decode_hex, decode_json_payload, parse_time_seconds_and_nanos,
             parse_timestamp_epoch, parse_timestamp_tz_with_offset, parse_vector_f32_payload,
             parse_vector_i32_payload,
         },
         row::RowRef,
-        schema::{ColumnType, Schema},
+        schema::{ColumnType, Schema, VectorElementType},
     },
 };
 
-/// Result-side representation of a Snowflake `NUMBER` / `DECIMAL` cell with non-zero scale.
+/// Result-side representation of a Snowflake `NUMBER` / `DECIMAL` cell with non-zero scale, or of a `DECFLOAT` cell.
 ///
 /// Snowflake decimals can carry up to 38 digits of precision, which does not fit in any native Rust numeric type.
-/// `DecimalValue` therefore preserves the value as the exact string Snowflake delivered.
+/// `DecimalValue` therefore preserves the value as the exact string Snowflake delivered, including the exponent form
+/// (such as `1.23e-40`) Snowflake uses for `DECFLOAT` values.
 /// The column's declared precision and scale are available on its [`ColumnType`](crate::ColumnType).
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DecimalValue {
@@ -89,10 +89,11 @@ impl AsRef<[u8]> for BinaryValue {
 
 /// Decoded Snowflake `VECTOR` value on the dynamic-typing path.
 ///
-/// Snowflake result metadata does not report a `VECTOR`'s element type, so the payload determines the variant:
-/// an integer-only payload decodes as [`Int`](VectorValue::Int), and a payload carrying a decimal point or a
-/// lowercase `inf` / `-inf` / `nan` token decodes as [`Float`](VectorValue::Float). Snowflake cannot produce an empty
-/// `VECTOR`, so an empty payload is rejected rather than being treated as an ambiguous element type.
+/// The variant follows the element type the column reports. When the result metadata does not report one, the payload
+/// determines the variant: an integer-only payload decodes as [`Int`](VectorValue::Int), and a payload carrying a
+/// decimal point or a lowercase `inf` / `-inf` / `nan` token decodes as [`Float`](VectorValue::Float). Snowflake
+/// cannot produce an empty `VECTOR`, so an empty payload is rejected rather than being treated as an ambiguous element
+/// type.
 #[derive(Clone, Debug, PartialEq)]
 pub enum VectorValue {
     /// `VECTOR(INT)` — 32-bit signed integer elements.
@@ -170,6 +171,8 @@ pub enum CellValue {
     Float(f64),
     /// `NUMBER` with non-zero scale — preserved as text via [`DecimalValue`].
     Decimal(DecimalValue),
+    /// `DECFLOAT` — preserved as text via [`DecimalValue`], keeping any exponent.
+    DecFloat(DecimalValue),
     /// `TEXT` / `VARCHAR` / `CHAR` / `STRING`.
     String(String),
     /// `DATE`.
@@ -276,6 +279,30 @@ impl DynamicRow {
         Ok(mem::replace(slot, CellValue::Null))
     }
 
+    /// The `TIMESTAMP_LTZ` value at a zero-based column index in the session time zone
+    /// ([`Schema::session_timezone`]), matching the wall-clock time Snowflake shows.
+    ///
+    /// Returns `Ok(None)` when the value is not a [`CellValue::TimestampLtz`] (SQL `NULL` included), or when the session
+    /// `TIMEZONE` is absent or not an IANA zone name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError::InvalidColumnIndex`] when `index` is out of range for this row's schema.
+    #[cfg(feature = "chrono-tz")]
+    pub fn ltz_in_session_timezone_at(
+        &self,
+        index: usize,
+    ) -> StdResult<Option<DateTime<chrono_tz::Tz>>, SchemaError> {
+        let CellValue::TimestampLtz(dt) = self.value_at(index)? else {
+            return Ok(None);
+        };
+        Ok(self
+            .schema
+            .session_zone()
+            .tz()
+            .map(|zone| dt.with_timezone(&zone)))
+    }
+
     /// Consume the row, returning the shared schema and the decoded values in column order.
     pub fn into_parts(self) -> (Arc<Schema>, Box<[CellValue]>) {
         (self.schema, self.values)
@@ -283,6 +310,9 @@ impl DynamicRow {
 
     /// Consume the row into a `serde_json::Map` keyed by raw column label.
     ///
+    /// `DynamicRow` also implements [`serde::Serialize`] with the same rendering, which writes straight to a serializer
+    /// without building intermediate `serde_json::Value`s.
+    ///
     /// # Errors
     ///
     /// Returns [`SchemaError::DuplicateColumnName`] when two columns share the same raw label — JSON object keys must be unique.
@@ -323,7 +353,9 @@ impl CellValue {
                 Some(n) => serde_json::Value::Number(n),
                 None => serde_json::Value::String(f.to_string()),
             },
-            CellValue::Decimal(d) => serde_json::Value::String(d.raw().to_string()),
+            CellValue::Decimal(d) | CellValue::DecFloat(d) => {
+                serde_json::Value::String(d.raw().to_string())
+            }
             CellValue::String(s) => serde_json::Value::String(s),
             CellValue::Date(d) => serde_json::Value::String(d.format("%Y-%m-%d").to_string()),
             CellValue::Time(t) => serde_json::Value::String(t.format("%H:%M:%S%.f").to_string()),
@@ -352,7 +384,7 @@ impl FromRow for DynamicRow {
         let mut values = Vec::with_capacity(plan.len());
         for (offset, col) in plan.columns().iter().enumerate() {
             let cell = row.cell_at_offset(col, offset);
-            values.push(decode_dynamic(cell).map_err(|issue| {
+            values.push(decode_dynamic(cell.raw(), col.ty()).map_err(|issue| {
                 CellDecodeError::new(
                     cell.row_index(),
                     cell.column().index(),
@@ -372,13 +404,24 @@ impl FromRow for DynamicRow {
     }
 }
 
-fn decode_dynamic(cell: CellRef<'_>) -> CellDecodeResult<CellValue> {
-    if cell.is_null() {
-        return Ok(CellValue::Null);
+/// Decodes a single cell with the same rules as [`DynamicRow`]. The plan is the column's type; SQL `NULL` decodes as
+/// [`CellValue::Null`].
+impl FromCell for CellValue {
+    type Plan = ColumnType;
+
+    fn build_plan(ctx: CellPlanContext<'_>) -> PlanBuildResult<Self::Plan> {
+        Ok(ctx.column().ty().clone())
     }
 
-    let raw = cell.raw().expect("non-null checked above");
-    let ty = cell.column().ty();
+    fn from_cell_with_plan(raw: Option<&str>, plan: &Self::Plan) -> CellDecodeResult<Self> {
+        decode_dynamic(raw, plan)
+    }
+}
+
+fn decode_dynamic(raw: Option<&str>, ty: &ColumnType) -> CellDecodeResult<CellValue> {
+    let Some(raw) = raw else {
+        return Ok(CellValue::Null);
+    };
 
     match ty {
         ColumnType::Boolean => {
@@ -398,6 +441,7 @@ fn decode_dynamic(cell: CellRef<'_>) -> CellDecodeResult<CellValue> {
             }
             Ok(CellValue::Decimal(DecimalValue::new(raw)))
         }
+        ColumnType::DecFloat => Ok(CellValue::DecFloat(DecimalValue::new(raw))),
         ColumnType::Real => raw.parse::<f64>().map(CellValue::Float).map_err(|e| {
             CellConversionError::builder(format!("parse error: {e}"))
                 .source(e)
@@ -454,31 +498,44 @@ fn decode_dynamic(cell: CellRef<'_>) -> CellDecodeResult<CellValue> {
                 .map_err(|m| CellConversionError::builder(m).build())?;
             Ok(CellValue::TimestampTz(dt))
         }
-        ColumnType::Variant | ColumnType::Object | ColumnType::Array => {
-            Ok(CellValue::Json(decode_json_payload(raw)?))
-        }
+        ColumnType::Variant
+        | ColumnType::Object { .. }
+        | ColumnType::Array { .. }
+        | ColumnType::Map { .. } => Ok(CellValue::Json(decode_json_payload(raw)?)),
         ColumnType::Binary { .. } => {
             let bytes = decode_hex(raw).map_err(|m| CellConversionError::builder(m).build())?;
             Ok(CellValue::Binary(BinaryValue::new(bytes)))
         }
-        ColumnType::Vector => decode_vector_dynamic(raw).map(CellValue::Vector),
+        ColumnType::Vector { element, .. } => {
+            decode_vector_dynamic(raw, *element).map(CellValue::Vector)
+        }
         ColumnType::Geography | ColumnType::Geometry | ColumnType::Unknown { .. } => {
             Ok(CellValue::String(raw.to_string()))
         }
     }
 }
 
-/// Decode a `VECTOR` payload, inferring the element type.
+/// Decode a `VECTOR` payload as the reported element type, or infer it when the metadata did not report one.
 ///
-/// A `VECTOR(INT)` payload parses as [`VectorValue::Int`]. If integer parsing fails, the float parser handles decimal
-/// and lowercase non-finite tokens and returns [`VectorValue::Float`].
-fn decode_vector_dynamic(raw: &str) -> CellDecodeResult<VectorValue> {
-    if let Ok(ints) = parse_vector_i32_payload(raw) {
-        return Ok(VectorValue::Int(Vector::from_vec(ints)));
-    }
-    parse_vector_f32_payload(raw)
-        .map(|floats| VectorValue::Float(Vector::from_vec(floats)))
-        .map_err(|m| CellConversionError::builder(m).build())
+/// Without metadata, a `VECTOR(INT)` payload parses as [`VectorValue::Int`]. If integer parsing fails, the float
+/// parser handles decimal and lowercase non-finite tokens and returns [`VectorValue::Float`].
+fn decode_vector_dynamic(
+    raw: &str,
+    element: Option<VectorElementType>,
+) -> CellDecodeResult<VectorValue> {
+    let decoded = match element {
+        Some(VectorElementType::Int) => {
+            parse_vector_i32_payload(raw).map(|ints| VectorValue::Int(Vector::from_vec(ints)))
+        }
+        Some(_) => parse_vector_f32_payload(raw, true)
+            .map(|floats| VectorValue::Float(Vector::from_vec(floats))),
+        None => match parse_vector_i32_payload(raw) {
+            Ok(ints) => Ok(VectorValue::Int(Vector::from_vec(ints))),
+            Err(_) => parse_vector_f32_payload(raw, false)
+                .map(|floats| VectorValue::Float(Vector::from_vec(floats))),
+        },
+    };
+    decoded.map_err(|m| CellConversionError::builder(m).build())
 }
 
 #[cfg(test)]
@@ -509,6 +566,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn dynamic_row_keeps_decfloat_text_with_exponent() {
+        let row = one_cell_row(ColumnType::DecFloat, "-4.2e+16000");
+        assert_eq!(
+            row.value("PAYLOAD").unwrap(),
+            &CellValue::DecFloat(DecimalValue::new("-4.2e+16000"))
+        );
+        assert_eq!(row.into_json_object().unwrap()["PAYLOAD"], "-4.2e+16000");
+    }
+
     #[test]
     fn dynamic_row_decodes_variant_cells_as_json() {
         let row = one_cell_row(ColumnType::Variant, r#"{"a":1}"#);
@@ -538,7 +605,13 @@ mod tests {
 
     #[test]
     fn dynamic_row_decodes_integer_vector() {
-        let row = one_cell_row(ColumnType::Vector, "[1,2,3]");
+        let row = one_cell_row(
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            },
+            "[1,2,3]",
+        );
         match row.value("PAYLOAD").unwrap() {
             CellValue::Vector(VectorValue::Int(vector)) => {
                 assert_eq!(vector.as_slice(), &[1, 2, 3]);
@@ -549,7 +622,13 @@ mod tests {
 
     #[test]
     fn dynamic_row_decodes_float_vector_from_decimal_payload() {
-        let row = one_cell_row(ColumnType::Vector, "[1.500000,-2.250000,0.000000]");
+        let row = one_cell_row(
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            },
+            "[1.500000,-2.250000,0.000000]",
+        );
         match row.value("PAYLOAD").unwrap() {
             CellValue::Vector(VectorValue::Float(vector)) => {
                 assert_eq!(vector.as_slice(), &[1.5f32, -2.25, 0.0]);
@@ -560,7 +639,13 @@ mod tests {
 
     #[test]
     fn dynamic_row_decodes_float_vector_from_non_finite_payload() {
-        let row = one_cell_row(ColumnType::Vector, "[1.000000,nan,-inf]");
+        let row = one_cell_row(
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            },
+            "[1.000000,nan,-inf]",
+        );
         match row.value("PAYLOAD").unwrap() {
             CellValue::Vector(VectorValue::Float(vector)) => {
                 let slice = vector.as_slice();
@@ -572,9 +657,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn dynamic_row_uses_reported_vector_element_type() {
+        let float = ColumnType::Vector {
+            element: Some(VectorElementType::Float),
+            dimension: Some(3),
+        };
+        let row = one_cell_row(float, "[1,2,3]");
+        match row.value("PAYLOAD").unwrap() {
+            CellValue::Vector(VectorValue::Float(vector)) => {
+                assert_eq!(vector.as_slice(), &[1.0f32, 2.0, 3.0]);
+            }
+            other => panic!("expected Vector(Float), got {other:?}"),
+        }
+    }
+
     #[test]
     fn dynamic_row_malformed_vector_reports_contextual_error() {
-        let schema = make_schema(vec![("PAYLOAD".to_string(), ColumnType::Vector, true)]);
+        let schema = make_schema(vec![(
+            "PAYLOAD".to_string(),
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            },
+            true,
+        )]);
         let table =
             make_result_table_from_rows(schema, vec![vec![Some("[abc]".to_string())]]).unwrap();
 
@@ -1,27 +1,47 @@
 mod cell;
 mod decode;
+mod deserialize;
 mod dynamic;
 mod plan;
+mod pretty;
 mod row;
 mod schema;
+mod serialize;
 mod table;
 mod typed_table;
 
 pub use cell::CellRef;
+#[cfg(feature = "chrono-tz")]
+pub use decode::ZonedTimestampPlan;
+pub use decode::{ArrayPlan, MapPlan, Object, ObjectPlan};
 pub use decode::{
-    CellPlan, FromCell, FromRow, Json, TimePlan, TimestampPlan, UtcTimestampPlan, Vector,
+    BinaryPlan, BytesPlan, CellPlan, CellRefPlan, EnumPlan, FromCell, FromCellRef, FromRow,
+    FromRowRef, Json, Lossy, TimePlan, TimestampPlan, UtcTimestampPlan, UuidPlan, Vector,
+    VectorPlan,
 };
+#[cfg(feature = "geo")]
+pub use decode::{GeoPlan, Wkb};
+pub use deserialize::{DeserializeRows, Serde};
 pub use dynamic::{BinaryValue, CellValue, DecimalValue, DynamicRow, VectorValue};
 pub use plan::{CellPlanContext, RowPlanContext};
-pub use row::{RowRef, Rows};
-pub use schema::{Column, ColumnType, Schema};
+pub use pretty::PrettyTable;
+pub use row::{RowRef, Rows, RowsRef};
+pub use schema::{Column, ColumnLookup, ColumnType, Schema, VectorElementType};
+pub use serialize::{BinaryFormat, DecimalFormat, NonFiniteFloatFormat, SerializeOptions};
 pub use table::ResultTable;
 pub use typed_table::TypedResultTable;
 
 pub use crate::error::decode::{CellConversionError, CellDecodeResult};
 pub(crate) use cell::RawSpan;
+pub(crate) use schema::SessionZone;
+pub(crate) use serialize::{check_unique_labels, encode_binary};
 pub(crate) use table::ResultTableBuilder;
 
+#[cfg(feature = "parquet")]
+pub(crate) use decode::parse_decimal_unscaled;
+#[cfg(feature = "parquet")]
+pub(crate) use row::RowRefs;
+
 #[cfg(any(test, feature = "bench-internals"))]
 #[doc(hidden)]
 pub mod test_data {
@@ -48,7 +68,7 @@ pub mod test_data {
         precision: Option<u8>,
         scale: Option<u8>,
     ) -> ColumnType {
-        ColumnType::from_driver_metadata(snowflake_type, length, precision, scale)
+        ColumnType::from_driver_metadata(snowflake_type, length, precision, scale, Vec::new(), None)
     }
 
     pub fn make_result_table_from_rows(
@@ -69,6 +89,12 @@ pub mod test_data {
         }
         Ok(builder.finish()?)
     }
+
+    #[cfg(test)]
+    pub fn one_cell_table(ty: ColumnType, value: &str) -> ResultTable {
+        let schema = make_schema(vec![("X".to_string(), ty, true)]);
+        make_result_table_from_rows(schema, vec![vec![Some(value.to_string())]]).unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -1,16 +1,45 @@
 use std::sync::Arc;
 
-use crate::result_table::{Column, Schema};
+use crate::{
+    error::SchemaError,
+    result_table::{Column, ColumnLookup, Schema},
+};
 
 /// Context for building a row decode plan.
 #[derive(Clone, Copy)]
 pub struct RowPlanContext<'a> {
     schema: &'a Arc<Schema>,
+    lookup: ColumnLookup,
 }
 
 impl<'a> RowPlanContext<'a> {
     pub(crate) fn new(schema: &'a Arc<Schema>) -> Self {
-        Self { schema }
+        Self {
+            schema,
+            lookup: ColumnLookup::Exact,
+        }
+    }
+
+    /// Returns a copy of this context that resolves column names with `lookup`.
+    ///
+    /// Name-based plans such as [`CellPlan::by_name`](crate::CellPlan::by_name) and nested `FromRow` plans built from
+    /// the returned context use this mode.
+    pub fn with_column_lookup(self, lookup: ColumnLookup) -> Self {
+        Self { lookup, ..self }
+    }
+
+    /// The mode used to resolve column names. Defaults to [`ColumnLookup::Exact`].
+    pub fn column_lookup(&self) -> ColumnLookup {
+        self.lookup
+    }
+
+    /// Resolve a column name to its zero-based index using this context's [`ColumnLookup`] mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Schema::column_index_with`].
+    pub fn column_index(&self, name: &str) -> Result<usize, SchemaError> {
+        self.schema.column_index_with(name, self.lookup)
     }
 
     /// Borrow the schema describing the result-set columns.
@@ -50,4 +79,20 @@ impl<'a> CellPlanContext<'a> {
     pub fn shared_schema(&self) -> Arc<Schema> {
         self.row_ctx.shared_schema()
     }
+
+    /// The row context this cell plan is being built in.
+    pub(crate) fn row_context(&self) -> RowPlanContext<'a> {
+        self.row_ctx
+    }
+
+    /// Context for a column nested inside this one, such as a structured element or field.
+    pub(crate) fn nested<'b>(&self, column: &'b Column) -> CellPlanContext<'b>
+    where
+        'a: 'b,
+    {
+        CellPlanContext {
+            row_ctx: self.row_ctx,
+            column,
+        }
+    }
 }
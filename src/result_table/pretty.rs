@@ -0,0 +1,360 @@
+//! Human-readable table rendering for [`ResultTable`].
+//!
+//! [`ResultTable::pretty`] returns a [`PrettyTable`], a configurable [`fmt::Display`] adapter. `ResultTable`'s own
+//! `Display` impl renders with the defaults.
+
+use std::fmt::{self, Write as _};
+
+use crate::result_table::{
+    BinaryFormat, ColumnType, FromCell as _,
+    dynamic::CellValue,
+    encode_binary,
+    row::RowRefs,
+    schema::{Column, SessionZone},
+    table::ResultTable,
+};
+
+const DEFAULT_MAX_COLUMN_WIDTH: usize = 40;
+const ELLIPSIS: char = '…';
+
+/// A [`ResultTable`] rendered as a bordered text table, returned by [`ResultTable::pretty`].
+///
+/// Headers are the raw column labels. Cells are rendered as text: numbers and decimals as Snowflake delivered them,
+/// dates, times, and timestamps in the ISO 8601 forms used by [`CellValue::into_json_value`], semi-structured values
+/// as compact JSON, and `BINARY` as uppercase hex. `NUMBER` and `FLOAT` columns are right-aligned; all others are
+/// left-aligned. Line breaks, tabs, and other control characters are escaped so every row stays on one line, and
+/// cells that do not decode are shown as their raw text.
+///
+/// Widths are measured in `char`s, so wide characters such as CJK ideographs can misalign a column.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(session: snowflake_connector_rs::Session) -> snowflake_connector_rs::Result<()> {
+/// let table = session.query("SELECT 1 AS ID, 'hello' AS VALUE").await?.collect_table().await?;
+/// println!("{}", table.pretty().with_max_rows(20).with_max_column_width(24));
+/// // +----+-------+
+/// // | ID | VALUE |
+/// // +----+-------+
+/// // |  1 | hello |
+/// // +----+-------+
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PrettyTable<'a> {
+    table: &'a ResultTable,
+    max_column_width: usize,
+    max_rows: Option<usize>,
+    null_text: Box<str>,
+    ltz_in_session_timezone: bool,
+}
+
+impl<'a> PrettyTable<'a> {
+    fn new(table: &'a ResultTable) -> Self {
+        Self {
+            table,
+            max_column_width: DEFAULT_MAX_COLUMN_WIDTH,
+            max_rows: None,
+            null_text: "NULL".into(),
+            ltz_in_session_timezone: false,
+        }
+    }
+
+    /// Sets the widest a column may be, in `char`s. Longer headers and cells are truncated with `…`. Defaults to 40;
+    /// values below 1 are treated as 1.
+    pub fn with_max_column_width(mut self, width: usize) -> Self {
+        self.max_column_width = width.max(1);
+        self
+    }
+
+    /// Sets how many rows are rendered. Remaining rows are summarized in a footer line. Defaults to all rows.
+    pub fn with_max_rows(mut self, rows: usize) -> Self {
+        self.max_rows = Some(rows);
+        self
+    }
+
+    /// Sets the text shown for SQL `NULL`. Defaults to `NULL`.
+    pub fn with_null_text(mut self, null_text: impl Into<Box<str>>) -> Self {
+        self.null_text = null_text.into();
+        self
+    }
+
+    /// Sets whether `TIMESTAMP_LTZ` cells are shown in the session time zone
+    /// ([`Schema::session_timezone`](crate::Schema::session_timezone)) instead of UTC, matching the wall-clock time
+    /// Snowflake shows. Cells stay in UTC when the session `TIMEZONE` is absent or not an IANA zone name. Defaults to
+    /// `false`.
+    #[cfg(feature = "chrono-tz")]
+    pub fn with_ltz_in_session_timezone(mut self, enabled: bool) -> Self {
+        self.ltz_in_session_timezone = enabled;
+        self
+    }
+
+    pub fn max_column_width(&self) -> usize {
+        self.max_column_width
+    }
+
+    pub fn max_rows(&self) -> Option<usize> {
+        self.max_rows
+    }
+
+    pub fn null_text(&self) -> &str {
+        &self.null_text
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    pub fn ltz_in_session_timezone(&self) -> bool {
+        self.ltz_in_session_timezone
+    }
+
+    fn fit(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut width = 0;
+        for c in text.chars() {
+            let escaped = match c {
+                '\n' => Some("\\n"),
+                '\r' => Some("\\r"),
+                '\t' => Some("\\t"),
+                _ => None,
+            };
+            let start = out.len();
+            match escaped {
+                Some(escaped) => out.push_str(escaped),
+                None if c.is_control() => {
+                    let _ = write!(out, "{}", c.escape_default());
+                }
+                None => out.push(c),
+            }
+            width += out[start..].chars().count();
+            if width > self.max_column_width {
+                return truncate(out, self.max_column_width);
+            }
+        }
+        out
+    }
+
+    fn cell_text(&self, column: &Column, raw: Option<&str>, zone: SessionZone) -> String {
+        match CellValue::from_cell_with_plan(raw, column.ty()) {
+            Ok(CellValue::Null) => self.fit(&self.null_text),
+            Ok(value) => self.fit(&value_text(value, zone)),
+            Err(_) => self.fit(raw.unwrap_or(&self.null_text)),
+        }
+    }
+}
+
+/// Cut `text` to `width` chars, ending in `…`.
+fn truncate(mut text: String, width: usize) -> String {
+    let cut = text
+        .char_indices()
+        .nth(width - 1)
+        .map_or(text.len(), |(idx, _)| idx);
+    text.truncate(cut);
+    text.push(ELLIPSIS);
+    text
+}
+
+fn value_text(value: CellValue, zone: SessionZone) -> String {
+    match value {
+        CellValue::Null => String::new(),
+        CellValue::Boolean(b) => b.to_string(),
+        CellValue::Integer(i) => i.to_string(),
+        CellValue::Float(f) => f.to_string(),
+        CellValue::Decimal(d) | CellValue::DecFloat(d) => d.raw().to_string(),
+        CellValue::String(s) => s,
+        CellValue::Binary(bytes) => encode_binary(bytes.as_bytes(), BinaryFormat::Hex),
+        CellValue::Json(json) => json.to_string(),
+        CellValue::TimestampLtz(dt) => zone.ltz_rfc3339(&dt),
+        other => match other.into_json_value() {
+            serde_json::Value::String(s) => s,
+            json => json.to_string(),
+        },
+    }
+}
+
+fn is_numeric(ty: &ColumnType) -> bool {
+    matches!(
+        ty,
+        ColumnType::Fixed { .. } | ColumnType::Real | ColumnType::DecFloat
+    )
+}
+
+impl fmt::Display for PrettyTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns = self.table.schema().columns();
+        let shown = self.max_rows.map_or(self.table.row_count(), |max| {
+            max.min(self.table.row_count())
+        });
+
+        let headers = columns
+            .iter()
+            .map(|col| self.fit(col.name()))
+            .collect::<Vec<_>>();
+        let zone = self.table.schema().ltz_zone(self.ltz_in_session_timezone);
+        let rows = RowRefs::new(self.table)
+            .take(shown)
+            .map(|row| {
+                columns
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, col)| {
+                        self.cell_text(col, row.cell_at_offset(col, offset).raw(), zone)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = headers
+            .iter()
+            .map(|h| h.chars().count())
+            .collect::<Vec<_>>();
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let border = |f: &mut fmt::Formatter<'_>| -> fmt::Result {
+            f.write_char('+')?;
+            for width in &widths {
+                write!(f, "{:-<1$}+", "", width + 2)?;
+            }
+            f.write_char('\n')
+        };
+        let line = |f: &mut fmt::Formatter<'_>, cells: &[String]| -> fmt::Result {
+            f.write_char('|')?;
+            for ((cell, width), col) in cells.iter().zip(&widths).zip(columns) {
+                if is_numeric(col.ty()) {
+                    write!(f, " {cell:>width$} |")?;
+                } else {
+                    write!(f, " {cell:<width$} |")?;
+                }
+            }
+            f.write_char('\n')
+        };
+
+        border(f)?;
+        line(f, &headers)?;
+        border(f)?;
+        for row in &rows {
+            line(f, row)?;
+        }
+        if !rows.is_empty() {
+            border(f)?;
+        }
+
+        match self.table.row_count() - shown {
+            0 => Ok(()),
+            1 => writeln!(f, "1 more row not shown"),
+            hidden => writeln!(f, "{hidden} more rows not shown"),
+        }
+    }
+}
+
+impl ResultTable {
+    /// Render this table as a bordered text table. See [`PrettyTable`] for the options and format.
+    pub fn pretty(&self) -> PrettyTable<'_> {
+        PrettyTable::new(self)
+    }
+}
+
+/// Renders the table with [`ResultTable::pretty`]'s defaults: every row, columns up to 40 `char`s wide, and `NULL`
+/// for SQL `NULL`.
+impl fmt::Display for ResultTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.pretty(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result_table::test_data::{make_result_table_from_rows, make_schema};
+
+    fn sample_table() -> ResultTable {
+        let schema = make_schema(vec![
+            (
+                "ID".to_string(),
+                ColumnType::Fixed {
+                    precision: Some(38),
+                    scale: Some(0),
+                },
+                false,
+            ),
+            ("NOTE".to_string(), ColumnType::Text { length: None }, true),
+            (
+                "PAYLOAD".to_string(),
+                ColumnType::Binary { length: None },
+                true,
+            ),
+        ]);
+        make_result_table_from_rows(
+            schema,
+            vec![
+                vec![
+                    Some("1".to_string()),
+                    Some("hello".to_string()),
+                    Some("CAFE".to_string()),
+                ],
+                vec![Some("42".to_string()), None, None],
+                vec![
+                    Some("7".to_string()),
+                    Some("two\nlines".to_string()),
+                    Some("00".to_string()),
+                ],
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn display_aligns_numbers_right_and_escapes_line_breaks() {
+        let table = sample_table();
+
+        assert_eq!(
+            table.to_string(),
+            "\
++----+------------+---------+
+| ID | NOTE       | PAYLOAD |
++----+------------+---------+
+|  1 | hello      | CAFE    |
+| 42 | NULL       | NULL    |
+|  7 | two\\nlines | 00      |
++----+------------+---------+
+"
+        );
+    }
+
+    #[test]
+    fn pretty_truncates_wide_cells_and_limits_rows() {
+        let table = sample_table();
+
+        assert_eq!(
+            table
+                .pretty()
+                .with_max_column_width(4)
+                .with_max_rows(1)
+                .with_null_text("-")
+                .to_string(),
+            "\
++----+------+------+
+| ID | NOTE | PAY… |
++----+------+------+
+|  1 | hel… | CAFE |
++----+------+------+
+2 more rows not shown
+"
+        );
+    }
+
+    #[test]
+    fn empty_table_renders_headers_only() {
+        let schema = make_schema(vec![(
+            "NAME".to_string(),
+            ColumnType::Text { length: None },
+            true,
+        )]);
+        let table = make_result_table_from_rows(schema, Vec::new()).unwrap();
+
+        assert_eq!(table.to_string(), "+------+\n| NAME |\n+------+\n");
+    }
+}
@@ -4,8 +4,9 @@ use crate::{
     Error, Result,
     error::{CellDecodeError, InvalidColumnIndexError, RowDecodeError, SchemaError},
     result_table::{
+        CellConversionError,
         cell::{CellBlock, CellRef},
-        decode::{CellPlan, FromCell, FromRow},
+        decode::{CellPlan, CellRefPlan, FromCell, FromCellRef, FromRow, FromRowRef},
         plan::RowPlanContext,
         schema::{Column, Schema},
         table::{ResultTable, ResultTableStorage},
@@ -17,13 +18,43 @@ use crate::{
 /// `RowRef` is copyable and does not own the underlying result data.
 #[derive(Clone, Copy)]
 pub struct RowRef<'a> {
-    table: &'a ResultTable,
-    block: &'a CellBlock,
+    schema: &'a Schema,
+    cells: RowCells<'a>,
     global_row: usize,
-    local_row: usize,
+}
+
+/// Where the cell text of a [`RowRef`] lives.
+#[derive(Clone, Copy)]
+enum RowCells<'a> {
+    /// A row of a table's cell block.
+    Block {
+        block: &'a CellBlock,
+        local_row: usize,
+    },
+    /// Text held by the caller in schema order, such as the fields of one structured `OBJECT` value.
+    Texts(&'a [Option<&'a str>]),
+}
+
+impl<'a> RowCells<'a> {
+    fn text(self, offset: usize) -> Option<&'a str> {
+        match self {
+            Self::Block { block, local_row } => block.cell_text(block.cell(local_row, offset)),
+            Self::Texts(texts) => texts[offset],
+        }
+    }
 }
 
 impl<'a> RowRef<'a> {
+    /// A row over `texts`, one entry per column of `schema`, reported as row 0.
+    pub(crate) fn from_texts(schema: &'a Schema, texts: &'a [Option<&'a str>]) -> Self {
+        debug_assert_eq!(schema.len(), texts.len());
+        Self {
+            schema,
+            cells: RowCells::Texts(texts),
+            global_row: 0,
+        }
+    }
+
     /// Zero-based index of this row within the result set.
     pub fn row_index(self) -> usize {
         self.global_row
@@ -36,15 +67,11 @@ impl<'a> RowRef<'a> {
     /// Returns [`SchemaError::InvalidColumnIndex`](crate::error::SchemaError::InvalidColumnIndex) when `index` is out
     /// of bounds for this row's schema.
     pub fn cell_at(self, index: usize) -> StdResult<CellRef<'a>, SchemaError> {
-        let column = self.table.schema().column_at(index).ok_or_else(|| {
-            SchemaError::InvalidColumnIndex(InvalidColumnIndexError::new(
-                index,
-                self.table.schema().len(),
-            ))
+        let column = self.schema.column_at(index).ok_or_else(|| {
+            SchemaError::InvalidColumnIndex(InvalidColumnIndexError::new(index, self.schema.len()))
         })?;
 
-        let cell = self.block.cell(self.local_row, index);
-        let raw = self.block.cell_text(cell);
+        let raw = self.cells.text(index);
 
         Ok(CellRef {
             row_index: self.global_row,
@@ -58,8 +85,7 @@ impl<'a> RowRef<'a> {
     pub(crate) fn cell_at_offset(self, column: &'a Column, offset: usize) -> CellRef<'a> {
         debug_assert_eq!(column.index(), offset);
 
-        let cell = self.block.cell(self.local_row, offset);
-        let raw = self.block.cell_text(cell);
+        let raw = self.cells.text(offset);
 
         CellRef {
             row_index: self.global_row,
@@ -74,8 +100,7 @@ impl<'a> RowRef<'a> {
     ///
     /// Returns a [`CellDecodeError`] when the cell cannot be decoded as `T`.
     pub fn get_with_plan<T: FromCell>(self, plan: &CellPlan<T>) -> StdResult<T, CellDecodeError> {
-        let cell = self.block.cell(self.local_row, plan.offset);
-        let raw = self.block.cell_text(cell);
+        let raw = self.cells.text(plan.offset);
 
         T::from_cell_with_plan(raw, &plan.decode_plan).map_err(|issue| {
             CellDecodeError::new(
@@ -90,47 +115,78 @@ impl<'a> RowRef<'a> {
         })
     }
 
+    /// Build the error [`get_with_plan`](Self::get_with_plan) would report for `plan`'s cell, for a failure found
+    /// after the cell itself decoded.
+    pub(crate) fn cell_error_with_plan<T: FromCell>(
+        self,
+        plan: &CellPlan<T>,
+        target_type_name: &'static str,
+        issue: CellConversionError,
+    ) -> CellDecodeError {
+        CellDecodeError::new(
+            self.global_row,
+            plan.column.index(),
+            plan.column.name(),
+            target_type_name,
+            plan.column.ty().clone(),
+            self.cells.text(plan.offset),
+            issue,
+        )
+    }
+
+    /// Decode a cell using a prepared [`CellRefPlan`], borrowing from the table where `T` allows it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CellDecodeError`] when the cell cannot be decoded as `T`.
+    pub fn get_ref_with_plan<T: FromCellRef<'a>>(
+        self,
+        plan: &CellRefPlan<'a, T>,
+    ) -> StdResult<T, CellDecodeError> {
+        let raw = self.cells.text(plan.offset);
+
+        T::from_cell_ref_with_plan(raw, &plan.decode_plan).map_err(|issue| {
+            CellDecodeError::new(
+                self.global_row,
+                plan.column.index(),
+                plan.column.name(),
+                type_name::<T>(),
+                plan.column.ty().clone(),
+                raw,
+                issue,
+            )
+        })
+    }
+
     /// Borrows the schema describing the result-set columns.
     pub fn schema(self) -> &'a Schema {
-        self.table.schema()
+        self.schema
     }
 }
 
-/// Typed iterator over result rows.
-///
-/// Iteration is non-allocating beyond what `T`'s decode requires: the `Arc`-shared schema and decode plan are reused across rows.
-pub struct Rows<'a, T: FromRow> {
+/// Position of the next row to visit, shared by the row iterators.
+struct RowCursor<'a> {
     table: &'a ResultTable,
-    plan: Arc<T::Plan>,
     next: usize,
     chunk_index: usize,
     chunk_local: usize,
-    _phantom: PhantomData<fn() -> T>,
 }
 
-impl<'a, T: FromRow> Rows<'a, T> {
-    pub(crate) fn new(table: &'a ResultTable) -> Result<Self> {
-        let plan = Arc::new(T::build_plan(RowPlanContext::new(table.shared_schema()))?);
-        Ok(Self::from_arc_plan(table, plan))
-    }
-
-    /// Construct from a shared pre-built plan.
-    pub(crate) fn from_arc_plan(table: &'a ResultTable, plan: Arc<T::Plan>) -> Self {
+impl<'a> RowCursor<'a> {
+    fn new(table: &'a ResultTable) -> Self {
         Self {
             table,
-            plan,
             next: 0,
             chunk_index: 0,
             chunk_local: 0,
-            _phantom: PhantomData,
         }
     }
-}
 
-impl<'a, T: FromRow> Iterator for Rows<'a, T> {
-    type Item = Result<T>;
+    fn remaining(&self) -> usize {
+        self.table.row_count().saturating_sub(self.next)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next_row(&mut self) -> Option<RowRef<'a>> {
         if self.next >= self.table.row_count() {
             return None;
         }
@@ -149,34 +205,98 @@ impl<'a, T: FromRow> Iterator for Rows<'a, T> {
         };
 
         let row_ref = RowRef {
-            table: self.table,
-            block,
-            global_row: self.next,
-            local_row: match self.table.storage() {
-                ResultTableStorage::Single(_) => self.next,
-                ResultTableStorage::Chunks(_) => self.chunk_local,
+            schema: self.table.schema(),
+            cells: RowCells::Block {
+                block,
+                local_row: match self.table.storage() {
+                    ResultTableStorage::Single(_) => self.next,
+                    ResultTableStorage::Chunks(_) => self.chunk_local,
+                },
             },
+            global_row: self.next,
         };
         self.next += 1;
         if matches!(self.table.storage(), ResultTableStorage::Chunks(_)) {
             self.chunk_local += 1;
         }
+        Some(row_ref)
+    }
+}
+
+/// Borrowed rows of a table, for internal consumers that inspect cells directly rather than through a row plan.
+pub(crate) struct RowRefs<'a> {
+    cursor: RowCursor<'a>,
+}
+
+impl<'a> RowRefs<'a> {
+    pub(crate) fn new(table: &'a ResultTable) -> Self {
+        Self {
+            cursor: RowCursor::new(table),
+        }
+    }
+}
+
+impl<'a> Iterator for RowRefs<'a> {
+    type Item = RowRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next_row()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cursor.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+/// Add the row index to row-level conversion failures, then convert the decode error into `Error`.
+/// Cell failures already carry row/column context; schema failures need no row enrichment.
+fn row_decode_error(mut error: RowDecodeError, global_row: usize) -> Error {
+    if let RowDecodeError::Conversion(conversion) = &mut error {
+        conversion.set_row_index(global_row);
+    }
+    Error::from(error)
+}
+
+/// Typed iterator over result rows.
+///
+/// Iteration is non-allocating beyond what `T`'s decode requires: the `Arc`-shared schema and decode plan are reused across rows.
+pub struct Rows<'a, T: FromRow> {
+    cursor: RowCursor<'a>,
+    plan: Arc<T::Plan>,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: FromRow> Rows<'a, T> {
+    pub(crate) fn new(table: &'a ResultTable) -> Result<Self> {
+        let plan = Arc::new(T::build_plan(RowPlanContext::new(table.shared_schema()))?);
+        Ok(Self::from_arc_plan(table, plan))
+    }
+
+    /// Construct from a shared pre-built plan.
+    pub(crate) fn from_arc_plan(table: &'a ResultTable, plan: Arc<T::Plan>) -> Self {
+        Self {
+            cursor: RowCursor::new(table),
+            plan,
+            _phantom: PhantomData,
+        }
+    }
+}
 
-        // Add the row index to row-level conversion failures, then convert the decode error into `Error`.
-        // Cell failures already carry row/column context; schema failures need no row enrichment.
+impl<'a, T: FromRow> Iterator for Rows<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_ref = self.cursor.next_row()?;
         let global_row = row_ref.global_row;
         Some(
-            T::from_row_with_plan(row_ref, self.plan.as_ref()).map_err(|mut error| {
-                if let RowDecodeError::Conversion(conversion) = &mut error {
-                    conversion.set_row_index(global_row);
-                }
-                Error::from(error)
-            }),
+            T::from_row_with_plan(row_ref, self.plan.as_ref())
+                .map_err(|error| row_decode_error(error, global_row)),
         )
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.table.row_count().saturating_sub(self.next);
+        let remaining = self.cursor.remaining();
         (remaining, Some(remaining))
     }
 }
@@ -185,6 +305,45 @@ impl<T: FromRow> ExactSizeIterator for Rows<'_, T> {}
 
 impl<T: FromRow> std::iter::FusedIterator for Rows<'_, T> {}
 
+/// Typed iterator over result rows that may borrow from the table, returned by
+/// [`ResultTable::rows_ref`](crate::ResultTable::rows_ref).
+pub struct RowsRef<'a, T: FromRowRef<'a>> {
+    cursor: RowCursor<'a>,
+    plan: T::Plan,
+}
+
+impl<'a, T: FromRowRef<'a>> RowsRef<'a, T> {
+    pub(crate) fn new(table: &'a ResultTable) -> Result<Self> {
+        let plan = T::build_plan(RowPlanContext::new(table.shared_schema()))?;
+        Ok(Self {
+            cursor: RowCursor::new(table),
+            plan,
+        })
+    }
+}
+
+impl<'a, T: FromRowRef<'a>> Iterator for RowsRef<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_ref = self.cursor.next_row()?;
+        let global_row = row_ref.global_row;
+        Some(
+            T::from_row_ref_with_plan(row_ref, &self.plan)
+                .map_err(|error| row_decode_error(error, global_row)),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.cursor.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: FromRowRef<'a>> ExactSizeIterator for RowsRef<'a, T> {}
+
+impl<'a, T: FromRowRef<'a>> std::iter::FusedIterator for RowsRef<'a, T> {}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -265,10 +424,12 @@ mod tests {
             ResultTableStorage::Chunks(_) => panic!("expected single block"),
         };
         let row = RowRef {
-            table: &table,
-            block,
+            schema: table.schema(),
+            cells: RowCells::Block {
+                block,
+                local_row: 0,
+            },
             global_row: 0,
-            local_row: 0,
         };
 
         for (offset, column) in table.schema().columns().iter().enumerate() {
@@ -1,4 +1,9 @@
-use std::{collections::HashMap, fmt, result::Result as StdResult, sync::Arc};
+use std::{
+    collections::HashMap,
+    fmt,
+    result::Result as StdResult,
+    sync::{Arc, OnceLock},
+};
 
 use crate::error::{AmbiguousColumnError, MissingColumnError, SchemaError};
 
@@ -14,8 +19,15 @@ use crate::error::{AmbiguousColumnError, MissingColumnError, SchemaError};
 ///
 /// Driver API query results normally report GEOGRAPHY / GEOMETRY values according to the session's
 /// `GEOGRAPHY_OUTPUT_FORMAT` / `GEOMETRY_OUTPUT_FORMAT`: GeoJSON as [`Object`](ColumnType::Object), WKT/EWKT as
-/// [`Text`](ColumnType::Text), and WKB/EWKB as [`Binary`](ColumnType::Binary). The connector does not parse or validate
-/// the geo payload itself.
+/// [`Text`](ColumnType::Text), and WKB/EWKB as [`Binary`](ColumnType::Binary). The schema does not parse or validate
+/// the geo payload itself; with the `geo` feature, `geo_types::Geometry`, `wkt::Wkt`, and `Wkb` decode any of these
+/// formats, detecting it per cell when the column is reported as `GEOGRAPHY` / `GEOMETRY`.
+///
+/// # Structured types
+///
+/// Structured `ARRAY(T)`, `OBJECT(...)`, and `MAP(K, V)` columns carry their nested types, parsed from the `fields`
+/// metadata Snowflake reports for them. Semi-structured `ARRAY` and `OBJECT` columns report no nested types, so
+/// [`Array::element`](ColumnType::Array::element) and [`Object::fields`](ColumnType::Object::fields) are `None`.
 ///
 /// # Example
 ///
@@ -48,6 +60,8 @@ pub enum ColumnType {
     },
     /// `FLOAT` / `DOUBLE` / `REAL` — IEEE 754 floating-point.
     Real,
+    /// `DECFLOAT` — decimal floating-point with 38 significant digits and an exponent from -16383 to 16384.
+    DecFloat,
     /// `TEXT` / `VARCHAR` / `CHAR` / `STRING` — variable-length string.
     #[non_exhaustive]
     Text {
@@ -85,10 +99,27 @@ pub enum ColumnType {
     },
     /// `VARIANT` — semi-structured value.
     Variant,
-    /// `OBJECT` — semi-structured map.
-    Object,
-    /// `ARRAY` — semi-structured array.
-    Array,
+    /// `OBJECT` — semi-structured map, or a structured `OBJECT(...)` when its fields are reported.
+    #[non_exhaustive]
+    Object {
+        /// Declared fields of a structured `OBJECT(name type, ...)`, in declaration order; `None` for a semi-structured
+        /// `OBJECT`.
+        fields: Option<Box<[Column]>>,
+    },
+    /// `ARRAY` — semi-structured array, or a structured `ARRAY(T)` when its element type is reported.
+    #[non_exhaustive]
+    Array {
+        /// Element type of a structured `ARRAY(T)`; `None` for a semi-structured `ARRAY`.
+        element: Option<Box<ColumnType>>,
+    },
+    /// `MAP(K, V)` — structured map.
+    #[non_exhaustive]
+    Map {
+        /// Key type.
+        key: Box<ColumnType>,
+        /// Value type.
+        value: Box<ColumnType>,
+    },
     /// `BINARY` — byte buffer.
     #[non_exhaustive]
     Binary {
@@ -99,11 +130,17 @@ pub enum ColumnType {
     Geography,
     /// `GEOMETRY`, when reported directly as a logical type.
     Geometry,
-    /// `VECTOR`.
+    /// `VECTOR(INT | FLOAT, n)`.
     ///
-    /// SELECT result metadata reports `VECTOR`, but not the element type or dimension. Use
-    /// [`Vector<i32>`](crate::decode::Vector) / [`Vector<f32>`](crate::decode::Vector) when decoding the expected element type.
-    Vector,
+    /// The element type and dimension are filled in when the result metadata reports them. Decode with
+    /// [`Vector<i32>`](crate::decode::Vector) / [`Vector<f32>`](crate::decode::Vector), which check both when present.
+    #[non_exhaustive]
+    Vector {
+        /// Element type, when reported.
+        element: Option<VectorElementType>,
+        /// Number of elements in every value, when reported.
+        dimension: Option<u32>,
+    },
     /// Type tag the connector did not recognize. The original (lowercased) server-side tag is preserved for diagnostics;
     /// values come through as raw text.
     Unknown {
@@ -112,17 +149,46 @@ pub enum ColumnType {
     },
 }
 
+/// Element type of a [`ColumnType::Vector`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VectorElementType {
+    /// `INT` — 32-bit signed integers.
+    Int,
+    /// `FLOAT` — 32-bit IEEE 754 floats.
+    Float,
+}
+
+impl VectorElementType {
+    /// Returns the lowercase SQL element type name (`int` or `float`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VectorElementType::Int => "int",
+            VectorElementType::Float => "float",
+        }
+    }
+}
+
+impl fmt::Display for VectorElementType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl ColumnType {
     pub(crate) fn from_driver_metadata(
         snowflake_type: &str,
         length: Option<u32>,
         precision: Option<u8>,
         scale: Option<u8>,
+        fields: Vec<Column>,
+        vector_dimension: Option<u32>,
     ) -> Self {
         let lower = snowflake_type.to_ascii_lowercase();
         match lower.as_str() {
             "fixed" => ColumnType::Fixed { precision, scale },
             "real" | "float" | "double" | "double precision" => ColumnType::Real,
+            "decfloat" => ColumnType::DecFloat,
             "text" | "varchar" | "char" | "string" => ColumnType::Text { length },
             "boolean" => ColumnType::Boolean,
             "date" => ColumnType::Date,
@@ -131,12 +197,30 @@ impl ColumnType {
             "timestamp_ltz" => ColumnType::TimestampLtz { scale },
             "timestamp_tz" => ColumnType::TimestampTz { scale },
             "variant" => ColumnType::Variant,
-            "object" => ColumnType::Object,
-            "array" => ColumnType::Array,
+            "object" => ColumnType::Object {
+                fields: (!fields.is_empty()).then(|| fields.into_boxed_slice()),
+            },
+            "array" => ColumnType::Array {
+                element: fields.into_iter().next().map(|field| Box::new(field.ty)),
+            },
+            "map" if fields.len() == 2 => {
+                let mut types = fields.into_iter().map(|field| Box::new(field.ty));
+                ColumnType::Map {
+                    key: types.next().expect("MAP key field"),
+                    value: types.next().expect("MAP value field"),
+                }
+            }
             "binary" => ColumnType::Binary { length },
             "geography" => ColumnType::Geography,
             "geometry" => ColumnType::Geometry,
-            "vector" => ColumnType::Vector,
+            "vector" => ColumnType::Vector {
+                element: fields.first().and_then(|field| match field.ty {
+                    ColumnType::Fixed { .. } => Some(VectorElementType::Int),
+                    ColumnType::Real => Some(VectorElementType::Float),
+                    _ => None,
+                }),
+                dimension: vector_dimension,
+            },
             _ => ColumnType::Unknown {
                 snowflake_type: Box::from(lower),
             },
@@ -148,6 +232,7 @@ impl ColumnType {
         match self {
             ColumnType::Fixed { .. } => "fixed",
             ColumnType::Real => "real",
+            ColumnType::DecFloat => "decfloat",
             ColumnType::Text { .. } => "text",
             ColumnType::Boolean => "boolean",
             ColumnType::Date => "date",
@@ -156,12 +241,13 @@ impl ColumnType {
             ColumnType::TimestampLtz { .. } => "timestamp_ltz",
             ColumnType::TimestampTz { .. } => "timestamp_tz",
             ColumnType::Variant => "variant",
-            ColumnType::Object => "object",
-            ColumnType::Array => "array",
+            ColumnType::Object { .. } => "object",
+            ColumnType::Array { .. } => "array",
+            ColumnType::Map { .. } => "map",
             ColumnType::Binary { .. } => "binary",
             ColumnType::Geography => "geography",
             ColumnType::Geometry => "geometry",
-            ColumnType::Vector => "vector",
+            ColumnType::Vector { .. } => "vector",
             ColumnType::Unknown { snowflake_type } => snowflake_type,
         }
     }
@@ -223,6 +309,30 @@ impl fmt::Display for ColumnType {
             | ColumnType::TimestampNtz { scale: Some(scale) }
             | ColumnType::TimestampLtz { scale: Some(scale) }
             | ColumnType::TimestampTz { scale: Some(scale) } => write!(f, "({scale})"),
+            ColumnType::Array {
+                element: Some(element),
+            } => write!(f, "({element})"),
+            ColumnType::Object {
+                fields: Some(fields),
+            } => {
+                f.write_str("(")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{} {}", field.name(), field.ty())?;
+                }
+                f.write_str(")")
+            }
+            ColumnType::Map { key, value } => write!(f, "({key}, {value})"),
+            ColumnType::Vector {
+                element: Some(element),
+                dimension: Some(dimension),
+            } => write!(f, "({element}, {dimension})"),
+            ColumnType::Vector {
+                element: Some(element),
+                dimension: None,
+            } => write!(f, "({element})"),
             _ => Ok(()),
         }
     }
@@ -256,7 +366,7 @@ impl Column {
     pub fn name(&self) -> &str {
         &self.name
     }
-    /// Zero-based position of this column within the schema.
+    /// Zero-based position of this column within the schema, or of the field within its structured `OBJECT`.
     pub fn index(&self) -> usize {
         self.index
     }
@@ -282,12 +392,10 @@ pub(crate) struct ColumnIndexMap {
 }
 
 impl ColumnIndexMap {
-    fn build(columns: &[Column]) -> Self {
+    fn build(columns: &[Column], key: impl Fn(&Arc<str>) -> Arc<str>) -> Self {
         let mut map: HashMap<Arc<str>, Vec<usize>> = HashMap::with_capacity(columns.len());
         for col in columns {
-            map.entry(Arc::clone(&col.name))
-                .or_default()
-                .push(col.index());
+            map.entry(key(&col.name)).or_default().push(col.index());
         }
 
         let entries = map
@@ -310,21 +418,120 @@ impl ColumnIndexMap {
     }
 }
 
+/// How a column name is matched against the raw labels of a [`Schema`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColumnLookup {
+    /// Match the raw label exactly. This is the default.
+    #[default]
+    Exact,
+    /// Match labels ignoring case, comparing Unicode uppercase forms. Labels that differ only in case are ambiguous.
+    CaseInsensitive,
+    /// Resolve the name as a Snowflake identifier: an unquoted name folds to Unicode uppercase, while a name in
+    /// double quotes (`"name"`, with `""` escaping a quote) matches the label exactly.
+    Identifier,
+}
+
+/// The zone `DateTime<chrono_tz::Tz>` decodes `TIMESTAMP_LTZ` values in, and opted-in renderings show them in,
+/// resolved once per schema from the session `TIMEZONE`.
+///
+/// Without the `chrono-tz` feature nothing is resolved.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct SessionZone {
+    #[cfg(feature = "chrono-tz")]
+    zone: Option<chrono_tz::Tz>,
+}
+
+impl SessionZone {
+    #[cfg_attr(not(feature = "chrono-tz"), allow(unused_variables))]
+    fn resolve(timezone: Option<&str>) -> Self {
+        Self {
+            #[cfg(feature = "chrono-tz")]
+            zone: timezone.and_then(|name| name.parse().ok()),
+        }
+    }
+
+    /// The resolved zone; `None` when the session `TIMEZONE` is absent or not an IANA zone name.
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) fn tz(self) -> Option<chrono_tz::Tz> {
+        self.zone
+    }
+
+    /// RFC 3339 text of a `TIMESTAMP_LTZ` value, in the resolved zone or in UTC when none resolved.
+    pub(crate) fn ltz_rfc3339(self, dt: &chrono::DateTime<chrono::Utc>) -> String {
+        #[cfg(feature = "chrono-tz")]
+        if let Some(zone) = self.zone {
+            return dt.with_timezone(&zone).to_rfc3339();
+        }
+        dt.to_rfc3339()
+    }
+}
+
 /// Ordered metadata describing the columns of a result set.
 ///
-/// Lookups are case-sensitive and match the raw label Snowflake reported.
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// Lookups are case-sensitive and match the raw label Snowflake reported, unless another [`ColumnLookup`] is given.
+#[derive(Clone, Debug)]
 pub struct Schema {
     columns: Box<[Column]>,
     indices: ColumnIndexMap,
+    /// Labels keyed by their Unicode uppercase form, for [`ColumnLookup::CaseInsensitive`]. Built on the first such
+    /// lookup, since most schemas are only ever resolved exactly.
+    folded_indices: OnceLock<ColumnIndexMap>,
+    session_timezone: Option<Arc<str>>,
+    session_zone: SessionZone,
+}
+
+// The folded index is a cache derived from the columns, so whether it was built yet must not affect equality.
+impl PartialEq for Schema {
+    fn eq(&self, other: &Self) -> bool {
+        self.columns == other.columns
+            && self.session_timezone == other.session_timezone
+            && self.session_zone == other.session_zone
+    }
 }
 
+impl Eq for Schema {}
+
 impl Schema {
     pub(crate) fn from_columns(columns: Vec<Column>) -> Self {
-        let indices = ColumnIndexMap::build(&columns);
+        let indices = ColumnIndexMap::build(&columns, Arc::clone);
         Self {
             columns: columns.into_boxed_slice(),
             indices,
+            folded_indices: OnceLock::new(),
+            session_timezone: None,
+            session_zone: SessionZone::default(),
+        }
+    }
+
+    /// Attach the session `TIMEZONE` parameter the result was produced under.
+    pub(crate) fn with_session_timezone(mut self, timezone: Option<Arc<str>>) -> Self {
+        self.session_zone = SessionZone::resolve(timezone.as_deref());
+        self.session_timezone = timezone;
+        self
+    }
+
+    /// The session `TIMEZONE` parameter in effect when the result was produced, as Snowflake reported it (for example
+    /// `America/Los_Angeles`).
+    ///
+    /// Snowflake clients present `TIMESTAMP_LTZ` values in this zone. `None` when Snowflake did not report it.
+    pub fn session_timezone(&self) -> Option<&str> {
+        self.session_timezone.as_deref()
+    }
+
+    /// The zone resolved from [`Schema::session_timezone`].
+    #[cfg(feature = "chrono-tz")]
+    pub(crate) fn session_zone(&self) -> SessionZone {
+        self.session_zone
+    }
+
+    /// The zone `TIMESTAMP_LTZ` values are rendered in: the session zone when `in_session_zone` is set, otherwise none,
+    /// which renders them in UTC.
+    pub(crate) fn ltz_zone(&self, in_session_zone: bool) -> SessionZone {
+        if in_session_zone {
+            self.session_zone
+        } else {
+            SessionZone::default()
         }
     }
 
@@ -357,7 +564,36 @@ impl Schema {
     /// - [`SchemaError::MissingColumn`] when no column carries the name.
     /// - [`SchemaError::AmbiguousColumn`] when several columns share it.
     pub fn column_index(&self, name: &str) -> StdResult<usize, SchemaError> {
-        match self.indices.get(name) {
+        self.column_index_with(name, ColumnLookup::Exact)
+    }
+
+    /// Resolve a column name to its zero-based index using the given [`ColumnLookup`] mode.
+    ///
+    /// Errors report `name` as given, before any case folding or unquoting.
+    ///
+    /// # Errors
+    ///
+    /// - [`SchemaError::MissingColumn`] when no column matches the name.
+    /// - [`SchemaError::AmbiguousColumn`] when several columns match it.
+    pub fn column_index_with(
+        &self,
+        name: &str,
+        lookup: ColumnLookup,
+    ) -> StdResult<usize, SchemaError> {
+        let entry = match lookup {
+            ColumnLookup::Exact => self.indices.get(name),
+            ColumnLookup::CaseInsensitive => self
+                .folded_indices
+                .get_or_init(|| {
+                    ColumnIndexMap::build(&self.columns, |name| Arc::from(name.to_uppercase()))
+                })
+                .get(&name.to_uppercase()),
+            ColumnLookup::Identifier => match unquote_identifier(name) {
+                Some(quoted) => self.indices.get(&quoted),
+                None => self.indices.get(&name.to_uppercase()),
+            },
+        };
+        match entry {
             Some(LookupEntry::Unique(idx)) => Ok(*idx),
             Some(LookupEntry::Ambiguous(candidates)) => Err(SchemaError::AmbiguousColumn(
                 AmbiguousColumnError::new(name, candidates.clone()),
@@ -365,6 +601,28 @@ impl Schema {
             None => Err(SchemaError::MissingColumn(MissingColumnError::new(name))),
         }
     }
+
+    /// The first raw label shared by more than one column, or `None` when every label is unique.
+    pub(crate) fn duplicate_label(&self) -> Option<&str> {
+        if self.indices.map.len() == self.columns.len() {
+            return None;
+        }
+        self.columns
+            .iter()
+            .find(|col| {
+                matches!(
+                    self.indices.get(col.name()),
+                    Some(LookupEntry::Ambiguous(_))
+                )
+            })
+            .map(Column::name)
+    }
+}
+
+/// The contents of a double-quoted identifier with `""` unescaped, or `None` when `name` is not quoted.
+fn unquote_identifier(name: &str) -> Option<String> {
+    let inner = name.strip_prefix('"')?.strip_suffix('"')?;
+    Some(inner.replace("\"\"", "\""))
 }
 
 #[cfg(test)]
@@ -433,20 +691,173 @@ mod tests {
         }
     }
 
+    fn text_schema(labels: &[&str]) -> Schema {
+        Schema::from_columns(
+            labels
+                .iter()
+                .enumerate()
+                .map(|(index, label)| {
+                    Column::new(*label, index, true, ColumnType::Text { length: None })
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn case_insensitive_lookup_folds_unicode_and_reports_collisions() {
+        let schema = text_schema(&["Straße", "id", "ID"]);
+        assert!(schema.column_index_with("id", ColumnLookup::Exact).is_ok());
+        assert!(schema.folded_indices.get().is_none());
+        assert_eq!(
+            schema
+                .column_index_with("STRASSE", ColumnLookup::CaseInsensitive)
+                .unwrap(),
+            0
+        );
+        match schema.column_index_with("Id", ColumnLookup::CaseInsensitive) {
+            Err(SchemaError::AmbiguousColumn(error)) => {
+                assert_eq!(error.name(), "Id");
+                assert_eq!(error.candidates(), &[1, 2]);
+            }
+            other => panic!("expected case-folded ambiguity, got {other:?}"),
+        }
+        assert!(schema.folded_indices.get().is_some());
+        assert_eq!(schema, text_schema(&["Straße", "id", "ID"]));
+    }
+
+    #[test]
+    fn identifier_lookup_folds_unquoted_names_and_keeps_quoted_names() {
+        let schema = text_schema(&["NAME", "name", "Say \"hi\"", "ÉTAT"]);
+        let index = |name| schema.column_index_with(name, ColumnLookup::Identifier);
+
+        assert_eq!(index("name").unwrap(), 0);
+        assert_eq!(index("\"name\"").unwrap(), 1);
+        assert_eq!(index("\"Say \"\"hi\"\"\"").unwrap(), 2);
+        assert_eq!(index("état").unwrap(), 3);
+        assert!(matches!(
+            index("\"Name\""),
+            Err(SchemaError::MissingColumn(error)) if error.name() == "\"Name\""
+        ));
+    }
+
     #[test]
     fn column_type_from_driver_metadata() {
         assert_eq!(
-            ColumnType::from_driver_metadata("FIXED", None, Some(38), Some(0)),
+            ColumnType::from_driver_metadata("FIXED", None, Some(38), Some(0), Vec::new(), None),
             ColumnType::Fixed {
                 precision: Some(38),
                 scale: Some(0),
             }
         );
         assert_eq!(
-            ColumnType::from_driver_metadata("text", Some(255), None, None),
+            ColumnType::from_driver_metadata("text", Some(255), None, None, Vec::new(), None),
             ColumnType::Text { length: Some(255) }
         );
-        let unknown = ColumnType::from_driver_metadata("Frob", None, None, None);
+        assert_eq!(
+            ColumnType::from_driver_metadata("DECFLOAT", None, Some(38), None, Vec::new(), None),
+            ColumnType::DecFloat
+        );
+        let unknown = ColumnType::from_driver_metadata("Frob", None, None, None, Vec::new(), None);
         assert!(matches!(unknown, ColumnType::Unknown { .. }));
     }
+
+    #[test]
+    fn column_type_from_structured_metadata() {
+        let date = || Column::new("", 0, true, ColumnType::Date);
+        let text =
+            |name: &str, index| Column::new(name, index, true, ColumnType::Text { length: None });
+
+        let array = ColumnType::from_driver_metadata("array", None, None, None, vec![date()], None);
+        assert_eq!(
+            array,
+            ColumnType::Array {
+                element: Some(Box::new(ColumnType::Date)),
+            }
+        );
+        assert_eq!(array.to_string(), "array(date)");
+
+        let object = ColumnType::from_driver_metadata(
+            "object",
+            None,
+            None,
+            None,
+            vec![
+                text("CITY", 0),
+                Column::new("SINCE", 1, false, ColumnType::Date),
+            ],
+            None,
+        );
+        assert_eq!(object.to_string(), "object(CITY text, SINCE date)");
+
+        let map = ColumnType::from_driver_metadata(
+            "map",
+            None,
+            None,
+            None,
+            vec![text("", 0), date()],
+            None,
+        );
+        assert_eq!(
+            map,
+            ColumnType::Map {
+                key: Box::new(ColumnType::Text { length: None }),
+                value: Box::new(ColumnType::Date),
+            }
+        );
+        assert_eq!(map.to_string(), "map(text, date)");
+
+        assert_eq!(
+            ColumnType::from_driver_metadata("ARRAY", None, None, None, Vec::new(), None),
+            ColumnType::Array { element: None }
+        );
+        assert_eq!(
+            ColumnType::from_driver_metadata("object", None, None, None, Vec::new(), None),
+            ColumnType::Object { fields: None }
+        );
+    }
+
+    #[test]
+    fn column_type_from_vector_metadata() {
+        let element = |ty| vec![Column::new("", 0, false, ty)];
+
+        let vector = ColumnType::from_driver_metadata(
+            "vector",
+            None,
+            None,
+            None,
+            element(ColumnType::Real),
+            Some(768),
+        );
+        assert_eq!(
+            vector,
+            ColumnType::Vector {
+                element: Some(VectorElementType::Float),
+                dimension: Some(768),
+            }
+        );
+        assert_eq!(vector.to_string(), "vector(float, 768)");
+
+        let vector = ColumnType::from_driver_metadata(
+            "VECTOR",
+            None,
+            None,
+            None,
+            element(ColumnType::Fixed {
+                precision: Some(38),
+                scale: Some(0),
+            }),
+            None,
+        );
+        assert_eq!(vector.to_string(), "vector(int)");
+
+        let bare = ColumnType::from_driver_metadata("vector", None, None, None, Vec::new(), None);
+        assert_eq!(
+            bare,
+            ColumnType::Vector {
+                element: None,
+                dimension: None,
+            }
+        );
+        assert_eq!(bare.to_string(), "vector");
+    }
 }
@@ -0,0 +1,496 @@
+//! `serde::Serialize` support for dynamically decoded results.
+//!
+//! The default impls render values exactly like [`CellValue::into_json_value`] and
+//! [`DynamicRow::into_json_object`], but stream into the serializer instead of building a `serde_json::Value` per row.
+//! [`SerializeOptions`] adjusts how decimals, binary, non-finite floats, and `TIMESTAMP_LTZ` values are rendered.
+
+use base64::Engine as _;
+use serde::{
+    Serialize, Serializer,
+    ser::{Error as _, SerializeMap, SerializeSeq},
+};
+
+use crate::{
+    error::{DuplicateColumnNameError, SchemaError},
+    result_table::{
+        dynamic::{CellValue, DynamicRow, VectorValue},
+        schema::{Schema, SessionZone},
+        table::ResultTable,
+    },
+};
+
+/// How [`CellValue::Decimal`] and [`CellValue::DecFloat`] values are serialized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecimalFormat {
+    /// The exact decimal text Snowflake delivered, as a string. This is the default.
+    #[default]
+    String,
+    /// A number parsed to `f64`. Values with more than 15 significant digits may lose precision, and `DECFLOAT`
+    /// values beyond `f64`'s range stay strings.
+    Number,
+}
+
+/// How [`CellValue::Binary`] values are serialized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BinaryFormat {
+    /// Standard base64 with padding. This is the default.
+    #[default]
+    Base64,
+    /// Uppercase hex, matching Snowflake's default `BINARY_OUTPUT_FORMAT`.
+    Hex,
+}
+
+/// How NaN and infinite floats, which JSON cannot represent as numbers, are serialized.
+///
+/// Applies to [`CellValue::Float`] and to the elements of a float [`VectorValue`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NonFiniteFloatFormat {
+    /// A string token. This is the default. `FLOAT` cells render as `"NaN"`, `"inf"`, and `"-inf"`, and `VECTOR`
+    /// elements use Snowflake's lowercase wire tokens `"nan"`, `"inf"`, and `"-inf"`.
+    #[default]
+    String,
+    /// `null`.
+    Null,
+}
+
+/// Options for serializing [`CellValue`], [`DynamicRow`], and [`ResultTable`] with non-default renderings.
+///
+/// The plain [`Serialize`] impls use `SerializeOptions::default()`.
+///
+/// # Example
+///
+/// ```
+/// use snowflake_connector_rs::{BinaryFormat, DecimalFormat, SerializeOptions};
+///
+/// let options = SerializeOptions::new()
+///     .with_decimal_format(DecimalFormat::Number)
+///     .with_binary_format(BinaryFormat::Hex);
+/// assert_eq!(options.decimal_format(), DecimalFormat::Number);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SerializeOptions {
+    decimal: DecimalFormat,
+    binary: BinaryFormat,
+    non_finite_float: NonFiniteFloatFormat,
+    ltz_in_session_timezone: bool,
+}
+
+impl SerializeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how decimals are rendered. Defaults to [`DecimalFormat::String`].
+    pub fn with_decimal_format(mut self, format: DecimalFormat) -> Self {
+        self.decimal = format;
+        self
+    }
+
+    /// Sets how binary values are rendered. Defaults to [`BinaryFormat::Base64`].
+    pub fn with_binary_format(mut self, format: BinaryFormat) -> Self {
+        self.binary = format;
+        self
+    }
+
+    /// Sets how NaN and infinite floats are rendered. Defaults to [`NonFiniteFloatFormat::String`].
+    pub fn with_non_finite_float_format(mut self, format: NonFiniteFloatFormat) -> Self {
+        self.non_finite_float = format;
+        self
+    }
+
+    /// Sets whether rows and tables render [`CellValue::TimestampLtz`] in the session time zone
+    /// ([`Schema::session_timezone`]) instead of UTC, matching the wall-clock time Snowflake shows. Values stay in UTC
+    /// when the session `TIMEZONE` is absent or not an IANA zone name, and a lone [`CellValue`], which carries no
+    /// schema, always renders in UTC. Defaults to `false`.
+    #[cfg(feature = "chrono-tz")]
+    pub fn with_ltz_in_session_timezone(mut self, enabled: bool) -> Self {
+        self.ltz_in_session_timezone = enabled;
+        self
+    }
+
+    pub fn decimal_format(&self) -> DecimalFormat {
+        self.decimal
+    }
+
+    pub fn binary_format(&self) -> BinaryFormat {
+        self.binary
+    }
+
+    pub fn non_finite_float_format(&self) -> NonFiniteFloatFormat {
+        self.non_finite_float
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    pub fn ltz_in_session_timezone(&self) -> bool {
+        self.ltz_in_session_timezone
+    }
+}
+
+/// Pairs a value with the options it is serialized under and the zone its `TIMESTAMP_LTZ` values render in.
+struct WithOptions<'a, T: ?Sized> {
+    value: &'a T,
+    options: &'a SerializeOptions,
+    zone: SessionZone,
+}
+
+impl CellValue {
+    /// Borrow this value as a [`Serialize`] implementation that renders it under `options`.
+    pub fn serialize_with<'a>(&'a self, options: &'a SerializeOptions) -> impl Serialize + 'a {
+        WithOptions {
+            value: self,
+            options,
+            zone: SessionZone::default(),
+        }
+    }
+}
+
+impl DynamicRow {
+    /// Borrow this row as a [`Serialize`] implementation that renders its values under `options`.
+    ///
+    /// The row serializes as a map keyed by raw column label; serialization fails when two columns share a label.
+    pub fn serialize_with<'a>(&'a self, options: &'a SerializeOptions) -> impl Serialize + 'a {
+        WithOptions {
+            value: self,
+            options,
+            zone: self.schema().ltz_zone(options.ltz_in_session_timezone),
+        }
+    }
+}
+
+impl ResultTable {
+    /// Borrow this table as a [`Serialize`] implementation that renders every row under `options`.
+    ///
+    /// The table serializes as a sequence of row maps. Rows are decoded one at a time while serializing, so a decode
+    /// failure or a duplicated column label fails serialization.
+    pub fn serialize_with<'a>(&'a self, options: &'a SerializeOptions) -> impl Serialize + 'a {
+        WithOptions {
+            value: self,
+            options,
+            zone: self.schema().ltz_zone(options.ltz_in_session_timezone),
+        }
+    }
+}
+
+/// Serializes under [`SerializeOptions::default`]; see [`CellValue::into_json_value`] for the rendering of each variant.
+impl Serialize for CellValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_with(&SerializeOptions::default())
+            .serialize(serializer)
+    }
+}
+
+/// Serializes as a map keyed by raw column label under [`SerializeOptions::default`].
+impl Serialize for DynamicRow {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_with(&SerializeOptions::default())
+            .serialize(serializer)
+    }
+}
+
+/// Serializes as a sequence of row maps under [`SerializeOptions::default`].
+impl Serialize for ResultTable {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_with(&SerializeOptions::default())
+            .serialize(serializer)
+    }
+}
+
+impl Serialize for WithOptions<'_, CellValue> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let options = self.options;
+        match self.value {
+            CellValue::Null => serializer.serialize_unit(),
+            CellValue::Boolean(b) => serializer.serialize_bool(*b),
+            CellValue::Integer(i) => {
+                if let Ok(value) = i64::try_from(*i) {
+                    serializer.serialize_i64(value)
+                } else if let Ok(value) = u64::try_from(*i) {
+                    serializer.serialize_u64(value)
+                } else {
+                    serializer.collect_str(i)
+                }
+            }
+            CellValue::Float(f) => {
+                // `f64`'s `Display` spelling, which `into_json_value` falls back to.
+                let token = if f.is_nan() {
+                    "NaN"
+                } else if f.is_sign_positive() {
+                    "inf"
+                } else {
+                    "-inf"
+                };
+                serialize_float(*f, token, options, serializer)
+            }
+            CellValue::Decimal(d) | CellValue::DecFloat(d) => match options.decimal {
+                DecimalFormat::String => serializer.serialize_str(d.raw()),
+                DecimalFormat::Number => match d.raw().parse::<f64>() {
+                    Ok(value) if value.is_finite() => serializer.serialize_f64(value),
+                    _ => serializer.serialize_str(d.raw()),
+                },
+            },
+            CellValue::String(s) => serializer.serialize_str(s),
+            CellValue::Date(d) => serializer.collect_str(&d.format("%Y-%m-%d")),
+            CellValue::Time(t) => serializer.collect_str(&t.format("%H:%M:%S%.f")),
+            CellValue::TimestampNtz(dt) => {
+                serializer.collect_str(&dt.format("%Y-%m-%dT%H:%M:%S%.f"))
+            }
+            CellValue::TimestampLtz(dt) => serializer.serialize_str(&self.zone.ltz_rfc3339(dt)),
+            CellValue::TimestampTz(dt) => serializer.serialize_str(&dt.to_rfc3339()),
+            CellValue::Json(value) => value.serialize(serializer),
+            CellValue::Binary(bytes) => {
+                serializer.serialize_str(&encode_binary(bytes.as_bytes(), options.binary))
+            }
+            CellValue::Vector(VectorValue::Int(vector)) => {
+                serializer.collect_seq(vector.as_slice())
+            }
+            CellValue::Vector(VectorValue::Float(vector)) => {
+                let mut seq = serializer.serialize_seq(Some(vector.as_slice().len()))?;
+                for element in vector.as_slice() {
+                    seq.serialize_element(&VectorElement {
+                        value: *element,
+                        options,
+                    })?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+impl Serialize for WithOptions<'_, DynamicRow> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let schema = self.value.schema();
+        check_unique_labels(schema).map_err(S::Error::custom)?;
+
+        let mut map = serializer.serialize_map(Some(schema.len()))?;
+        for (col, value) in schema.columns().iter().zip(self.value.values()) {
+            let value = WithOptions {
+                value,
+                options: self.options,
+                zone: self.zone,
+            };
+            map.serialize_entry(col.name(), &value)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for WithOptions<'_, ResultTable> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        check_unique_labels(self.value.schema()).map_err(S::Error::custom)?;
+
+        let mut seq = serializer.serialize_seq(Some(self.value.row_count()))?;
+        for row in self.value.dynamic_rows().map_err(S::Error::custom)? {
+            let row = row.map_err(S::Error::custom)?;
+            seq.serialize_element(&row.serialize_with(self.options))?;
+        }
+        seq.end()
+    }
+}
+
+struct VectorElement<'a> {
+    value: f32,
+    options: &'a SerializeOptions,
+}
+
+impl Serialize for VectorElement<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let token = if self.value.is_nan() {
+            "nan"
+        } else if self.value.is_sign_positive() {
+            "inf"
+        } else {
+            "-inf"
+        };
+        serialize_float(f64::from(self.value), token, self.options, serializer)
+    }
+}
+
+fn serialize_float<S: Serializer>(
+    value: f64,
+    non_finite_token: &str,
+    options: &SerializeOptions,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if value.is_finite() {
+        return serializer.serialize_f64(value);
+    }
+    match options.non_finite_float {
+        NonFiniteFloatFormat::String => serializer.serialize_str(non_finite_token),
+        NonFiniteFloatFormat::Null => serializer.serialize_unit(),
+    }
+}
+
+/// Rows serialize as maps, so a label shared by two columns would produce duplicate keys.
+pub(crate) fn check_unique_labels(schema: &Schema) -> Result<(), SchemaError> {
+    match schema.duplicate_label() {
+        Some(label) => Err(SchemaError::DuplicateColumnName(
+            DuplicateColumnNameError::new(label),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Render binary bytes as text in the given format.
+pub(crate) fn encode_binary(bytes: &[u8], format: BinaryFormat) -> String {
+    match format {
+        BinaryFormat::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+        BinaryFormat::Hex => {
+            const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+            let mut out = String::with_capacity(bytes.len() * 2);
+            for byte in bytes {
+                out.push(char::from(DIGITS[usize::from(byte >> 4)]));
+                out.push(char::from(DIGITS[usize::from(byte & 0x0f)]));
+            }
+            out
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        ErrorKind,
+        result_table::{
+            ColumnType,
+            dynamic::DecimalValue,
+            test_data::{make_result_table_from_rows, make_schema},
+        },
+    };
+
+    fn mixed_table() -> ResultTable {
+        let schema = make_schema(vec![
+            (
+                "ID".to_string(),
+                ColumnType::Fixed {
+                    precision: None,
+                    scale: Some(0),
+                },
+                false,
+            ),
+            (
+                "PRICE".to_string(),
+                ColumnType::Fixed {
+                    precision: Some(10),
+                    scale: Some(2),
+                },
+                true,
+            ),
+            ("RATIO".to_string(), ColumnType::Real, true),
+            ("DAY".to_string(), ColumnType::Date, true),
+            ("ATTRS".to_string(), ColumnType::Variant, true),
+            (
+                "PAYLOAD".to_string(),
+                ColumnType::Binary { length: None },
+                true,
+            ),
+            (
+                "EMBEDDING".to_string(),
+                ColumnType::Vector {
+                    element: None,
+                    dimension: None,
+                },
+                true,
+            ),
+        ]);
+        make_result_table_from_rows(
+            schema,
+            vec![
+                vec![
+                    Some("170141183460469231731687303715884105727".to_string()),
+                    Some("12.50".to_string()),
+                    Some("NaN".to_string()),
+                    Some("19000".to_string()),
+                    Some(r#"{"tags":["a"]}"#.to_string()),
+                    Some("CAFE".to_string()),
+                    Some("[1.5,inf]".to_string()),
+                ],
+                vec![
+                    Some("2".to_string()),
+                    None,
+                    Some("0.25".to_string()),
+                    None,
+                    None,
+                    None,
+                    None,
+                ],
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn default_serialization_matches_into_json_object() {
+        let table = mixed_table();
+        for row in table.dynamic_rows().unwrap() {
+            let row = row.unwrap();
+            let streamed = serde_json::to_value(&row).unwrap();
+            let materialized = serde_json::Value::Object(row.into_json_object().unwrap());
+            assert_eq!(streamed, materialized);
+        }
+
+        let rows = serde_json::to_value(&table).unwrap();
+        assert_eq!(rows.as_array().map(Vec::len), Some(2));
+        assert_eq!(rows[0]["ID"], "170141183460469231731687303715884105727");
+        assert_eq!(rows[0]["RATIO"], "NaN");
+        assert_eq!(rows[0]["EMBEDDING"], json!([1.5, "inf"]));
+        assert_eq!(rows[1]["PRICE"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn options_control_decimal_binary_and_non_finite_rendering() {
+        let table = mixed_table();
+        let options = SerializeOptions::new()
+            .with_decimal_format(DecimalFormat::Number)
+            .with_binary_format(BinaryFormat::Hex)
+            .with_non_finite_float_format(NonFiniteFloatFormat::Null);
+
+        let rows = serde_json::to_value(table.serialize_with(&options)).unwrap();
+        assert_eq!(rows[0]["PRICE"], json!(12.5));
+        assert_eq!(rows[0]["PAYLOAD"], "CAFE");
+        assert_eq!(rows[0]["RATIO"], serde_json::Value::Null);
+        assert_eq!(rows[0]["EMBEDDING"], json!([1.5, null]));
+        assert_eq!(rows[1]["RATIO"], json!(0.25));
+
+        let decfloat = CellValue::DecFloat(DecimalValue::new("1.5e400"));
+        assert_eq!(
+            serde_json::to_value(decfloat.serialize_with(&options)).unwrap(),
+            "1.5e400"
+        );
+
+        let cell = CellValue::Float(f64::NEG_INFINITY);
+        assert_eq!(serde_json::to_string(&cell).unwrap(), r#""-inf""#);
+        assert_eq!(
+            serde_json::to_string(&cell.serialize_with(&options)).unwrap(),
+            "null"
+        );
+    }
+
+    #[test]
+    fn duplicate_labels_fail_row_and_table_serialization() {
+        let schema = make_schema(vec![
+            ("A".to_string(), ColumnType::Text { length: None }, true),
+            ("A".to_string(), ColumnType::Text { length: None }, true),
+        ]);
+        let table = make_result_table_from_rows(
+            schema,
+            vec![vec![Some("x".to_string()), Some("y".to_string())]],
+        )
+        .unwrap();
+
+        let err = serde_json::to_string(&table).unwrap_err();
+        assert!(err.to_string().contains("A"), "{err}");
+
+        let row = table.dynamic_rows().unwrap().next().unwrap().unwrap();
+        assert!(serde_json::to_string(&row).is_err());
+
+        let schema_err = check_unique_labels(table.schema()).unwrap_err();
+        assert_eq!(crate::Error::from(schema_err).kind(), ErrorKind::Decode);
+    }
+}
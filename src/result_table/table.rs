@@ -1,14 +1,16 @@
 use std::{result::Result as StdResult, sync::Arc};
 
 use bytes::Bytes;
+use serde::de::DeserializeOwned;
 
 use crate::{
     Result,
     error::RowsetParseError,
     result_table::{
-        DynamicRow, FromRow,
+        DynamicRow, FromRow, FromRowRef,
         cell::{Cell, CellBlock, RawSpan, StringArenaBuilder},
-        row::Rows,
+        deserialize::DeserializeRows,
+        row::{Rows, RowsRef},
         schema::Schema,
     },
 };
@@ -72,6 +74,30 @@ impl ResultTable {
         Rows::new(self)
     }
 
+    /// Iterate the table as typed rows of `T` that may borrow from the table's storage.
+    ///
+    /// Use this for row types with a lifetime, such as `#[derive(FromRow)] struct Row<'a> { name: &'a str }` or a
+    /// tuple like `(&str, i64)`, to decode text cells without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when building the row decode plan fails.
+    pub fn rows_ref<'a, T: FromRowRef<'a>>(&'a self) -> Result<RowsRef<'a, T>> {
+        RowsRef::new(self)
+    }
+
+    /// Iterate the table as rows deserialized through `T`'s [`serde::Deserialize`] implementation.
+    ///
+    /// Each row is presented to `T` as a map from raw column label to cell value. See [`Serde`](crate::decode::Serde) for how column types
+    /// map onto the serde data model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when building the row decode plan fails.
+    pub fn deserialize_rows<T: DeserializeOwned>(&self) -> Result<DeserializeRows<'_, T>> {
+        DeserializeRows::new(self)
+    }
+
     /// Iterate the table as [`DynamicRow`] values.
     ///
     /// Iterate the table as dynamically typed rows.
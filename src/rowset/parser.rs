@@ -4,19 +4,20 @@
 //! Strings without escapes are kept as byte spans into the retained `Bytes` buffer; strings with escapes are unescaped once
 //! into a per-table arena.
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use bytes::Bytes;
 
 use super::{
     ParseWorkload, decode_gzip_chunk,
     json_string::{self, JsonStringFragment, JsonStringScanError},
-    workload::{ParseWorkError, execute_parse_work},
+    workload::{ParseExecution, ParseWorkError, execute_parse_work},
 };
 use crate::{
     error::{
         InternalError, QueryScopedError, QueryScopedRepr, QueryScopedResult, RowsetParseError,
     },
+    observer::{ObserverHandle, ParseEvent},
     result_table::{RawSpan, ResultTable, ResultTableBuilder, Schema},
     runtime::BlockingParseLimiter,
 };
@@ -436,6 +437,7 @@ pub(crate) async fn parse_inline_result_table_async(
     body: Bytes,
     row_count: Option<u64>,
     blocking_parse_limiter: Option<BlockingParseLimiter>,
+    observer: &ObserverHandle,
 ) -> QueryScopedResult<ResultTable> {
     let workload = ParseWorkload::inline_rowset(body.len(), row_count, schema.len());
     let row_count_hint = match checked_row_count_hint(row_count) {
@@ -446,19 +448,25 @@ pub(crate) async fn parse_inline_result_table_async(
     };
     let query_id_for_work = Arc::clone(&query_id);
 
-    let parse_work_result = execute_parse_work(workload, blocking_parse_limiter, move || {
-        parse_table_with_shape(
-            schema,
-            query_id_for_work,
-            body,
-            RowsetShape::InlineArray,
-            row_count_hint,
-        )
-    })
+    let parse_work_result = execute_observed_parse_work(
+        &query_id,
+        workload,
+        blocking_parse_limiter,
+        observer,
+        move || {
+            parse_table_with_shape(
+                schema,
+                query_id_for_work,
+                body,
+                RowsetShape::InlineArray,
+                row_count_hint,
+            )
+        },
+    )
     .await;
 
     let table = match parse_work_result {
-        Ok((_, table)) => table,
+        Ok(table) => table,
         Err(ParseWorkError::Join(error)) => {
             return Err(QueryScopedError::new(
                 query_id,
@@ -493,6 +501,7 @@ pub(crate) async fn parse_remote_chunk_result_table_async(
     body: Bytes,
     workload: ParseWorkload,
     blocking_parse_limiter: Option<BlockingParseLimiter>,
+    observer: &ObserverHandle,
 ) -> QueryScopedResult<ResultTable> {
     let row_count_hint = match checked_row_count_hint(workload.row_count) {
         Ok(hint) => hint,
@@ -503,22 +512,28 @@ pub(crate) async fn parse_remote_chunk_result_table_async(
     let query_id_for_work = Arc::clone(&query_id);
     let expected_uncompressed_size = workload.uncompressed_bytes;
 
-    let parse_work_result = execute_parse_work(workload, blocking_parse_limiter, move || {
-        let bytes =
-            decode_gzip_chunk(body, expected_uncompressed_size).map_err(QueryScopedRepr::from)?;
-        parse_table_with_shape(
-            schema,
-            query_id_for_work,
-            bytes,
-            RowsetShape::ChunkFragmentSequence,
-            row_count_hint,
-        )
-        .map_err(QueryScopedRepr::from)
-    })
+    let parse_work_result = execute_observed_parse_work(
+        &query_id,
+        workload,
+        blocking_parse_limiter,
+        observer,
+        move || {
+            let bytes = decode_gzip_chunk(body, expected_uncompressed_size)
+                .map_err(QueryScopedRepr::from)?;
+            parse_table_with_shape(
+                schema,
+                query_id_for_work,
+                bytes,
+                RowsetShape::ChunkFragmentSequence,
+                row_count_hint,
+            )
+            .map_err(QueryScopedRepr::from)
+        },
+    )
     .await;
 
     let table = match parse_work_result {
-        Ok((_, table)) => table,
+        Ok(table) => table,
         Err(ParseWorkError::Join(error)) => {
             return Err(QueryScopedError::new(
                 query_id,
@@ -531,6 +546,39 @@ pub(crate) async fn parse_remote_chunk_result_table_async(
     Ok(table)
 }
 
+/// Run `parse` through [`execute_parse_work`] and report its duration to `observer`.
+///
+/// The clock starts inside the work closure, so time spent waiting for a blocking-parse permit is not counted.
+async fn execute_observed_parse_work<E, F>(
+    query_id: &Arc<str>,
+    workload: ParseWorkload,
+    blocking_parse_limiter: Option<BlockingParseLimiter>,
+    observer: &ObserverHandle,
+    parse: F,
+) -> Result<ResultTable, ParseWorkError<E>>
+where
+    E: Send + 'static,
+    F: FnOnce() -> Result<ResultTable, E> + Send + 'static,
+{
+    let input_bytes = workload.input_bytes;
+    let (execution, (table, elapsed)) =
+        execute_parse_work(workload, blocking_parse_limiter, move || {
+            let started_at = Instant::now();
+            parse().map(|table| (table, started_at.elapsed()))
+        })
+        .await?;
+    observer.notify(|observer| {
+        observer.on_parse(&ParseEvent {
+            query_id: Arc::clone(query_id),
+            input_bytes,
+            row_count: table.row_count(),
+            elapsed,
+            blocking: execution == ParseExecution::SpawnBlocking,
+        })
+    });
+    Ok(table)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -630,9 +678,16 @@ mod tests {
         let body = Bytes::from(r#"["a","b"],["c","d"]"#);
         let s = schema(2);
         let workload = ParseWorkload::inline_rowset(body.len(), None, s.len());
-        let t = parse_remote_chunk_result_table_async(s, qid(), body, workload, None)
-            .await
-            .unwrap();
+        let t = parse_remote_chunk_result_table_async(
+            s,
+            qid(),
+            body,
+            workload,
+            None,
+            &ObserverHandle::default(),
+        )
+        .await
+        .unwrap();
         assert_eq!(t.row_count(), 2);
     }
 
@@ -821,9 +876,16 @@ mod tests {
     async fn parse_remote_chunk_result_table_allows_empty_body() {
         let s = schema(2);
         let workload = ParseWorkload::remote_chunk(0, 0, s.len(), false, 0, 0);
-        let t = parse_remote_chunk_result_table_async(s, qid(), Bytes::new(), workload, None)
-            .await
-            .unwrap();
+        let t = parse_remote_chunk_result_table_async(
+            s,
+            qid(),
+            Bytes::new(),
+            workload,
+            None,
+            &ObserverHandle::default(),
+        )
+        .await
+        .unwrap();
         assert_eq!(t.row_count(), 0);
     }
 
@@ -79,6 +79,20 @@ pub(crate) enum ParseWorkError<E> {
     Work(E),
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "snowflake.parse",
+        level = "debug",
+        skip_all,
+        fields(
+            input_bytes = workload.input_bytes,
+            rows = workload.row_count,
+            columns = workload.column_count,
+            execution = tracing::field::Empty,
+        )
+    )
+)]
 pub(crate) async fn execute_parse_work<T, E, F>(
     workload: ParseWorkload,
     blocking_parse_limiter: Option<BlockingParseLimiter>,
@@ -95,7 +109,13 @@ where
             None => None,
         };
 
+        #[cfg(feature = "tracing")]
+        let span = tracing::Span::current();
+        #[cfg(feature = "tracing")]
+        span.record("execution", "spawn_blocking");
         let result = tokio::task::spawn_blocking(move || {
+            #[cfg(feature = "tracing")]
+            let _entered = span.enter();
             let _permit = permit;
             work()
         })
@@ -107,6 +127,8 @@ where
             result.map_err(ParseWorkError::Work)?,
         ))
     } else {
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("execution", "inline");
         Ok((
             ParseExecution::Inline,
             work().map_err(ParseWorkError::Work)?,
@@ -2,17 +2,21 @@ use std::{num::NonZeroUsize, sync::Arc, thread};
 
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
+use crate::observer::ObserverHandle;
+
 const MAX_BLOCKING_PARSE_CONCURRENCY: usize = 4;
 
 #[derive(Clone, Debug)]
 pub(crate) struct QueryRuntime {
     blocking_parse_limiter: BlockingParseLimiter,
+    observer: ObserverHandle,
 }
 
 impl QueryRuntime {
     pub(crate) fn new() -> Self {
         Self {
             blocking_parse_limiter: BlockingParseLimiter::new(blocking_parse_concurrency_limit()),
+            observer: ObserverHandle::default(),
         }
     }
 
@@ -20,12 +24,22 @@ impl QueryRuntime {
     pub(crate) fn with_blocking_parse_concurrency(concurrency: NonZeroUsize) -> Self {
         Self {
             blocking_parse_limiter: BlockingParseLimiter::new(concurrency),
+            observer: ObserverHandle::default(),
         }
     }
 
+    pub(crate) fn with_observer(mut self, observer: ObserverHandle) -> Self {
+        self.observer = observer;
+        self
+    }
+
     pub(crate) fn blocking_parse_limiter(&self) -> BlockingParseLimiter {
         self.blocking_parse_limiter.clone()
     }
+
+    pub(crate) fn observer(&self) -> &ObserverHandle {
+        &self.observer
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -1,4 +1,9 @@
-use std::{fmt, num::NonZeroUsize, sync::Arc, time::Duration};
+use std::{
+    fmt,
+    num::NonZeroUsize,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 use uuid::Uuid;
 
@@ -12,6 +17,7 @@ use crate::{
 pub struct Session {
     pub(crate) shared: Arc<ClientShared>,
     pub(crate) auth: Arc<SessionAuth>,
+    pub(crate) parameters: Arc<SessionParameters>,
 }
 
 /// Per-query overrides for a single [`Session::query_with_options`] / [`Session::query_as_with_options`] /
@@ -62,6 +68,43 @@ pub(crate) struct SessionAuth {
     pub(crate) session_token: String,
 }
 
+/// Session parameters Snowflake reports at login and with each query response.
+#[derive(Debug, Default)]
+pub(crate) struct SessionParameters {
+    timezone: RwLock<Option<Arc<str>>>,
+}
+
+impl SessionParameters {
+    pub(crate) fn new(timezone: Option<Arc<str>>) -> Self {
+        Self {
+            timezone: RwLock::new(timezone),
+        }
+    }
+
+    /// The last `TIMEZONE` Snowflake reported for this session.
+    pub(crate) fn timezone(&self) -> Option<Arc<str>> {
+        self.timezone
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Record the `TIMEZONE` a query response reported and return the one in effect for its result.
+    ///
+    /// A response without the parameter keeps the last reported value, so `ALTER SESSION SET TIMEZONE` is picked up
+    /// by the next response that carries it.
+    pub(crate) fn observe_timezone(&self, reported: Option<Arc<str>>) -> Option<Arc<str>> {
+        match reported {
+            Some(timezone) => {
+                *self.timezone.write().unwrap_or_else(|e| e.into_inner()) =
+                    Some(Arc::clone(&timezone));
+                Some(timezone)
+            }
+            None => self.timezone(),
+        }
+    }
+}
+
 impl fmt::Debug for Session {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // The session token authenticates every request; never print it.
@@ -69,6 +112,7 @@ impl fmt::Debug for Session {
             .field("base_url", &self.shared.api.base_url())
             .field("session_token", &"<redacted>")
             .field("query", &self.shared.query)
+            .field("timezone", &self.parameters.timezone())
             .finish_non_exhaustive()
     }
 }
@@ -84,6 +128,14 @@ impl SessionAuth {
 }
 
 impl Session {
+    /// The session `TIMEZONE` parameter as Snowflake last reported it, at login or with a query response.
+    ///
+    /// Snowflake presents `TIMESTAMP_LTZ` values in this zone, and each result carries the value in effect for it as
+    /// [`Schema::session_timezone`](crate::Schema::session_timezone). `None` until Snowflake reports the parameter.
+    pub fn timezone(&self) -> Option<Arc<str>> {
+        self.parameters.timezone()
+    }
+
     /// Submit a statement and return a `ResultCursor` for streaming partition access.
     ///
     /// # Errors
@@ -225,6 +277,7 @@ mod tests {
                 .with_query(config.into())
                 .build(),
             auth: SessionAuth::for_test("test-token"),
+            parameters: Arc::default(),
         }
     }
 
@@ -367,6 +420,22 @@ mod tests {
         assert!(server.await.unwrap());
     }
 
+    #[test]
+    fn session_timezone_tracks_the_last_reported_value() {
+        let parameters = SessionParameters::new(Some(Arc::from("America/Los_Angeles")));
+        assert_eq!(
+            parameters.observe_timezone(None).as_deref(),
+            Some("America/Los_Angeles")
+        );
+        assert_eq!(
+            parameters
+                .observe_timezone(Some(Arc::from("Asia/Tokyo")))
+                .as_deref(),
+            Some("Asia/Tokyo")
+        );
+        assert_eq!(parameters.timezone().as_deref(), Some("Asia/Tokyo"));
+    }
+
     #[test]
     fn query_handle_validates_named_bind_keys_synchronously() {
         let session = test_session(Url::parse("http://127.0.0.1:1/").unwrap());
@@ -8,7 +8,7 @@ mod test_support;
 
 use std::sync::Arc;
 
-use crate::{ApiContext, session::SessionAuth};
+use crate::{ApiContext, RetryPolicy, session::SessionAuth};
 
 pub(crate) use deadline::QueryResponseDeadline;
 
@@ -22,4 +22,8 @@ impl QueryApiClient {
     pub(crate) fn new(api: Arc<ApiContext>, auth: Arc<SessionAuth>) -> Self {
         Self { api, auth }
     }
+
+    pub(crate) fn retry_policy(&self) -> &RetryPolicy {
+        self.api.retry_policy()
+    }
 }
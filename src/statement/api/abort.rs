@@ -11,10 +11,12 @@ use uuid::Uuid;
 
 use crate::{
     Error, Result,
+    config::{is_retryable_status, retry_after, send_request},
     error::{
         NetworkError, ProtocolError, ServerError, SessionExpiredError, TimeoutError,
         classify_request_error,
     },
+    observer::RetryOperation,
     statement::wire::{
         request::WireAbortBody,
         response::{SESSION_EXPIRED, WireAbortResponse},
@@ -23,8 +25,6 @@ use crate::{
 
 use super::{QueryApiClient, deadline::deadline_after};
 
-const MAX_ABORT_ATTEMPTS: usize = 8;
-
 impl QueryApiClient {
     pub(crate) async fn abort_query(
         &self,
@@ -51,9 +51,9 @@ impl QueryApiClient {
             })
             .map_err(|error| Error::other(format!("failed to serialize abort request: {error}")))?,
         );
-        let mut backoff = AbortBackoff::new();
+        let mut retry = self.api.retry_policy().start();
 
-        for attempt in 0..MAX_ABORT_ATTEMPTS {
+        loop {
             let remaining = clock.remaining();
             if remaining.is_zero() {
                 return Err(TimeoutError::query_cancel().into());
@@ -70,21 +70,24 @@ impl QueryApiClient {
                 )
                 .body(body.clone());
 
+            let mut failed_status = None;
             let attempt_result = match timeout(remaining, async {
-                let response = request.send().await?;
+                let response = send_request(request).await?;
                 let status = response.status();
+                let retry_after = retry_after(response.headers());
                 let body = response.bytes().await?;
-                Ok::<_, reqwest::Error>((status, body))
+                Ok::<_, reqwest::Error>((status, retry_after, body))
             })
             .await
             {
-                Ok(Ok((status, body))) => {
+                Ok(Ok((status, retry_after, body))) => {
                     if !status.is_success() {
                         let error = NetworkError::http_status(status.as_u16(), &body);
-                        if is_retryable_abort_status(status.as_u16()) {
-                            Err((true, Error::from(error)))
+                        if is_retryable_status(status) {
+                            failed_status = Some(status);
+                            Err((true, retry_after, Error::from(error)))
                         } else {
-                            Err((false, Error::from(error)))
+                            Err((false, None, Error::from(error)))
                         }
                     } else {
                         let response: WireAbortResponse = from_slice(&body).map_err(|error| {
@@ -93,12 +96,14 @@ impl QueryApiClient {
                         if response.code.as_deref() == Some(SESSION_EXPIRED) {
                             Err((
                                 false,
+                                None,
                                 SessionExpiredError::new(response.code, response.message, None)
                                     .into(),
                             ))
                         } else if !response.success {
                             Err((
                                 false,
+                                None,
                                 ServerError::new(response.code, response.message, None).into(),
                             ))
                         } else {
@@ -106,33 +111,35 @@ impl QueryApiClient {
                         }
                     }
                 }
-                Ok(Err(error)) => Err((true, classify_request_error(error))),
-                Err(_elapsed) => Err((true, TimeoutError::query_cancel().into())),
+                Ok(Err(error)) => Err((true, None, classify_request_error(error))),
+                Err(_elapsed) => Err((true, None, TimeoutError::query_cancel().into())),
             };
 
             match attempt_result {
                 Ok(()) => return Ok(()),
-                Err((retryable, error)) if !retryable || attempt + 1 == MAX_ABORT_ATTEMPTS => {
-                    return Err(error);
-                }
-                Err((_retryable, _error)) => {
+                Err((false, _, error)) => return Err(error),
+                Err((true, retry_after, error)) => {
+                    let Some(delay) = retry.next_delay(retry_after) else {
+                        return Err(error);
+                    };
                     let remaining = clock.remaining();
                     if remaining.is_zero() {
                         return Err(TimeoutError::query_cancel().into());
                     }
-                    clock.sleep(remaining.min(backoff.next_delay())).await;
+                    let delay = remaining.min(delay);
+                    self.api.notify_retry(
+                        RetryOperation::Abort,
+                        retry.retries(),
+                        delay,
+                        failed_status,
+                    );
+                    clock.sleep(delay).await;
                 }
             }
         }
-
-        unreachable!("abort retry loop always returns within the attempt budget");
     }
 }
 
-fn is_retryable_abort_status(status: u16) -> bool {
-    matches!(status, 408 | 429 | 500..=599)
-}
-
 trait AbortClock {
     fn remaining(&self) -> Duration;
     fn sleep(&self, duration: Duration) -> impl std::future::Future<Output = ()> + Send;
@@ -160,29 +167,6 @@ impl AbortClock for RealAbortClock {
     }
 }
 
-struct AbortBackoff {
-    step: usize,
-}
-
-impl AbortBackoff {
-    const STEPS: [Duration; 4] = [
-        Duration::from_millis(200),
-        Duration::from_millis(500),
-        Duration::from_secs(1),
-        Duration::from_secs(2),
-    ];
-
-    fn new() -> Self {
-        Self { step: 0 }
-    }
-
-    fn next_delay(&mut self) -> Duration {
-        let delay = Self::STEPS[self.step];
-        self.step = (self.step + 1).min(Self::STEPS.len() - 1);
-        delay
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::{sync::Mutex as StdMutex, time::Duration};
@@ -193,10 +177,12 @@ mod tests {
 
     use super::*;
     use crate::{
-        ErrorKind,
+        ErrorKind, RetryPolicy,
         api_context::DEFAULT_USER_AGENT,
-        statement::api::test_support::{respond_once, test_query_api},
-        test_support::http::{read_http_request, write_json_response},
+        statement::api::test_support::{respond_once, test_query_api, test_query_api_with_retry},
+        test_support::http::{
+            read_http_request, write_json_response, write_json_response_with_headers,
+        },
     };
 
     #[tokio::test]
@@ -380,6 +366,14 @@ mod tests {
         let _ = server.await.unwrap();
     }
 
+    fn deterministic_retry() -> RetryPolicy {
+        RetryPolicy::new()
+            .with_jitter(false)
+            .with_max_retries(3)
+            .with_initial_backoff(Duration::from_millis(200))
+            .with_max_backoff(Duration::from_millis(500))
+    }
+
     struct MockAbortClock {
         remaining: StdMutex<Duration>,
         sleeps: StdMutex<Vec<Duration>>,
@@ -421,7 +415,10 @@ mod tests {
                 respond_once(&listener, 200, r#"{"success":true}"#).await;
             });
 
-            let client = test_query_api(Url::parse(&format!("http://{addr}/")).unwrap());
+            let client = test_query_api_with_retry(
+                Url::parse(&format!("http://{addr}/")).unwrap(),
+                deterministic_retry(),
+            );
             let clock = MockAbortClock::new(Duration::from_secs(1_000));
             client
                 .abort_query_with_clock("query-request-id", &clock)
@@ -439,6 +436,8 @@ mod tests {
 
     #[tokio::test]
     async fn abort_stops_after_the_maximum_attempt_budget() {
+        const MAX_ABORT_ATTEMPTS: usize = 4;
+
         let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
         let addr = listener.local_addr().unwrap();
         let server = tokio::spawn(async move {
@@ -450,7 +449,10 @@ mod tests {
                 .is_err()
         });
 
-        let client = test_query_api(Url::parse(&format!("http://{addr}/")).unwrap());
+        let client = test_query_api_with_retry(
+            Url::parse(&format!("http://{addr}/")).unwrap(),
+            deterministic_retry(),
+        );
         let clock = MockAbortClock::new(Duration::from_secs(1_000));
         let err = client
             .abort_query_with_clock("query-request-id", &clock)
@@ -466,17 +468,45 @@ mod tests {
             clock.recorded_sleeps(),
             vec![
                 Duration::from_millis(200),
+                Duration::from_millis(400),
                 Duration::from_millis(500),
-                Duration::from_secs(1),
-                Duration::from_secs(2),
-                Duration::from_secs(2),
-                Duration::from_secs(2),
-                Duration::from_secs(2),
             ],
-            "backoff must grow and then cap at 2s across the seven retries"
+            "backoff must follow the retry policy and cap at its maximum across the three retries"
         );
     }
 
+    #[tokio::test]
+    async fn abort_waits_for_retry_after() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            read_http_request(&mut socket).await.unwrap();
+            write_json_response_with_headers(
+                &mut socket,
+                StatusCode::SERVICE_UNAVAILABLE,
+                &[("retry-after", "7")],
+                "{}",
+            )
+            .await
+            .unwrap();
+            respond_once(&listener, 200, r#"{"success":true}"#).await;
+        });
+
+        let client = test_query_api_with_retry(
+            Url::parse(&format!("http://{addr}/")).unwrap(),
+            deterministic_retry(),
+        );
+        let clock = MockAbortClock::new(Duration::from_secs(1_000));
+        client
+            .abort_query_with_clock("query-request-id", &clock)
+            .await
+            .unwrap();
+
+        server.await.unwrap();
+        assert_eq!(clock.recorded_sleeps(), vec![Duration::from_secs(7)]);
+    }
+
     #[tokio::test]
     async fn abort_stops_when_the_deadline_is_exhausted_between_retries() {
         let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
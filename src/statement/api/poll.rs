@@ -1,4 +1,7 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use http::{
     Method,
@@ -9,6 +12,7 @@ use tokio::time::{sleep, timeout};
 
 use crate::{
     error::{NetworkError, ProtocolError, QueryScopedError, QueryScopedResult, TimeoutError},
+    observer::{PollEvent, RetryOperation},
     statement::wire::response::{
         QUERY_IN_PROGRESS_ASYNC_CODE, QUERY_IN_PROGRESS_CODE, WireQueryResponse,
         parse_query_response,
@@ -18,6 +22,14 @@ use crate::{
 use super::{QueryApiClient, QueryResponseDeadline};
 
 impl QueryApiClient {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "snowflake.poll",
+            skip_all,
+            fields(query_id = %query_id, retries = tracing::field::Empty)
+        )
+    )]
     pub(crate) async fn poll_async_results(
         &self,
         poll_relative_url: &str,
@@ -31,6 +43,7 @@ impl QueryApiClient {
             }
         };
         let mut backoff = PollBackoff::new();
+        let mut iteration = 0_u32;
 
         loop {
             // The poll endpoint is a server-side long-poll, so apply the remaining query-response budget to the whole
@@ -40,23 +53,19 @@ impl QueryApiClient {
                 Err(err) => return Err(QueryScopedError::new(query_id, err)),
             };
 
-            let request = self
-                .api
-                .request(Method::GET, poll_url.clone())
-                .header(ACCEPT, "application/snowflake")
-                .header(
-                    AUTHORIZATION,
-                    format!(r#"Snowflake Token="{}""#, self.auth.session_token),
-                );
-
-            let response = match timeout(remaining, async {
-                let resp = request.send().await?;
-                let status = resp.status();
-                let body = resp.bytes().await?;
-                Ok::<_, reqwest::Error>((status, body))
-            })
-            .await
-            {
+            iteration += 1;
+            let started_at = Instant::now();
+            let send = self.api.send_with_retry(RetryOperation::Poll, |_| {
+                self.api
+                    .request(Method::GET, poll_url.clone())
+                    .header(ACCEPT, "application/snowflake")
+                    .header(
+                        AUTHORIZATION,
+                        format!(r#"Snowflake Token="{}""#, self.auth.session_token),
+                    )
+            });
+
+            let response = match timeout(remaining, send).await {
                 Ok(Ok(pair)) => pair,
                 Ok(Err(error)) => {
                     if error.is_timeout() {
@@ -80,22 +89,38 @@ impl QueryApiClient {
                 ));
             }
 
-            match parse_query_response(body) {
-                Ok(response)
-                    if response.code.as_deref() != Some(QUERY_IN_PROGRESS_ASYNC_CODE)
-                        && response.code.as_deref() != Some(QUERY_IN_PROGRESS_CODE) =>
-                {
-                    return Ok(response);
-                }
-                Ok(_) => {
-                    let remaining = deadline.remaining();
-                    if remaining.is_zero() {
-                        return Err(QueryScopedError::new(query_id, TimeoutError::query()));
-                    }
-                    sleep(remaining.min(backoff.next_delay())).await;
-                }
+            let response_bytes = body.len();
+            let response = match parse_query_response(body) {
+                Ok(response) => response,
                 Err(err) => return Err(QueryScopedError::new(query_id, err)),
+            };
+            let in_progress = response.code.as_deref() == Some(QUERY_IN_PROGRESS_ASYNC_CODE)
+                || response.code.as_deref() == Some(QUERY_IN_PROGRESS_CODE);
+            self.api.observer().notify(|observer| {
+                observer.on_poll(&PollEvent {
+                    query_id: Arc::clone(&query_id),
+                    iteration,
+                    elapsed: started_at.elapsed(),
+                    response_bytes,
+                    in_progress,
+                })
+            });
+
+            if !in_progress {
+                return Ok(response);
             }
+
+            let remaining = deadline.remaining();
+            if remaining.is_zero() {
+                return Err(QueryScopedError::new(query_id, TimeoutError::query()));
+            }
+            let delay = remaining.min(backoff.next_delay());
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                delay_ms = delay.as_millis() as u64,
+                "query still in progress"
+            );
+            sleep(delay).await;
         }
     }
 }
@@ -190,9 +215,12 @@ mod tests {
 
     use super::*;
     use crate::{
-        Error, ErrorKind,
+        Error, ErrorKind, RetryPolicy,
         api_context::DEFAULT_USER_AGENT,
-        statement::api::{QueryResponseDeadline, test_support::test_query_api},
+        statement::api::{
+            QueryResponseDeadline,
+            test_support::{test_query_api, test_query_api_with_retry},
+        },
         test_support::http::{read_http_request, write_json_response},
     };
 
@@ -239,6 +267,44 @@ mod tests {
         )));
     }
 
+    #[tokio::test]
+    async fn poll_retries_connection_failure_and_transient_status() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            // Drop the first connection without answering, then fail once with a transient status.
+            drop(listener.accept().await.unwrap());
+            for (status, body) in [
+                (StatusCode::BAD_GATEWAY, "bad gateway"),
+                (
+                    StatusCode::OK,
+                    r#"{"success":true,"data":{"queryId":"q","rowtype":[],"rowset":[],"queryResultFormat":"json"}}"#,
+                ),
+            ] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                read_http_request(&mut socket).await.unwrap();
+                write_json_response(&mut socket, status, body)
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let client = test_query_api_with_retry(
+            Url::parse(&format!("http://{addr}/")).unwrap(),
+            RetryPolicy::new().with_initial_backoff(Duration::from_millis(10)),
+        );
+        let deadline = QueryResponseDeadline::new(Duration::from_secs(30));
+        client
+            .poll_async_results(
+                "/queries/v1/query-request?requestId=abc",
+                deadline,
+                Arc::from("query-id"),
+            )
+            .await
+            .unwrap();
+        server.await.unwrap();
+    }
+
     #[test]
     fn resolve_poll_url_accepts_relative_and_same_origin_absolute_urls() {
         let base_url = Url::parse("https://example.com/").unwrap();
@@ -1,18 +1,25 @@
+use std::{sync::Arc, time::Instant};
+
 use bytes::Bytes;
 use http::{
     Method,
     header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE},
 };
+use reqwest::Url;
 use tokio::time::timeout;
 
 use crate::{
     Error, Result,
     error::{NetworkError, TimeoutError, classify_request_error},
+    observer::{FirstResponseEvent, RetryOperation, SubmitEvent},
     statement::{
         StatementParts,
         wire::{
             request::WireQueryBody,
-            response::{WireQueryResponse, parse_query_response},
+            response::{
+                QUERY_IN_PROGRESS_ASYNC_CODE, QUERY_IN_PROGRESS_CODE, WireQueryResponse,
+                parse_query_response,
+            },
         },
     },
 };
@@ -20,7 +27,9 @@ use crate::{
 use super::{QueryApiClient, QueryResponseDeadline};
 
 pub(crate) struct PreparedSubmit {
-    request: reqwest::RequestBuilder,
+    request_id: Arc<str>,
+    url: Url,
+    body: Bytes,
 }
 
 impl QueryApiClient {
@@ -39,20 +48,26 @@ impl QueryApiClient {
             })?,
         );
 
-        let request = self
-            .api
-            .request(Method::POST, url)
-            .header(ACCEPT, "application/snowflake")
-            .header(CONTENT_TYPE, "application/json")
-            .header(
-                AUTHORIZATION,
-                format!(r#"Snowflake Token="{}""#, self.auth.session_token),
-            )
-            .body(body);
-
-        Ok(PreparedSubmit { request })
+        Ok(PreparedSubmit {
+            request_id: Arc::from(query_request_id),
+            url,
+            body,
+        })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "snowflake.submit",
+            skip_all,
+            fields(
+                body_bytes = prepared.body.len(),
+                status = tracing::field::Empty,
+                response_bytes = tracing::field::Empty,
+                retries = tracing::field::Empty,
+            )
+        )
+    )]
     pub(crate) async fn send_prepared_submit(
         &self,
         prepared: PreparedSubmit,
@@ -60,14 +75,27 @@ impl QueryApiClient {
     ) -> Result<WireQueryResponse> {
         let remaining = deadline.remaining_or_timeout()?;
 
+        let observer = self.api.observer();
+        observer.notify(|observer| {
+            observer.on_submit(&SubmitEvent {
+                request_id: Arc::clone(&prepared.request_id),
+                body_bytes: prepared.body.len(),
+            })
+        });
+        let started_at = Instant::now();
+
         let body = match timeout(remaining, async {
-            let response = prepared
-                .request
-                .send()
+            let (status, body) = self
+                .api
+                .send_with_retry(RetryOperation::Submit, |is_retry| {
+                    self.submit_request(&prepared, is_retry)
+                })
                 .await
                 .map_err(classify_request_error)?;
-            let status = response.status();
-            let body = response.bytes().await.map_err(classify_request_error)?;
+            #[cfg(feature = "tracing")]
+            tracing::Span::current()
+                .record("status", status.as_u16())
+                .record("response_bytes", body.len());
             if !status.is_success() {
                 return Err(Error::from(NetworkError::http_status(
                     status.as_u16(),
@@ -82,7 +110,41 @@ impl QueryApiClient {
             Err(_elapsed) => return Err(TimeoutError::query().into()),
         };
 
-        parse_query_response(body).map_err(Error::from)
+        let response_bytes = body.len();
+        let response = parse_query_response(body).map_err(Error::from)?;
+        observer.notify(|observer| {
+            let code = response.code.as_deref();
+            observer.on_first_response(&FirstResponseEvent {
+                request_id: Arc::clone(&prepared.request_id),
+                query_id: response
+                    .data
+                    .as_ref()
+                    .map(|data| Arc::clone(&data.query_id)),
+                elapsed: started_at.elapsed(),
+                response_bytes,
+                in_progress: code == Some(QUERY_IN_PROGRESS_ASYNC_CODE)
+                    || code == Some(QUERY_IN_PROGRESS_CODE),
+            })
+        });
+        Ok(response)
+    }
+
+    /// Retries resend the original `requestId` with `retry=true`, which lets Snowflake recognize a statement it has
+    /// already accepted instead of executing it twice.
+    fn submit_request(&self, prepared: &PreparedSubmit, is_retry: bool) -> reqwest::RequestBuilder {
+        let mut url = prepared.url.clone();
+        if is_retry {
+            url.query_pairs_mut().append_pair("retry", "true");
+        }
+        self.api
+            .request(Method::POST, url)
+            .header(ACCEPT, "application/snowflake")
+            .header(CONTENT_TYPE, "application/json")
+            .header(
+                AUTHORIZATION,
+                format!(r#"Snowflake Token="{}""#, self.auth.session_token),
+            )
+            .body(prepared.body.clone())
     }
 }
 
@@ -96,13 +158,18 @@ mod tests {
 
     use super::*;
     use crate::{
-        ApiContext, ErrorKind, Statement,
+        ApiContext, ErrorKind, RetryPolicy, Statement,
         api_context::DEFAULT_USER_AGENT,
         session::SessionAuth,
-        statement::{api::test_support::test_query_api, builder::into_statement_parts},
+        statement::{
+            api::test_support::{test_query_api, test_query_api_with_retry},
+            builder::into_statement_parts,
+        },
         test_support::http::{read_http_request, write_json_response},
     };
 
+    const QUERY_RESPONSE: &str = r#"{"success":true,"data":{"queryId":"q","rowtype":[],"rowset":[],"queryResultFormat":"json"}}"#;
+
     #[tokio::test]
     async fn submit_posts_wire_request_contract() {
         let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
@@ -110,13 +177,9 @@ mod tests {
         let server = tokio::spawn(async move {
             let (mut socket, _) = listener.accept().await.unwrap();
             let request = read_http_request(&mut socket).await.unwrap();
-            write_json_response(
-                &mut socket,
-                StatusCode::OK,
-                r#"{"success":true,"data":{"queryId":"q","rowtype":[],"rowset":[],"queryResultFormat":"json"}}"#,
-            )
-            .await
-            .unwrap();
+            write_json_response(&mut socket, StatusCode::OK, QUERY_RESPONSE)
+                .await
+                .unwrap();
             request
         });
 
@@ -148,6 +211,86 @@ mod tests {
         assert_eq!(body["sqlText"], "select 1");
     }
 
+    #[tokio::test]
+    async fn submit_retries_transient_status_with_same_request_id_and_retry_marker() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for (status, body) in [
+                (StatusCode::SERVICE_UNAVAILABLE, "unavailable"),
+                (StatusCode::OK, QUERY_RESPONSE),
+            ] {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                requests.push(read_http_request(&mut socket).await.unwrap());
+                write_json_response(&mut socket, status, body)
+                    .await
+                    .unwrap();
+            }
+            requests
+        });
+
+        let client = test_query_api_with_retry(
+            Url::parse(&format!("http://{addr}/")).unwrap(),
+            RetryPolicy::new().with_initial_backoff(Duration::from_millis(10)),
+        );
+        let parts = into_statement_parts(Statement::from("select 1")).unwrap();
+        let deadline = QueryResponseDeadline::new(Duration::from_secs(30));
+        let prepared = client.prepare_submit(&parts, "query-request-id").unwrap();
+        client
+            .send_prepared_submit(prepared, deadline)
+            .await
+            .unwrap();
+
+        let requests = server.await.unwrap();
+        let request_line = |request: &str| request.lines().next().unwrap().to_owned();
+        assert!(
+            request_line(&requests[0])
+                .starts_with("POST /queries/v1/query-request?requestId=query-request-id HTTP/1.1"),
+            "{}",
+            request_line(&requests[0])
+        );
+        assert!(
+            request_line(&requests[1]).starts_with(
+                "POST /queries/v1/query-request?requestId=query-request-id&retry=true HTTP/1.1"
+            ),
+            "{}",
+            request_line(&requests[1])
+        );
+        assert_eq!(
+            requests[0].split("\r\n\r\n").nth(1),
+            requests[1].split("\r\n\r\n").nth(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn submit_returns_last_transient_status_when_retries_are_exhausted() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            read_http_request(&mut socket).await.unwrap();
+            write_json_response(&mut socket, StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+                .await
+                .unwrap();
+        });
+
+        let client = test_query_api_with_retry(
+            Url::parse(&format!("http://{addr}/")).unwrap(),
+            RetryPolicy::disabled(),
+        );
+        let parts = into_statement_parts(Statement::from("select 1")).unwrap();
+        let deadline = QueryResponseDeadline::new(Duration::from_secs(30));
+        let prepared = client.prepare_submit(&parts, "query-request-id").unwrap();
+        let err = client
+            .send_prepared_submit(prepared, deadline)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Network);
+        server.await.unwrap();
+    }
+
     #[tokio::test]
     async fn submit_timeout_is_timeout_error() {
         let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
@@ -5,15 +5,19 @@ use reqwest::Url;
 use tokio::net::TcpListener;
 
 use crate::{
-    ApiContext,
+    ApiContext, RetryPolicy,
     session::SessionAuth,
     statement::api::QueryApiClient,
     test_support::http::{read_http_request, write_json_response},
 };
 
 pub(super) fn test_query_api(base_url: Url) -> QueryApiClient {
+    test_query_api_with_retry(base_url, RetryPolicy::default())
+}
+
+pub(super) fn test_query_api_with_retry(base_url: Url, retry: RetryPolicy) -> QueryApiClient {
     QueryApiClient::new(
-        Arc::new(ApiContext::new(reqwest::Client::new(), base_url)),
+        Arc::new(ApiContext::new(reqwest::Client::new(), base_url).with_retry_policy(retry)),
         SessionAuth::for_test("test-token"),
     )
 }
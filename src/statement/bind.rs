@@ -6,10 +6,22 @@ use std::{borrow::Cow, fmt};
 
 use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 
-/// Snowflake server-side bind type, used with [`RawBind`](crate::bind::RawBind) to pick the wire type explicitly.
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+mod decimal;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "time")]
+mod time;
+mod uuid;
+mod vector;
+
+/// Snowflake server-side bind type, used with [`RawBind`](crate::bind::RawBind) and
+/// [`ToBind`](crate::bind::ToBind) to pick the wire type explicitly.
 ///
 /// The typed wrappers select the right variant for you; reach for this
-/// enum only when going through [`RawBind`](crate::bind::RawBind).
+/// enum only when going through [`RawBind`](crate::bind::RawBind) or implementing [`ToBind`](crate::bind::ToBind).
 ///
 /// # Example
 ///
@@ -41,8 +53,9 @@ pub enum BindType {
     TimestampTz,
     /// `BINARY` — byte buffer.
     Binary,
-    /// `DECFLOAT` (decimal floating-point). No typed wrapper is provided;
-    /// bind via [`RawBind`] with the documented decimal-string payload (e.g. `"1.23e-40"`).
+    /// `DECFLOAT` (decimal floating-point). No typed wrapper is provided; `bigdecimal::BigDecimal` values too wide for
+    /// `NUMBER` bind as this type, otherwise bind via [`RawBind`] with the documented decimal-string payload
+    /// (e.g. `"1.23e-40"`).
     DecFloat,
 }
 
@@ -481,11 +494,20 @@ impl TryFrom<NaiveTime> for Time {
 /// Marker trait for values accepted by [`Statement::bind`](crate::Statement::bind) and
 /// [`Statement::bind_named`](crate::Statement::bind_named).
 ///
-/// Implemented for primary scalars, every wrapper in this module,
-/// [`RawBind`], and `Option<T>` where `T: IntoBindNullable`.
+/// Implemented for primary scalars, every wrapper in this module, [`RawBind`], every [`ToBind`] type, and
+/// `Option<T>` where `T: IntoBindNullable`. With the `rust_decimal` / `bigdecimal` features, `rust_decimal::Decimal`
+/// and `bigdecimal::BigDecimal` bind as exact `FIXED` text (`DECFLOAT` for a `BigDecimal` wider than `NUMBER(38)`).
+/// With the `time` / `jiff` features, their civil date, time, and date-time types bind as `DATE`, `TIME`, and
+/// `TIMESTAMP_NTZ`, and `jiff::Timestamp` binds as `TIMESTAMP_LTZ`; convert `time::OffsetDateTime` or `jiff::Zoned`
+/// into [`TimestampTz`] to keep the offset. `uuid::Uuid` binds as hyphenated `TEXT`; convert it into [`Binary`] for a
+/// `BINARY(16)` column. With the `geo` feature, `geo_types::Geometry` and `wkt::Wkt` bind as WKT `TEXT` and
+/// `geojson::Geometry` as GeoJSON `TEXT`, which Snowflake converts into a `GEOGRAPHY` or `GEOMETRY` column.
 ///
-/// The trait is sealed; outside crates cannot add implementations. To bind a type not covered here, either convert
-/// it to one of the supported types or go through [`RawBind`].
+/// [`Vector<i32>`](crate::decode::Vector) and [`Vector<f32>`](crate::decode::Vector) bind as `TEXT` holding a JSON
+/// array, since Snowflake has no `VECTOR` bind type; convert it in SQL, as in
+/// `INSERT INTO docs (embedding) SELECT PARSE_JSON(?)::ARRAY::VECTOR(FLOAT, 768)`.
+///
+/// The trait is sealed; outside crates cannot implement it directly. To bind your own type, implement [`ToBind`].
 pub trait IntoBind: Sized + into_bind_sealed::Sealed {}
 
 /// Marker for [`IntoBind`] types whose `None` produces a typed wire NULL.
@@ -504,6 +526,115 @@ pub trait IntoBind: Sized + into_bind_sealed::Sealed {}
 /// ```
 pub trait IntoBindNullable: IntoBind + into_bind_nullable_sealed::Sealed {}
 
+/// Extension point for binding your own types, such as ID newtypes, money amounts, or enums.
+///
+/// Every `ToBind` type implements [`IntoBind`] and [`IntoBindNullable`], so it can be passed to
+/// [`Statement::bind`](crate::Statement::bind) and [`Statement::bind_named`](crate::Statement::bind_named) directly
+/// or as `Option<T>`, whose `None` becomes a NULL of type [`BIND_TYPE`](ToBind::BIND_TYPE).
+///
+/// The value is always sent with `BIND_TYPE` as its wire type; [`to_bind`](ToBind::to_bind) only supplies the
+/// payload. Pick a variant that matches, such as [`BindValueRef::Text`] for `BindType::Text` or a decimal string
+/// for `BindType::DecFloat`.
+///
+/// The built-in bind types do not implement this trait; a newtype builds its [`BindValueRef`] from its fields.
+///
+/// # Example
+///
+/// ```
+/// use snowflake_connector_rs::{
+///     Statement,
+///     bind::{BindType, BindValueRef, ToBind},
+/// };
+///
+/// struct CustomerId(String);
+///
+/// impl ToBind for CustomerId {
+///     const BIND_TYPE: BindType = BindType::Text;
+///
+///     fn to_bind(&self) -> BindValueRef<'_> {
+///         BindValueRef::Text(&self.0)
+///     }
+/// }
+///
+/// let _ = Statement::new("SELECT * FROM orders WHERE customer_id = ? OR referrer_id = ?")
+///     .bind(CustomerId("C-001".to_string()))
+///     .bind(None::<CustomerId>);
+/// ```
+pub trait ToBind {
+    /// Wire type of the value, and of the NULL sent for `Option::<Self>::None`.
+    const BIND_TYPE: BindType;
+
+    /// The value to send.
+    fn to_bind(&self) -> BindValueRef<'_>;
+}
+
+/// Bind payload returned by [`ToBind::to_bind`].
+///
+/// Values needing validation use the same wrappers as direct binds, so out-of-range integers and leap seconds are
+/// rejected when the wrapper is built. `Debug` prints only the variant name, never the value.
+#[derive(Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum BindValueRef<'a> {
+    /// An integer within `NUMBER(38, 0)`.
+    Fixed(Integer),
+    /// A floating-point number.
+    Real(f64),
+    /// A boolean.
+    Boolean(bool),
+    /// Text, sent verbatim. Also the payload for types without a dedicated variant, such as `DECFLOAT`.
+    Text(&'a str),
+    /// A calendar date.
+    Date(NaiveDate),
+    /// A time of day.
+    Time(Time),
+    /// A timestamp without time zone.
+    TimestampNtz(TimestampNtz),
+    /// A UTC instant.
+    TimestampLtz(TimestampLtz),
+    /// A timestamp with whole-minute offset.
+    TimestampTz(TimestampTz),
+    /// A byte buffer.
+    Binary(&'a [u8]),
+}
+
+impl fmt::Debug for BindValueRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Same reasoning as `BindValue`: custom bind types often wrap identifiers or secrets.
+        let name = match self {
+            Self::Fixed(_) => "Fixed",
+            Self::Real(_) => "Real",
+            Self::Boolean(_) => "Boolean",
+            Self::Text(_) => "Text",
+            Self::Date(_) => "Date",
+            Self::Time(_) => "Time",
+            Self::TimestampNtz(_) => "TimestampNtz",
+            Self::TimestampLtz(_) => "TimestampLtz",
+            Self::TimestampTz(_) => "TimestampTz",
+            Self::Binary(_) => "Binary",
+        };
+        f.debug_tuple(name)
+            .field(&format_args!("<redacted>"))
+            .finish()
+    }
+}
+
+impl BindValueRef<'_> {
+    fn to_owned_value(self) -> BindValue {
+        match self {
+            Self::Fixed(value) => BindValue::Fixed(value.0),
+            Self::Real(value) => BindValue::Real64(value),
+            Self::Boolean(value) => BindValue::Bool(value),
+            Self::Text(value) => BindValue::Text(Cow::Owned(value.to_owned())),
+            Self::Date(value) => BindValue::Date(value),
+            Self::Time(value) => BindValue::Time(value.0),
+            Self::TimestampNtz(value) => BindValue::TimestampNtz(value.0),
+            Self::TimestampLtz(value) => BindValue::TimestampLtz(value.0),
+            Self::TimestampTz(value) => BindValue::TimestampTz(value.0),
+            Self::Binary(value) => BindValue::Binary(value.to_vec()),
+        }
+    }
+}
+
 pub(super) mod into_bind_sealed {
     pub trait Sealed: Sized {
         fn into_bind(self) -> super::Bind;
@@ -600,6 +731,26 @@ impl into_bind_sealed::Sealed for RawBind {
     }
 }
 
+impl<T> IntoBind for T where T: ToBind {}
+
+impl<T> into_bind_sealed::Sealed for T
+where
+    T: ToBind,
+{
+    fn into_bind(self) -> Bind {
+        Bind::new(T::BIND_TYPE, self.to_bind().to_owned_value())
+    }
+}
+
+impl<T> IntoBindNullable for T where T: ToBind {}
+
+impl<T> into_bind_nullable_sealed::Sealed for T
+where
+    T: ToBind,
+{
+    const DEFAULT_TYPE: BindType = T::BIND_TYPE;
+}
+
 impl<T> IntoBind for Option<T> where T: IntoBindNullable {}
 
 impl<T> into_bind_sealed::Sealed for Option<T>
@@ -832,6 +983,7 @@ const _: fn() = || {
     assert_send_sync::<TimestampLtz>();
     assert_send_sync::<TimestampTz>();
     assert_send_sync::<Time>();
+    assert_send_sync::<BindValueRef<'static>>();
 };
 
 #[cfg(test)]
@@ -867,6 +1019,67 @@ mod tests {
         assert_eq!(format!("{:?}", BindValue::Fixed(42)), "<redacted>");
     }
 
+    struct AccountId(u64);
+
+    impl ToBind for AccountId {
+        const BIND_TYPE: BindType = BindType::Fixed;
+
+        fn to_bind(&self) -> BindValueRef<'_> {
+            BindValueRef::Fixed(self.0.into())
+        }
+    }
+
+    struct Amount(String);
+
+    impl ToBind for Amount {
+        const BIND_TYPE: BindType = BindType::DecFloat;
+
+        fn to_bind(&self) -> BindValueRef<'_> {
+            BindValueRef::Text(&self.0)
+        }
+    }
+
+    #[test]
+    fn to_bind_types_encode_with_their_bind_type() {
+        assert_bind_value(
+            encode_bind(AccountId(42)),
+            BindType::Fixed,
+            Some(BindValue::Fixed(42)),
+        );
+        assert_bind_value(
+            encode_bind(Amount("1.23e-40".to_string())),
+            BindType::DecFloat,
+            Some(BindValue::Text("1.23e-40".into())),
+        );
+    }
+
+    #[test]
+    fn option_of_to_bind_type_produces_typed_null() {
+        assert_bind_value(
+            encode_bind(Some(AccountId(7))),
+            BindType::Fixed,
+            Some(BindValue::Fixed(7)),
+        );
+        assert_bind_value(encode_bind(None::<AccountId>), BindType::Fixed, None);
+        assert_bind_value(encode_bind(None::<Amount>), BindType::DecFloat, None);
+        assert_eq!(
+            default_null_bind_type::<Option<Amount>>(),
+            BindType::DecFloat
+        );
+    }
+
+    #[test]
+    fn bind_value_ref_debug_hides_the_value() {
+        assert_eq!(
+            format!("{:?}", BindValueRef::Text("secret")),
+            "Text(<redacted>)"
+        );
+        assert_eq!(
+            format!("{:?}", BindValueRef::Binary(&[1, 2, 3])),
+            "Binary(<redacted>)"
+        );
+    }
+
     #[test]
     fn option_bind_preserves_underlying_type() {
         assert_bind_value(
@@ -0,0 +1,140 @@
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::BigDecimal;
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+use super::{
+    Bind, BindType, BindValue, IntoBind, IntoBindNullable, into_bind_nullable_sealed,
+    into_bind_sealed,
+};
+
+/// Widest `NUMBER` Snowflake stores: 38 digits, at most 37 of them after the decimal point.
+#[cfg(feature = "bigdecimal")]
+const NUMBER_MAX_PRECISION: i64 = 38;
+#[cfg(feature = "bigdecimal")]
+const NUMBER_MAX_SCALE: i64 = 37;
+
+// A `Decimal` holds at most 28 digits at scale 0..=28, so it always fits `NUMBER(38, s)`. `Display` keeps trailing
+// zeros, so the bound text carries the value's exact scale.
+#[cfg(feature = "rust_decimal")]
+impl IntoBind for Decimal {}
+
+#[cfg(feature = "rust_decimal")]
+impl into_bind_sealed::Sealed for Decimal {
+    fn into_bind(self) -> Bind {
+        Bind::new(BindType::Fixed, BindValue::Text(self.to_string().into()))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl IntoBindNullable for Decimal {}
+
+#[cfg(feature = "rust_decimal")]
+impl into_bind_nullable_sealed::Sealed for Decimal {
+    const DEFAULT_TYPE: BindType = BindType::Fixed;
+}
+
+// A `BigDecimal` that fits `NUMBER(38, s)` binds as `FIXED` in plain notation; anything wider or with more than 37
+// fractional digits binds as `DECFLOAT` in scientific notation. `None` binds a `FIXED` NULL.
+#[cfg(feature = "bigdecimal")]
+impl IntoBind for BigDecimal {}
+
+#[cfg(feature = "bigdecimal")]
+impl into_bind_sealed::Sealed for BigDecimal {
+    fn into_bind(self) -> Bind {
+        let digits = i64::try_from(self.digits()).unwrap_or(i64::MAX);
+        let scale = self.fractional_digit_count();
+        // A negative scale stands for trailing zeros that plain notation writes out as integer digits.
+        let precision = if scale >= 0 {
+            digits.max(scale)
+        } else {
+            digits.saturating_sub(scale)
+        };
+        if precision <= NUMBER_MAX_PRECISION && scale <= NUMBER_MAX_SCALE {
+            Bind::new(
+                BindType::Fixed,
+                BindValue::Text(self.to_plain_string().into()),
+            )
+        } else {
+            Bind::new(
+                BindType::DecFloat,
+                BindValue::Text(self.to_scientific_notation().into()),
+            )
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl IntoBindNullable for BigDecimal {}
+
+#[cfg(feature = "bigdecimal")]
+impl into_bind_nullable_sealed::Sealed for BigDecimal {
+    const DEFAULT_TYPE: BindType = BindType::Fixed;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statement::bind::encode_bind;
+
+    fn text(bind: &Bind) -> &str {
+        match bind.value() {
+            Some(BindValue::Text(text)) => text,
+            other => panic!("expected text payload, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn decimal_binds_as_fixed_with_exact_scale() {
+        let bind = encode_bind(Decimal::new(12_500, 3));
+        assert_eq!(bind.ty(), BindType::Fixed);
+        assert_eq!(text(&bind), "12.500");
+
+        let bind = encode_bind(None::<Decimal>);
+        assert_eq!(bind.ty(), BindType::Fixed);
+        assert_eq!(bind.value(), None);
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn big_decimal_binds_as_fixed_when_it_fits_number() {
+        for (input, expected) in [
+            ("-12.500", "-12.500"),
+            (
+                "0.0000000000000000000000000000000000001",
+                "0.0000000000000000000000000000000000001",
+            ),
+            ("1e3", "1000"),
+            (
+                "99999999999999999999999999999999999999",
+                "99999999999999999999999999999999999999",
+            ),
+        ] {
+            let bind = encode_bind(input.parse::<BigDecimal>().unwrap());
+            assert_eq!(bind.ty(), BindType::Fixed, "{input}");
+            assert_eq!(text(&bind), expected);
+        }
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn big_decimal_binds_as_decfloat_beyond_number() {
+        for (input, expected) in [
+            ("1.23e-40", "1.23e-40"),
+            ("1e38", "1e38"),
+            (
+                "123456789012345678901234567890.123456789",
+                "1.23456789012345678901234567890123456789e29",
+            ),
+        ] {
+            let bind = encode_bind(input.parse::<BigDecimal>().unwrap());
+            assert_eq!(bind.ty(), BindType::DecFloat, "{input}");
+            assert_eq!(text(&bind), expected);
+        }
+
+        let bind = encode_bind(None::<BigDecimal>);
+        assert_eq!(bind.ty(), BindType::Fixed);
+        assert_eq!(bind.value(), None);
+    }
+}
@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use geo_types::Geometry;
+use wkt::{ToWkt, Wkt};
+
+use super::{
+    Bind, BindType, BindValue, IntoBind, IntoBindNullable, into_bind_nullable_sealed,
+    into_bind_sealed,
+};
+
+// Geometries bind as `TEXT`; Snowflake parses WKT and GeoJSON text when the value lands in a `GEOGRAPHY` or
+// `GEOMETRY` column, or through `TO_GEOGRAPHY(?)` / `TO_GEOMETRY(?)`.
+
+macro_rules! impl_geo_text_bind {
+    ($ty:ty, |$value:ident| $text:expr) => {
+        impl IntoBind for $ty {}
+
+        impl into_bind_sealed::Sealed for $ty {
+            fn into_bind(self) -> Bind {
+                let $value = self;
+                Bind::new(BindType::Text, BindValue::Text(Cow::Owned($text)))
+            }
+        }
+
+        impl IntoBindNullable for $ty {}
+
+        impl into_bind_nullable_sealed::Sealed for $ty {
+            const DEFAULT_TYPE: BindType = BindType::Text;
+        }
+    };
+}
+
+impl_geo_text_bind!(Geometry<f64>, |value| value.wkt_string());
+impl_geo_text_bind!(Wkt<f64>, |value| value.to_string());
+impl_geo_text_bind!(geojson::Geometry, |value| value.to_string());
+
+#[cfg(test)]
+mod tests {
+    use geo_types::{Point, point};
+
+    use super::*;
+    use crate::statement::bind::encode_bind;
+
+    #[test]
+    fn geometry_and_wkt_bind_as_wkt_text() {
+        let geometry = Geometry::Point(point!(x: 1.5, y: -2.0));
+        let bind = encode_bind(geometry.clone());
+        assert_eq!(bind.ty(), BindType::Text);
+        assert_eq!(bind.value(), Some(&BindValue::Text("POINT(1.5 -2)".into())));
+
+        let bind = encode_bind(geometry.to_wkt());
+        assert_eq!(bind.value(), Some(&BindValue::Text("POINT(1.5 -2)".into())));
+
+        let bind = encode_bind(None::<Geometry<f64>>);
+        assert_eq!(bind.ty(), BindType::Text);
+        assert_eq!(bind.value(), None);
+    }
+
+    #[test]
+    fn geojson_binds_as_geojson_text() {
+        let geometry = geojson::Geometry::from(&Point::new(1.5, -2.0));
+        let Some(BindValue::Text(text)) = encode_bind(geometry).value().cloned() else {
+            panic!("expected a text bind");
+        };
+        let json: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"type": "Point", "coordinates": [1.5, -2.0]})
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, FixedOffset};
+use jiff::{
+    Timestamp, Zoned,
+    civil::{Date, DateTime as CivilDateTime, Time},
+};
+
+use super::{
+    Bind, BindType, BindValue, IntoBind, IntoBindNullable, TimestampTz, into_bind_nullable_sealed,
+    into_bind_sealed,
+};
+
+const UNIX_EPOCH_DATE: Date = Date::constant(1970, 1, 1);
+
+// jiff values have no leap seconds, so the direct binds below are infallible and encode straight to Snowflake's
+// epoch-based wire text.
+
+impl IntoBind for Date {}
+
+impl into_bind_sealed::Sealed for Date {
+    fn into_bind(self) -> Bind {
+        let millis = self.duration_since(UNIX_EPOCH_DATE).as_millis();
+        Bind::new(BindType::Date, BindValue::Raw(millis.to_string().into()))
+    }
+}
+
+impl IntoBindNullable for Date {}
+
+impl into_bind_nullable_sealed::Sealed for Date {
+    const DEFAULT_TYPE: BindType = BindType::Date;
+}
+
+impl IntoBind for Time {}
+
+impl into_bind_sealed::Sealed for Time {
+    fn into_bind(self) -> Bind {
+        let nanos = self.duration_since(Time::midnight()).as_nanos();
+        Bind::new(BindType::Time, BindValue::Raw(nanos.to_string().into()))
+    }
+}
+
+impl IntoBindNullable for Time {}
+
+impl into_bind_nullable_sealed::Sealed for Time {
+    const DEFAULT_TYPE: BindType = BindType::Time;
+}
+
+impl IntoBind for CivilDateTime {}
+
+impl into_bind_sealed::Sealed for CivilDateTime {
+    fn into_bind(self) -> Bind {
+        let nanos = self
+            .duration_since(UNIX_EPOCH_DATE.to_datetime(Time::midnight()))
+            .as_nanos();
+        Bind::new(
+            BindType::TimestampNtz,
+            BindValue::Raw(nanos.to_string().into()),
+        )
+    }
+}
+
+impl IntoBindNullable for CivilDateTime {}
+
+impl into_bind_nullable_sealed::Sealed for CivilDateTime {
+    const DEFAULT_TYPE: BindType = BindType::TimestampNtz;
+}
+
+impl IntoBind for Timestamp {}
+
+impl into_bind_sealed::Sealed for Timestamp {
+    fn into_bind(self) -> Bind {
+        Bind::new(
+            BindType::TimestampLtz,
+            BindValue::Raw(self.as_nanosecond().to_string().into()),
+        )
+    }
+}
+
+impl IntoBindNullable for Timestamp {}
+
+impl into_bind_nullable_sealed::Sealed for Timestamp {
+    const DEFAULT_TYPE: BindType = BindType::TimestampLtz;
+}
+
+impl TryFrom<&Zoned> for TimestampTz {
+    type Error = crate::Error;
+
+    /// Binds the instant at the offset in effect for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ErrorKind::BindEncode`](crate::ErrorKind::BindEncode) for sub-minute offsets, such as the local mean
+    /// time of some zones before standardization.
+    fn try_from(value: &Zoned) -> crate::Result<Self> {
+        let offset = FixedOffset::east_opt(value.offset().seconds())
+            .expect("jiff offsets are always under 26 hours");
+        let nanos = value.timestamp().as_nanosecond();
+        let seconds = nanos.div_euclid(1_000_000_000) as i64;
+        let subsec = nanos.rem_euclid(1_000_000_000) as u32;
+        let utc = DateTime::from_timestamp(seconds, subsec)
+            .expect("jiff timestamps are within chrono's range");
+        Self::try_from(utc.with_timezone(&offset))
+    }
+}
+
+impl TryFrom<Zoned> for TimestampTz {
+    type Error = crate::Error;
+
+    /// See the `TryFrom<&Zoned>` impl.
+    fn try_from(value: Zoned) -> crate::Result<Self> {
+        Self::try_from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::{
+        civil::{date, time},
+        tz::{Offset, TimeZone},
+    };
+
+    use super::*;
+    use crate::{ErrorKind, statement::bind::encode_bind};
+
+    fn raw(bind: &Bind) -> &str {
+        match bind.value() {
+            Some(BindValue::Raw(text)) => text,
+            other => panic!("expected wire text, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn binds_encode_epoch_wire_values() {
+        let bind = encode_bind(date(2024, 6, 15));
+        assert_eq!(bind.ty(), BindType::Date);
+        assert_eq!(raw(&bind), "1718409600000");
+
+        let bind = encode_bind(date(1969, 12, 31));
+        assert_eq!(raw(&bind), "-86400000");
+
+        let bind = encode_bind(time(12, 30, 45, 1));
+        assert_eq!(bind.ty(), BindType::Time);
+        assert_eq!(raw(&bind), "45045000000001");
+
+        let bind = encode_bind(date(2024, 6, 15).at(12, 30, 45, 500_000_000));
+        assert_eq!(bind.ty(), BindType::TimestampNtz);
+        assert_eq!(raw(&bind), "1718454645500000000");
+
+        let bind = encode_bind(Timestamp::new(-1, 500_000_000).unwrap());
+        assert_eq!(bind.ty(), BindType::TimestampLtz);
+        assert_eq!(raw(&bind), "-500000000");
+
+        let bind = encode_bind(None::<Timestamp>);
+        assert_eq!(bind.ty(), BindType::TimestampLtz);
+        assert_eq!(bind.value(), None);
+    }
+
+    #[test]
+    fn zoned_binds_through_timestamp_tz() {
+        let zoned = Timestamp::new(-1, 500_000_000)
+            .unwrap()
+            .to_zoned(TimeZone::fixed(Offset::constant(9)));
+
+        let bind = encode_bind(TimestampTz::try_from(&zoned).unwrap());
+        let expected = DateTime::from_timestamp(-1, 500_000_000)
+            .unwrap()
+            .with_timezone(&FixedOffset::east_opt(9 * 3_600).unwrap());
+        assert_eq!(bind.ty(), BindType::TimestampTz);
+        assert_eq!(bind.value(), Some(&BindValue::TimestampTz(expected)));
+
+        let sub_minute = Offset::from_seconds(3_630).unwrap();
+        let err =
+            TimestampTz::try_from(zoned.with_time_zone(TimeZone::fixed(sub_minute))).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BindEncode);
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, FixedOffset};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+use super::{
+    Bind, BindType, BindValue, IntoBind, IntoBindNullable, TimestampTz, into_bind_nullable_sealed,
+    into_bind_sealed,
+};
+
+/// Julian day number of 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+// `time` values have no leap seconds and every offset is below a day, so the direct binds below are infallible and
+// encode straight to Snowflake's epoch-based wire text rather than going through chrono, whose range `time`'s
+// `large-dates` feature exceeds.
+
+impl IntoBind for Date {}
+
+impl into_bind_sealed::Sealed for Date {
+    fn into_bind(self) -> Bind {
+        let millis = (i64::from(self.to_julian_day()) - UNIX_EPOCH_JULIAN_DAY) * 86_400_000;
+        Bind::new(BindType::Date, BindValue::Raw(millis.to_string().into()))
+    }
+}
+
+impl IntoBindNullable for Date {}
+
+impl into_bind_nullable_sealed::Sealed for Date {
+    const DEFAULT_TYPE: BindType = BindType::Date;
+}
+
+impl IntoBind for Time {}
+
+impl into_bind_sealed::Sealed for Time {
+    fn into_bind(self) -> Bind {
+        let (hour, minute, second, nanos) = self.as_hms_nano();
+        let seconds = u64::from(hour) * 3_600 + u64::from(minute) * 60 + u64::from(second);
+        let nanos = seconds * 1_000_000_000 + u64::from(nanos);
+        Bind::new(BindType::Time, BindValue::Raw(nanos.to_string().into()))
+    }
+}
+
+impl IntoBindNullable for Time {}
+
+impl into_bind_nullable_sealed::Sealed for Time {
+    const DEFAULT_TYPE: BindType = BindType::Time;
+}
+
+impl IntoBind for PrimitiveDateTime {}
+
+impl into_bind_sealed::Sealed for PrimitiveDateTime {
+    fn into_bind(self) -> Bind {
+        let nanos = self.assume_utc().unix_timestamp_nanos();
+        Bind::new(
+            BindType::TimestampNtz,
+            BindValue::Raw(nanos.to_string().into()),
+        )
+    }
+}
+
+impl IntoBindNullable for PrimitiveDateTime {}
+
+impl into_bind_nullable_sealed::Sealed for PrimitiveDateTime {
+    const DEFAULT_TYPE: BindType = BindType::TimestampNtz;
+}
+
+impl TryFrom<OffsetDateTime> for TimestampTz {
+    type Error = crate::Error;
+
+    /// # Errors
+    ///
+    /// Returns [`ErrorKind::BindEncode`](crate::ErrorKind::BindEncode) for sub-minute offsets and for dates outside
+    /// chrono's range.
+    fn try_from(value: OffsetDateTime) -> crate::Result<Self> {
+        let offset = FixedOffset::east_opt(value.offset().whole_seconds())
+            .expect("time::UtcOffset is always under 24 hours");
+        let utc = DateTime::from_timestamp(value.unix_timestamp(), value.nanosecond())
+            .ok_or_else(|| crate::Error::bind_encode("OffsetDateTime is out of range"))?;
+        Self::try_from(utc.with_timezone(&offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Month, UtcOffset};
+
+    use super::*;
+    use crate::{ErrorKind, statement::bind::encode_bind};
+
+    fn raw(bind: &Bind) -> &str {
+        match bind.value() {
+            Some(BindValue::Raw(text)) => text,
+            other => panic!("expected wire text, got {other:?}"),
+        }
+    }
+
+    fn june_15() -> Date {
+        Date::from_calendar_date(2024, Month::June, 15).unwrap()
+    }
+
+    #[test]
+    fn binds_encode_epoch_wire_values() {
+        let bind = encode_bind(june_15());
+        assert_eq!(bind.ty(), BindType::Date);
+        assert_eq!(raw(&bind), "1718409600000");
+
+        let bind = encode_bind(Date::from_calendar_date(1969, Month::December, 31).unwrap());
+        assert_eq!(raw(&bind), "-86400000");
+
+        let bind = encode_bind(Time::from_hms_nano(12, 30, 45, 1).unwrap());
+        assert_eq!(bind.ty(), BindType::Time);
+        assert_eq!(raw(&bind), "45045000000001");
+
+        let time = Time::from_hms_milli(12, 30, 45, 500).unwrap();
+        let bind = encode_bind(PrimitiveDateTime::new(june_15(), time));
+        assert_eq!(bind.ty(), BindType::TimestampNtz);
+        assert_eq!(raw(&bind), "1718454645500000000");
+
+        let bind = encode_bind(None::<PrimitiveDateTime>);
+        assert_eq!(bind.ty(), BindType::TimestampNtz);
+        assert_eq!(bind.value(), None);
+    }
+
+    #[test]
+    fn offset_date_time_binds_through_timestamp_tz() {
+        let time = Time::from_hms(21, 30, 45).unwrap();
+        let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+        let value = PrimitiveDateTime::new(june_15(), time).assume_offset(offset);
+
+        let bind = encode_bind(TimestampTz::try_from(value).unwrap());
+        let expected = DateTime::from_timestamp(1_718_454_645, 0)
+            .unwrap()
+            .with_timezone(&FixedOffset::east_opt(9 * 3_600).unwrap());
+        assert_eq!(bind.ty(), BindType::TimestampTz);
+        assert_eq!(bind.value(), Some(&BindValue::TimestampTz(expected)));
+
+        let sub_minute = UtcOffset::from_hms(1, 0, 30).unwrap();
+        let err = TimestampTz::try_from(value.to_offset(sub_minute)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::BindEncode);
+    }
+}
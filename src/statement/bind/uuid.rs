@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use uuid::Uuid;
+
+use super::{
+    Binary, Bind, BindType, BindValue, IntoBind, IntoBindNullable, into_bind_nullable_sealed,
+    into_bind_sealed,
+};
+
+// A bare `Uuid` binds as hyphenated lowercase `TEXT`, the form Snowflake's `UUID_STRING()` produces. Wrap it in
+// `Binary` for `BINARY(16)` columns.
+
+impl IntoBind for Uuid {}
+
+impl into_bind_sealed::Sealed for Uuid {
+    fn into_bind(self) -> Bind {
+        Bind::new(
+            BindType::Text,
+            BindValue::Text(Cow::Owned(self.hyphenated().to_string())),
+        )
+    }
+}
+
+impl IntoBindNullable for Uuid {}
+
+impl into_bind_nullable_sealed::Sealed for Uuid {
+    const DEFAULT_TYPE: BindType = BindType::Text;
+}
+
+impl From<Uuid> for Binary {
+    /// Binds the UUID's 16 bytes, in big-endian order, as `BINARY`.
+    fn from(value: Uuid) -> Self {
+        Self::new(value.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statement::bind::encode_bind;
+
+    const ID: Uuid = uuid::uuid!("67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+    #[test]
+    fn uuid_binds_as_hyphenated_text() {
+        let bind = encode_bind(ID);
+        assert_eq!(bind.ty(), BindType::Text);
+        assert_eq!(
+            bind.value(),
+            Some(&BindValue::Text(
+                "67e55044-10b1-426f-9247-bb680e5fe0c8".into()
+            ))
+        );
+
+        let bind = encode_bind(None::<Uuid>);
+        assert_eq!(bind.ty(), BindType::Text);
+        assert_eq!(bind.value(), None);
+    }
+
+    #[test]
+    fn uuid_binds_as_binary_through_wrapper() {
+        let bind = encode_bind(Binary::from(ID));
+        assert_eq!(bind.ty(), BindType::Binary);
+        assert_eq!(
+            bind.value(),
+            Some(&BindValue::Binary(ID.as_bytes().to_vec()))
+        );
+
+        let bind = encode_bind(None::<Binary>);
+        assert_eq!(bind.ty(), BindType::Binary);
+    }
+}
@@ -0,0 +1,106 @@
+use std::{borrow::Cow, fmt::Write as _};
+
+use super::{
+    Bind, BindType, BindValue, IntoBind, IntoBindNullable, into_bind_nullable_sealed,
+    into_bind_sealed,
+};
+use crate::decode::Vector;
+
+// Snowflake has no `VECTOR` bind type, so a `Vector` binds as `TEXT` holding its JSON array. Non-finite `f32`
+// elements use the `NaN` / `Infinity` / `-Infinity` tokens `PARSE_JSON` accepts.
+
+fn json_array<T: Copy>(values: &[T], mut push: impl FnMut(&mut String, T)) -> String {
+    let mut text = String::with_capacity(2 + values.len() * 8);
+    text.push('[');
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            text.push(',');
+        }
+        push(&mut text, *value);
+    }
+    text.push(']');
+    text
+}
+
+impl IntoBind for Vector<i32> {}
+
+impl into_bind_sealed::Sealed for Vector<i32> {
+    fn into_bind(self) -> Bind {
+        let text = json_array(self.as_slice(), |text, value| {
+            write!(text, "{value}").expect("writing to a String cannot fail");
+        });
+        Bind::new(BindType::Text, BindValue::Text(Cow::Owned(text)))
+    }
+}
+
+impl IntoBindNullable for Vector<i32> {}
+
+impl into_bind_nullable_sealed::Sealed for Vector<i32> {
+    const DEFAULT_TYPE: BindType = BindType::Text;
+}
+
+impl IntoBind for Vector<f32> {}
+
+impl into_bind_sealed::Sealed for Vector<f32> {
+    fn into_bind(self) -> Bind {
+        let text = json_array(self.as_slice(), |text, value| {
+            if value.is_nan() {
+                text.push_str("NaN");
+            } else if value.is_infinite() {
+                text.push_str(if value > 0.0 { "Infinity" } else { "-Infinity" });
+            } else {
+                // `f32`'s `Display` is the shortest text that round-trips, without exponent notation.
+                write!(text, "{value}").expect("writing to a String cannot fail");
+            }
+        });
+        Bind::new(BindType::Text, BindValue::Text(Cow::Owned(text)))
+    }
+}
+
+impl IntoBindNullable for Vector<f32> {}
+
+impl into_bind_nullable_sealed::Sealed for Vector<f32> {
+    const DEFAULT_TYPE: BindType = BindType::Text;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statement::bind::encode_bind;
+
+    #[test]
+    fn int_vector_binds_as_json_array_text() {
+        let bind = encode_bind(Vector::from(vec![1, -2, i32::MAX]));
+        assert_eq!(bind.ty(), BindType::Text);
+        assert_eq!(
+            bind.value(),
+            Some(&BindValue::Text("[1,-2,2147483647]".into()))
+        );
+
+        let bind = encode_bind(None::<Vector<i32>>);
+        assert_eq!(bind.ty(), BindType::Text);
+        assert_eq!(bind.value(), None);
+    }
+
+    #[test]
+    fn float_vector_binds_round_trippable_elements() {
+        let values = vec![0.1f32, -2.0, 1e-30, f32::MAX];
+        let Some(BindValue::Text(text)) =
+            encode_bind(Vector::from(values.clone())).value().cloned()
+        else {
+            panic!("expected a text bind");
+        };
+        let parsed: Vec<f32> = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed, values);
+
+        let bind = encode_bind(Vector::from(vec![
+            f32::NAN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ]));
+        assert_eq!(
+            bind.value(),
+            Some(&BindValue::Text("[NaN,Infinity,-Infinity]".into()))
+        );
+    }
+}
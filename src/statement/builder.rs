@@ -20,15 +20,89 @@ pub struct UnboundBinds;
 /// Typestate marker for a [`Statement`] using positional (`?`) binds.
 ///
 /// While in this state, only [`Statement::bind`] is callable — [`Statement::bind_named`] is rejected at compile time.
-#[derive(Debug, Clone)]
+///
+/// Also built directly by [`IntoBinds`] implementations.
+#[derive(Debug, Clone, Default)]
 pub struct PositionalBinds(Vec<Bind>);
 
+impl PositionalBinds {
+    /// Builds an empty positional bind list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a positional bind.
+    pub fn push<T>(&mut self, value: T)
+    where
+        T: IntoBind,
+    {
+        self.0.push(encode_bind(value));
+    }
+}
+
 /// Typestate marker for a [`Statement`] using named (`:name` / `:1`) binds.
 ///
 /// While in this state, only [`Statement::bind_named`] is callable. Re-binding the same name is last-wins.
-#[derive(Debug, Clone)]
+///
+/// Also built directly by [`IntoBinds`] implementations.
+#[derive(Debug, Clone, Default)]
 pub struct NamedBinds(IndexMap<BindName, Bind>);
 
+impl NamedBinds {
+    /// Builds an empty named bind map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a named bind, last-wins for duplicated names.
+    ///
+    /// Pass the bare placeholder name (no leading `:`), as for [`Statement::bind_named`].
+    pub fn insert<T>(&mut self, name: impl Into<BindName>, value: T)
+    where
+        T: IntoBind,
+    {
+        self.0.insert(name.into(), encode_bind(value));
+    }
+}
+
+/// Converts a value into the binds for one statement, for use with [`Statement::bind_struct`].
+///
+/// Usually implemented with `#[derive(IntoBinds)]` (requires the `derive` feature), which binds each struct field by
+/// name, or by position with `#[snowflake(positional)]`.
+///
+/// # Examples
+///
+/// ```
+/// use snowflake_connector_rs::{IntoBinds, NamedBinds, Statement};
+///
+/// struct NewUser {
+///     id: i64,
+///     name: String,
+/// }
+///
+/// impl IntoBinds for NewUser {
+///     type Binds = NamedBinds;
+///
+///     fn to_binds(&self) -> NamedBinds {
+///         let mut binds = NamedBinds::new();
+///         binds.insert("id", self.id);
+///         binds.insert("name", self.name.clone());
+///         binds
+///     }
+/// }
+///
+/// let user = NewUser { id: 1, name: "alice".to_string() };
+/// let stmt = Statement::new("INSERT INTO users (id, name) VALUES (:id, :name)").bind_struct(&user);
+/// assert_eq!(stmt.sql(), "INSERT INTO users (id, name) VALUES (:id, :name)");
+/// ```
+pub trait IntoBinds {
+    /// The bind mode produced: [`NamedBinds`] or [`PositionalBinds`].
+    type Binds;
+
+    /// Returns the binds for `self`.
+    fn to_binds(&self) -> Self::Binds;
+}
+
 /// Builder for a SQL statement and its bind values, accepted by
 /// [`Session::query`](crate::Session::query) / [`Session::query_as`](crate::Session::query_as).
 ///
@@ -150,6 +224,39 @@ impl Statement<UnboundBinds> {
             bindings: NamedBinds(bindings),
         }
     }
+
+    /// Binds every value produced by `value`'s [`IntoBinds`] implementation, transitioning to its bind mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use snowflake_connector_rs::{IntoBinds, PositionalBinds, Statement};
+    ///
+    /// struct Point(i64, i64);
+    ///
+    /// impl IntoBinds for Point {
+    ///     type Binds = PositionalBinds;
+    ///
+    ///     fn to_binds(&self) -> PositionalBinds {
+    ///         let mut binds = PositionalBinds::new();
+    ///         binds.push(self.0);
+    ///         binds.push(self.1);
+    ///         binds
+    ///     }
+    /// }
+    ///
+    /// let stmt = Statement::new("INSERT INTO points VALUES (?, ?)").bind_struct(&Point(1, 2));
+    /// assert_eq!(stmt.sql(), "INSERT INTO points VALUES (?, ?)");
+    /// ```
+    pub fn bind_struct<T>(self, value: &T) -> Statement<T::Binds>
+    where
+        T: IntoBinds + ?Sized,
+    {
+        Statement {
+            sql: self.sql,
+            bindings: value.to_binds(),
+        }
+    }
 }
 
 impl Statement<PositionalBinds> {
@@ -197,6 +304,15 @@ impl Statement<PositionalBinds> {
         self.bindings.0.push(encode_bind(value));
         self
     }
+
+    /// Appends every positional bind produced by `value`'s [`IntoBinds`] implementation.
+    pub fn bind_struct<T>(mut self, value: &T) -> Self
+    where
+        T: IntoBinds<Binds = PositionalBinds> + ?Sized,
+    {
+        self.bindings.0.extend(value.to_binds().0);
+        self
+    }
 }
 
 impl Statement<NamedBinds> {
@@ -243,6 +359,15 @@ impl Statement<NamedBinds> {
         self.bindings.0.insert(name.into(), encode_bind(value));
         self
     }
+
+    /// Inserts every named bind produced by `value`'s [`IntoBinds`] implementation, last-wins for duplicated names.
+    pub fn bind_struct<T>(mut self, value: &T) -> Self
+    where
+        T: IntoBinds<Binds = NamedBinds> + ?Sized,
+    {
+        self.bindings.0.extend(value.to_binds().0);
+        self
+    }
 }
 
 impl<M> Statement<M> {
@@ -477,6 +602,107 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "derive")]
+    #[test]
+    fn bind_struct_uses_derived_named_binds() {
+        #[derive(crate::IntoBinds)]
+        #[snowflake(crate = "crate")]
+        struct NewUser {
+            id: i64,
+            #[snowflake(rename = "user_name")]
+            name: String,
+            #[snowflake(skip)]
+            #[allow(dead_code)]
+            cached: Vec<u8>,
+            note: Option<String>,
+        }
+
+        let user = NewUser {
+            id: 7,
+            name: "alice".to_string(),
+            cached: vec![1],
+            note: None,
+        };
+        let statement = Statement::new("INSERT INTO t VALUES (:id, :user_name, :note)")
+            .bind_struct(&user)
+            .bind_named("id", 8_i64);
+
+        match into_statement_parts(statement).unwrap().repr() {
+            StatementPartsRepr::Named { bindings, .. } => {
+                let names: Vec<_> = bindings.keys().map(BindName::as_str).collect();
+                assert_eq!(names, ["id", "user_name", "note"]);
+                assert_eq!(bindings[0].value(), Some(&BindValue::Fixed(8)));
+                assert_eq!(
+                    bindings[1].value(),
+                    Some(&BindValue::Text(Cow::Owned("alice".to_string())))
+                );
+                assert_eq!(bindings[2].value(), None);
+            }
+            _ => panic!("expected named statement"),
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn bind_struct_uses_derived_positional_binds() {
+        #[derive(crate::IntoBinds)]
+        #[snowflake(crate = "crate", positional)]
+        struct Point {
+            x: i64,
+            #[snowflake(skip)]
+            #[allow(dead_code)]
+            label: String,
+            y: i64,
+        }
+
+        #[derive(crate::IntoBinds)]
+        #[snowflake(crate = "crate")]
+        struct Pair(bool, &'static str);
+
+        let statement = Statement::new("SELECT ?, ?, ?, ?")
+            .bind_struct(&Point {
+                x: 1,
+                label: "unused".to_string(),
+                y: 2,
+            })
+            .bind_struct(&Pair(true, "z"));
+
+        match into_statement_parts(statement).unwrap().repr() {
+            StatementPartsRepr::Positional { bindings, .. } => {
+                let values: Vec<_> = bindings.iter().map(Bind::value).collect();
+                assert_eq!(
+                    values,
+                    [
+                        Some(&BindValue::Fixed(1)),
+                        Some(&BindValue::Fixed(2)),
+                        Some(&BindValue::Bool(true)),
+                        Some(&BindValue::Text(Cow::Borrowed("z"))),
+                    ]
+                );
+            }
+            _ => panic!("expected positional statement"),
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived_named_binds_honor_rename_all() {
+        #[derive(crate::IntoBinds)]
+        #[snowflake(crate = "crate", rename_all = "SCREAMING_SNAKE_CASE")]
+        struct Filter {
+            created_at: &'static str,
+            r#type: &'static str,
+        }
+
+        let binds = Filter {
+            created_at: "2024-01-01",
+            r#type: "a",
+        }
+        .to_binds();
+        let names: Vec<_> = binds.0.keys().map(BindName::as_str).collect();
+        assert_eq!(names, ["CREATED_AT", "TYPE"]);
+    }
+
     #[test]
     fn statement_clone_allows_reuse() {
         let statement = Statement::new("SELECT ?").bind(1_i64);
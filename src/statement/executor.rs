@@ -1,4 +1,8 @@
-use std::{num::NonZeroUsize, sync::Arc, time::Duration};
+use std::{
+    num::NonZeroUsize,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     QueryExecutionSettings,
@@ -6,8 +10,10 @@ use crate::{
         CancelledError, ProtocolError, QueryScopedResult, ServerError, SessionExpiredError,
         with_optional_query_id,
     },
+    observer::QueryCompleteEvent,
     result_cursor::{CollectPolicy, RemotePartitionSource, ResultCursor},
     runtime::QueryRuntime,
+    session::SessionParameters,
     statement::StatementParts,
     {Error, Result, Session},
 };
@@ -29,6 +35,7 @@ pub(crate) struct StatementExecutor {
     query_cancel_request_timeout: Duration,
     default_collect_concurrency: NonZeroUsize,
     runtime: QueryRuntime,
+    session_parameters: Arc<SessionParameters>,
 }
 
 impl StatementExecutor {
@@ -40,6 +47,7 @@ impl StatementExecutor {
             query_cancel_request_timeout: settings.query_cancel_request_timeout,
             default_collect_concurrency: settings.collect_prefetch_concurrency,
             runtime: session.shared.runtime.clone(),
+            session_parameters: Arc::clone(&session.parameters),
         }
     }
 
@@ -51,10 +59,47 @@ impl StatementExecutor {
         self.query_cancel_request_timeout
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "snowflake.query",
+            skip_all,
+            fields(
+                request_id = %control.query_request_id(),
+                query_id = tracing::field::Empty,
+                partitions = tracing::field::Empty,
+            ),
+            err(Display)
+        )
+    )]
     pub(crate) async fn execute(
         self,
         parts: StatementParts,
         control: Arc<QueryControl>,
+    ) -> Result<ResultCursor> {
+        let started_at = Instant::now();
+        let observer = self.runtime.observer().clone();
+        let result = self.submit_and_wait(parts, &control).await;
+        observer.notify(|observer| {
+            observer.on_complete(&QueryCompleteEvent {
+                request_id: Arc::from(control.query_request_id()),
+                query_id: control.query_id(),
+                elapsed: started_at.elapsed(),
+                row_count: result
+                    .as_ref()
+                    .ok()
+                    .and_then(ResultCursor::announced_row_count),
+                partition_count: result.as_ref().map_or(0, ResultCursor::partition_count),
+                error: result.as_ref().err().map(Error::kind),
+            })
+        });
+        result
+    }
+
+    async fn submit_and_wait(
+        self,
+        parts: StatementParts,
+        control: &Arc<QueryControl>,
     ) -> Result<ResultCursor> {
         let deadline = QueryResponseDeadline::new(self.query_response_timeout);
 
@@ -74,6 +119,8 @@ impl StatementExecutor {
 
         let mut response = self.api.send_prepared_submit(prepared, deadline).await?;
         if let Some(data) = response.data.as_ref() {
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("query_id", &*data.query_id);
             guard.record_query_id(Arc::clone(&data.query_id));
         }
 
@@ -107,7 +154,7 @@ impl StatementExecutor {
         // Mark terminality before building the local result manifest. Local schema/manifest failures do not make a
         // completed server-side query look ambiguous.
         guard.mark_terminal();
-        self.finish_response(response, &control)
+        self.finish_response(response, control)
     }
 
     fn finish_response(
@@ -160,10 +207,20 @@ impl StatementExecutor {
         self.build_result_set(data).map_err(Error::from)
     }
 
-    fn build_result_set(self, data: WireQueryData) -> QueryScopedResult<ResultCursor> {
+    fn build_result_set(self, mut data: WireQueryData) -> QueryScopedResult<ResultCursor> {
+        data.session_timezone = self
+            .session_parameters
+            .observe_timezone(data.session_timezone.take());
         let manifest = ResultManifest::try_from(data)?;
-
-        let source = RemotePartitionSource::new(manifest.lease, self.chunk_http);
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("partitions", manifest.snapshot.partitions.len());
+
+        let source = RemotePartitionSource::new(
+            manifest.lease,
+            self.chunk_http,
+            self.api.retry_policy().clone(),
+            self.runtime.observer().clone(),
+        );
         let default_collect_policy = CollectPolicy::new(self.default_collect_concurrency);
 
         Ok(ResultCursor::new(
@@ -202,6 +259,7 @@ mod tests {
             builder::into_statement_parts,
             wire::response::{WireQueryData, WireRowType},
         },
+        test_support::observer::{ObservedEvent, RecordingObserver},
     };
 
     fn default_settings(session: &Session) -> QueryExecutionSettings {
@@ -222,6 +280,7 @@ mod tests {
         Session {
             shared: ClientSharedPartial::new().with_base_url(base_url).build(),
             auth: SessionAuth::for_test("test-token"),
+            parameters: Arc::default(),
         }
     }
 
@@ -233,6 +292,7 @@ mod tests {
             query_cancel_request_timeout: DEFAULT_QUERY_CANCEL_REQUEST_TIMEOUT,
             default_collect_concurrency: NonZeroUsize::new(1).unwrap(),
             runtime: QueryRuntime::new(),
+            session_parameters: Arc::default(),
         }
     }
 
@@ -244,6 +304,7 @@ mod tests {
             query_cancel_request_timeout: DEFAULT_QUERY_CANCEL_REQUEST_TIMEOUT,
             default_collect_concurrency: NonZeroUsize::new(1).unwrap(),
             runtime: QueryRuntime::new(),
+            session_parameters: Arc::default(),
         }
     }
 
@@ -334,6 +395,8 @@ mod tests {
             length: Some(16),
             precision: None,
             data_type: "text".to_string(),
+            fields: None,
+            vector_dimension: None,
         }
     }
 
@@ -349,6 +412,7 @@ mod tests {
             qrmk: None,
             chunks: None,
             query_result_format: Some("json".to_string()),
+            session_timezone: None,
         }
     }
 
@@ -381,6 +445,7 @@ mod tests {
                 .with_runtime(runtime.clone())
                 .build(),
             auth: SessionAuth::for_test("test-token"),
+            parameters: Arc::default(),
         };
 
         let executor = StatementExecutor::new(&session, default_settings(&session));
@@ -562,6 +627,138 @@ mod tests {
         assert_eq!(table.row_count(), 1);
     }
 
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn execute_spans_carry_ids_but_not_token_or_bind_values() {
+        let trace = crate::test_support::tracing::CapturedTrace::default();
+        let _guard = trace.set_default();
+        let executor = executor_with_timeout(
+            spawn_scripted_server(vec![
+                ServerStep::Respond(ASYNC_SUBMIT_RESPONSE),
+                ServerStep::Respond(FINAL_INLINE_RESPONSE),
+            ]),
+            Duration::from_secs(30),
+        );
+        let parts = into_statement_parts(Statement::new("select ?").bind("bind-secret")).unwrap();
+
+        let mut result = executor.execute(parts, fresh_control()).await.unwrap();
+        result
+            .next_table()
+            .await
+            .unwrap()
+            .expect("inline partition");
+
+        let names = trace.span_names();
+        for name in [
+            "snowflake.query",
+            "snowflake.submit",
+            "snowflake.poll",
+            "snowflake.parse",
+        ] {
+            assert!(names.contains(&name), "missing span {name}: {names:?}");
+        }
+        assert_eq!(
+            trace.span_field("snowflake.query", "request_id").as_deref(),
+            Some("query-request-id")
+        );
+        assert_eq!(
+            trace.span_field("snowflake.query", "query_id").as_deref(),
+            Some("query-id")
+        );
+        assert_eq!(
+            trace.span_field("snowflake.query", "partitions").as_deref(),
+            Some("1")
+        );
+        for value in trace.all_values() {
+            assert!(!value.contains("test-token"), "token leaked: {value}");
+            assert!(!value.contains("bind-secret"), "bind value leaked: {value}");
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_reports_lifecycle_events_to_observer() {
+        let recorder = RecordingObserver::default();
+        let base_url = spawn_scripted_server(vec![
+            ServerStep::Respond(ASYNC_SUBMIT_RESPONSE),
+            ServerStep::Respond(
+                r#"{"success":true,"data":{"queryId":"query-id","total":1,"rowset":[["x"]],"rowtype":[{"name":"X","nullable":false,"length":16,"type":"text"}],"queryResultFormat":"json"}}"#,
+            ),
+        ]);
+        let executor = StatementExecutor {
+            api: QueryApiClient::new(
+                Arc::new(
+                    ApiContext::new(reqwest::Client::new(), base_url)
+                        .with_observer(recorder.handle()),
+                ),
+                SessionAuth::for_test("test-token"),
+            ),
+            runtime: QueryRuntime::new().with_observer(recorder.handle()),
+            ..executor_with_timeout(
+                Url::parse("https://example.com/").unwrap(),
+                Duration::from_secs(30),
+            )
+        };
+
+        let mut result = executor
+            .execute(select_1_parts(), fresh_control())
+            .await
+            .unwrap();
+        result
+            .next_table()
+            .await
+            .unwrap()
+            .expect("inline partition");
+
+        let events = recorder.events();
+        let [
+            ObservedEvent::Submit(submit),
+            ObservedEvent::FirstResponse(first),
+            ObservedEvent::Poll(poll),
+            ObservedEvent::Complete(complete),
+            ObservedEvent::Parse(parse),
+        ] = events.as_slice()
+        else {
+            panic!("unexpected observer events: {events:?}");
+        };
+        assert_eq!(submit.request_id(), "query-request-id");
+        assert!(submit.body_bytes() > 0);
+        assert_eq!(first.query_id(), Some("query-id"));
+        assert!(first.in_progress());
+        assert_eq!(first.response_bytes(), ASYNC_SUBMIT_RESPONSE.len());
+        assert_eq!(poll.iteration(), 1);
+        assert!(!poll.in_progress());
+        assert_eq!(complete.query_id(), Some("query-id"));
+        assert_eq!(complete.error(), None);
+        assert_eq!(complete.partition_count(), 1);
+        assert_eq!(complete.row_count(), Some(1));
+        assert_eq!(parse.row_count(), 1);
+        assert!(!parse.blocking());
+    }
+
+    #[tokio::test]
+    async fn execute_reports_failed_completion_to_observer() {
+        let recorder = RecordingObserver::default();
+        let session = test_session(spawn_single_response_server(
+            r#"{"success":false,"code":"002003","message":"boom","data":{"queryId":"query-id"}}"#,
+        ));
+        let mut executor = StatementExecutor::new(&session, default_settings(&session));
+        executor.runtime = QueryRuntime::new().with_observer(recorder.handle());
+
+        let err = match executor.execute(select_1_parts(), fresh_control()).await {
+            Ok(_) => panic!("expected statement execution to fail"),
+            Err(err) => err,
+        };
+
+        let events = recorder.events();
+        let [ObservedEvent::Complete(complete)] = events.as_slice() else {
+            panic!("unexpected observer events: {events:?}");
+        };
+        assert_eq!(complete.error(), Some(err.kind()));
+        assert_eq!(complete.query_id(), Some("query-id"));
+        assert_eq!(complete.partition_count(), 0);
+        assert_eq!(complete.row_count(), None);
+    }
+
     #[tokio::test]
     async fn execute_unsupported_result_format_preserves_query_id() {
         let session = test_session(spawn_single_response_server(
@@ -689,6 +886,7 @@ mod tests {
             query_cancel_request_timeout: DEFAULT_QUERY_CANCEL_REQUEST_TIMEOUT,
             default_collect_concurrency: NonZeroUsize::new(1).unwrap(),
             runtime,
+            session_parameters: Arc::default(),
         };
         let response = WireQueryData {
             query_id: Arc::from("query-id"),
@@ -701,6 +899,7 @@ mod tests {
             qrmk: None,
             chunks: None,
             query_result_format: Some("json".to_string()),
+            session_timezone: None,
         };
 
         let mut result = match timeout(Duration::from_millis(20), async move {
@@ -734,6 +933,7 @@ mod tests {
             query_cancel_request_timeout: DEFAULT_QUERY_CANCEL_REQUEST_TIMEOUT,
             default_collect_concurrency: NonZeroUsize::new(1).unwrap(),
             runtime,
+            session_parameters: Arc::default(),
         };
         let response = WireQueryData {
             query_id: Arc::from("query-id"),
@@ -746,6 +946,7 @@ mod tests {
             qrmk: None,
             chunks: None,
             query_result_format: Some("json".to_string()),
+            session_timezone: None,
         };
 
         let mut result = executor.build_result_set(response).unwrap();
@@ -774,6 +975,7 @@ mod tests {
             query_cancel_request_timeout: DEFAULT_QUERY_CANCEL_REQUEST_TIMEOUT,
             default_collect_concurrency: NonZeroUsize::new(1).unwrap(),
             runtime,
+            session_parameters: Arc::default(),
         };
         let response = WireQueryData {
             query_id: Arc::from("query-id"),
@@ -786,6 +988,7 @@ mod tests {
             qrmk: None,
             chunks: None,
             query_result_format: Some("json".to_string()),
+            session_timezone: None,
         };
 
         let result = executor.build_result_set(response).unwrap();
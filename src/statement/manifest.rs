@@ -9,7 +9,7 @@ use crate::{
     rowset::parser::inline_rowset_has_rows_inner,
 };
 
-use super::wire::response::{WireQueryData, resolve_download_headers};
+use super::wire::response::{WireQueryData, WireRowType, resolve_download_headers};
 
 pub(crate) struct ResultManifest {
     pub(crate) snapshot: Arc<ResultSnapshot>,
@@ -30,6 +30,7 @@ impl TryFrom<WireQueryData> for ResultManifest {
             chunk_headers,
             qrmk,
             chunks,
+            session_timezone,
             ..
         } = value;
 
@@ -75,17 +76,10 @@ impl TryFrom<WireQueryData> for ResultManifest {
             .unwrap_or_default()
             .into_iter()
             .enumerate()
-            .map(|(index, row_type)| {
-                let ty = ColumnType::from_driver_metadata(
-                    &row_type.data_type,
-                    row_type.length,
-                    row_type.precision,
-                    row_type.scale,
-                );
-                Column::new(row_type.name, index, row_type.nullable, ty)
-            })
+            .map(|(index, row_type)| wire_column(index, row_type))
             .collect();
-        let schema = Arc::new(Schema::from_columns(columns));
+        let schema =
+            Arc::new(Schema::from_columns(columns).with_session_timezone(session_timezone));
 
         let download_headers = match resolve_download_headers(&qrmk, &chunk_headers) {
             Ok(headers) => headers,
@@ -134,6 +128,26 @@ impl TryFrom<WireQueryData> for ResultManifest {
     }
 }
 
+/// Build a column from its wire metadata, recursing into the `fields` of structured types.
+fn wire_column(index: usize, row_type: WireRowType) -> Column {
+    let fields = row_type
+        .fields
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| wire_column(index, field))
+        .collect();
+    let ty = ColumnType::from_driver_metadata(
+        &row_type.data_type,
+        row_type.length,
+        row_type.precision,
+        row_type.scale,
+        fields,
+        row_type.vector_dimension,
+    );
+    Column::new(row_type.name, index, row_type.nullable, ty)
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
@@ -160,6 +174,7 @@ mod tests {
             qrmk: None,
             chunks,
             query_result_format: Some("json".to_string()),
+            session_timezone: None,
         }
     }
 
@@ -187,6 +202,8 @@ mod tests {
             length: Some(16),
             precision: None,
             data_type: "text".to_string(),
+            fields: None,
+            vector_dimension: None,
         }
     }
 
@@ -240,4 +257,71 @@ mod tests {
             Some(PartitionSpec::Inline)
         ));
     }
+
+    #[test]
+    fn wire_column_parses_structured_field_metadata() {
+        let row_type: WireRowType = serde_json::from_str(
+            r#"{"name":"ADDR","type":"object","nullable":true,"scale":null,"length":null,"precision":null,"fields":[
+                {"name":"CITY","type":"text","nullable":true,"length":16777216,"scale":null,"precision":null},
+                {"name":"VISITS","type":"array","nullable":false,"length":null,"scale":null,"precision":null,"fields":[
+                    {"name":"","type":"date","nullable":true,"length":null,"scale":null,"precision":null}
+                ]},
+                {"name":"TAGS","type":"map","nullable":true,"length":null,"scale":null,"precision":null,"fields":[
+                    {"name":"key","type":"text","nullable":false,"length":16,"scale":null,"precision":null},
+                    {"name":"value","type":"fixed","nullable":true,"length":null,"scale":0,"precision":38}
+                ]}
+            ]}"#,
+        )
+        .unwrap();
+
+        let column = wire_column(3, row_type);
+        assert_eq!(column.index(), 3);
+        let ColumnType::Object {
+            fields: Some(fields),
+        } = column.ty()
+        else {
+            panic!("expected structured OBJECT, got {}", column.ty());
+        };
+        assert_eq!(
+            fields.iter().map(Column::name).collect::<Vec<_>>(),
+            ["CITY", "VISITS", "TAGS"]
+        );
+        assert!(!fields[1].is_nullable());
+        assert_eq!(
+            column.ty().to_string(),
+            "object(CITY text(16777216), VISITS array(date), TAGS map(text(16), fixed(38,0)))"
+        );
+    }
+
+    #[test]
+    fn wire_column_parses_vector_element_and_dimension() {
+        let row_type: WireRowType = serde_json::from_str(
+            r#"{"name":"EMBEDDING","type":"vector","nullable":true,"scale":null,"length":null,"precision":null,
+                "vectorDimension":256,"fields":[
+                    {"name":"","type":"real","nullable":false,"length":null,"scale":null,"precision":null}
+                ]}"#,
+        )
+        .unwrap();
+
+        let column = wire_column(0, row_type);
+        assert_eq!(
+            column.ty(),
+            &ColumnType::Vector {
+                element: Some(crate::result_table::VectorElementType::Float),
+                dimension: Some(256),
+            }
+        );
+    }
+
+    #[test]
+    fn manifest_stamps_session_timezone_onto_schema() {
+        let mut query_data = query_data_with_result_data(None, Some(Vec::new()), None);
+        query_data.session_timezone = Some(Arc::from("Asia/Tokyo"));
+
+        let manifest = ResultManifest::try_from(query_data).unwrap();
+        assert_eq!(
+            manifest.snapshot.schema.session_timezone(),
+            Some("Asia/Tokyo")
+        );
+    }
 }
@@ -16,6 +16,7 @@ const QUERY_CANCELLED_SQL_STATE: &str = "57014";
 const HEADER_SSE_C_ALGORITHM: &str = "x-amz-server-side-encryption-customer-algorithm";
 const HEADER_SSE_C_KEY: &str = "x-amz-server-side-encryption-customer-key";
 const AES256: &str = "AES256";
+const TIMEZONE_PARAMETER: &str = "TIMEZONE";
 
 #[derive(Debug)]
 pub(crate) struct WireQueryResponse {
@@ -35,7 +36,7 @@ impl WireQueryResponse {
 }
 
 // Response metadata ignored by the runtime is omitted from the deserialization DTOs:
-// - data.parameters: [{ "name": "...", "value": ... }]
+// - data.parameters[] other than TIMEZONE
 // - data.rowtype[]: { "database": "...", "schema": "...", "table": "...", "byteLength": ... }
 #[derive(Debug)]
 pub(crate) struct WireQueryData {
@@ -49,6 +50,8 @@ pub(crate) struct WireQueryData {
     pub(crate) qrmk: Option<String>,
     pub(crate) chunks: Option<Vec<WireChunk>>,
     pub(crate) query_result_format: Option<String>,
+    /// The session `TIMEZONE` parameter, when `data.parameters` reports it.
+    pub(crate) session_timezone: Option<Arc<str>>,
 }
 
 #[derive(Deserialize)]
@@ -77,6 +80,31 @@ struct BorrowedWireQueryData<'a> {
     qrmk: Option<String>,
     chunks: Option<Vec<WireChunk>>,
     query_result_format: Option<String>,
+    parameters: Option<Vec<WireParameter>>,
+}
+
+/// A `{ "name": ..., "value": ... }` entry of `data.parameters`, as reported by login and query responses.
+///
+/// Both fields are optional so that one malformed entry does not fail the whole response; such entries are skipped.
+#[derive(Debug, Deserialize)]
+pub(crate) struct WireParameter {
+    name: Option<String>,
+    value: Option<serde_json::Value>,
+}
+
+/// The `TIMEZONE` session parameter among `parameters`, if present as a string.
+pub(crate) fn session_timezone(parameters: &[WireParameter]) -> Option<Arc<str>> {
+    parameters
+        .iter()
+        .find(|parameter| {
+            parameter.value.is_some()
+                && parameter
+                    .name
+                    .as_deref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(TIMEZONE_PARAMETER))
+        })
+        .and_then(|parameter| parameter.value.as_ref()?.as_str())
+        .map(Arc::from)
 }
 
 #[derive(Debug, Deserialize)]
@@ -113,6 +141,7 @@ pub(crate) fn parse_query_response(body: Bytes) -> Result<WireQueryResponse, Pro
                 qrmk: d.qrmk,
                 chunks: d.chunks,
                 query_result_format: d.query_result_format,
+                session_timezone: d.parameters.as_deref().and_then(session_timezone),
             })
         })
         .transpose()?;
@@ -169,6 +198,10 @@ pub(crate) struct WireRowType {
     pub(crate) precision: Option<u8>,
     #[serde(rename = "type")]
     pub(crate) data_type: String,
+    /// Nested field metadata of a structured `ARRAY`, `OBJECT`, or `MAP` column, or the element of a `VECTOR`.
+    pub(crate) fields: Option<Vec<WireRowType>>,
+    /// Element count of a `VECTOR` column.
+    pub(crate) vector_dimension: Option<u32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -291,6 +324,19 @@ mod tests {
         assert_eq!(row_type.data_type, "fixed");
     }
 
+    #[test]
+    fn parse_response_extracts_session_timezone_parameter() {
+        let body = Bytes::from(
+            r#"{"data":{"queryId":"q1","parameters":[{"name":"CLIENT_PREFETCH_THREADS","value":4},{"name":"TIMEZONE","value":"Asia/Tokyo"}]},"success":true}"#,
+        );
+        let data = parse_query_response(body).unwrap().data.expect("data");
+        assert_eq!(data.session_timezone.as_deref(), Some("Asia/Tokyo"));
+
+        let body = Bytes::from(r#"{"data":{"queryId":"q1","parameters":[]},"success":true}"#);
+        let data = parse_query_response(body).unwrap().data.expect("data");
+        assert_eq!(data.session_timezone, None);
+    }
+
     #[test]
     fn parse_response_extracts_zero_copy_rowset_slice() {
         let body = Bytes::from(
@@ -55,12 +55,25 @@ pub(crate) async fn write_json_response(
     stream: &mut TcpStream,
     status: StatusCode,
     body: &str,
+) -> io::Result<()> {
+    write_json_response_with_headers(stream, status, &[], body).await
+}
+
+pub(crate) async fn write_json_response_with_headers(
+    stream: &mut TcpStream,
+    status: StatusCode,
+    headers: &[(&str, &str)],
+    body: &str,
 ) -> io::Result<()> {
     let reason = status.canonical_reason().unwrap_or("");
+    let extra_headers = headers
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}\r\n"))
+        .collect::<String>();
     stream
         .write_all(
             format!(
-                "HTTP/1.1 {} {reason}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                "HTTP/1.1 {} {reason}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n{extra_headers}connection: close\r\n\r\n{body}",
                 status.as_u16(),
                 body.len(),
             )
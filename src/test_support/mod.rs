@@ -1,3 +1,7 @@
 //! Crate-wide helpers shared by unit tests.
 
 pub(crate) mod http;
+pub(crate) mod observer;
+pub(crate) mod tls;
+#[cfg(feature = "tracing")]
+pub(crate) mod tracing;
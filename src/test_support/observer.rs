@@ -0,0 +1,68 @@
+//! A [`QueryObserver`] that keeps every event it receives, for assertions.
+
+use std::sync::{Arc, Mutex};
+
+use crate::observer::{
+    FirstResponseEvent, ObserverHandle, ParseEvent, PartitionDownloadEvent, PollEvent,
+    QueryCompleteEvent, QueryObserver, RetryEvent, SubmitEvent,
+};
+
+#[derive(Debug, Clone)]
+pub(crate) enum ObservedEvent {
+    Submit(SubmitEvent),
+    FirstResponse(FirstResponseEvent),
+    Poll(PollEvent),
+    PartitionDownloaded(PartitionDownloadEvent),
+    Parse(ParseEvent),
+    Retry(RetryEvent),
+    Complete(QueryCompleteEvent),
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct RecordingObserver {
+    events: Arc<Mutex<Vec<ObservedEvent>>>,
+}
+
+impl RecordingObserver {
+    pub(crate) fn handle(&self) -> ObserverHandle {
+        ObserverHandle::new(self.clone())
+    }
+
+    pub(crate) fn events(&self) -> Vec<ObservedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    fn push(&self, event: ObservedEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl QueryObserver for RecordingObserver {
+    fn on_submit(&self, event: &SubmitEvent) {
+        self.push(ObservedEvent::Submit(event.clone()));
+    }
+
+    fn on_first_response(&self, event: &FirstResponseEvent) {
+        self.push(ObservedEvent::FirstResponse(event.clone()));
+    }
+
+    fn on_poll(&self, event: &PollEvent) {
+        self.push(ObservedEvent::Poll(event.clone()));
+    }
+
+    fn on_partition_downloaded(&self, event: &PartitionDownloadEvent) {
+        self.push(ObservedEvent::PartitionDownloaded(event.clone()));
+    }
+
+    fn on_parse(&self, event: &ParseEvent) {
+        self.push(ObservedEvent::Parse(event.clone()));
+    }
+
+    fn on_retry(&self, event: &RetryEvent) {
+        self.push(ObservedEvent::Retry(event.clone()));
+    }
+
+    fn on_complete(&self, event: &QueryCompleteEvent) {
+        self.push(ObservedEvent::Complete(event.clone()));
+    }
+}
@@ -0,0 +1,106 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+use tokio_rustls::TlsAcceptor;
+use url::Url;
+
+use crate::TransportConfig;
+
+pub(crate) const CA_PEM: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/tls/ca.pem"
+));
+#[cfg(feature = "ocsp")]
+pub(crate) const CA_KEY: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/tls/ca.key"
+));
+pub(crate) const SERVER_PEM: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/tls/server.pem"
+));
+pub(crate) const SERVER_KEY: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/tls/server.key"
+));
+pub(crate) const CLIENT_PEM: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/tls/client.pem"
+));
+pub(crate) const CLIENT_KEY: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/tls/client.key"
+));
+
+pub(crate) fn cert_der(pem: &str) -> CertificateDer<'static> {
+    CertificateDer::from_pem_slice(pem.as_bytes()).expect("fixture certificate must parse")
+}
+
+/// Serve one HTTPS request with the fixture server certificate (issued by the fixture CA for `localhost`), optionally
+/// requiring a client certificate issued by the same CA.
+pub(crate) async fn spawn_https_server(require_client_cert: bool) -> (SocketAddr, JoinHandle<()>) {
+    spawn_https_server_for(require_client_cert, 1).await
+}
+
+/// Like [`spawn_https_server`], but accepting `connections` connections in turn.
+pub(crate) async fn spawn_https_server_for(
+    require_client_cert: bool,
+    connections: usize,
+) -> (SocketAddr, JoinHandle<()>) {
+    let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+    let builder = rustls::ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_safe_default_protocol_versions()
+        .unwrap();
+    let builder = if require_client_cert {
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(cert_der(CA_PEM)).unwrap();
+        builder.with_client_cert_verifier(
+            rustls::server::WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .unwrap(),
+        )
+    } else {
+        builder.with_no_client_auth()
+    };
+    let config = builder
+        .with_single_cert(
+            vec![cert_der(SERVER_PEM)],
+            PrivateKeyDer::from_pem_slice(SERVER_KEY.as_bytes()).unwrap(),
+        )
+        .unwrap();
+
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+    let server = tokio::spawn(async move {
+        for _ in 0..connections {
+            let (socket, _) = listener.accept().await.unwrap();
+            // A rejected handshake is an expected outcome for some tests; the client reports it.
+            let Ok(mut stream) = acceptor.accept(socket).await else {
+                continue;
+            };
+            let mut buf = [0_u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\nok")
+                .await;
+            let _ = stream.shutdown().await;
+        }
+    });
+    (addr, server)
+}
+
+/// Issue a GET against a server started by [`spawn_https_server`] with a client built from `transport`.
+pub(crate) async fn https_get(
+    transport: TransportConfig,
+    addr: SocketAddr,
+) -> reqwest::Result<String> {
+    let client = transport.build_http_client().unwrap();
+    let url = Url::parse(&format!("https://localhost:{}/", addr.port())).unwrap();
+    client.get(url).send().await?.text().await
+}
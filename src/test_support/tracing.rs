@@ -0,0 +1,136 @@
+//! A minimal `tracing` subscriber that records span names, field values, and events for assertions.
+
+use std::{
+    cell::RefCell,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use tracing::{
+    Event, Id, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Record},
+};
+use tracing_core::span::Current;
+
+thread_local! {
+    /// Entered spans on this thread, so that `Span::current()` resolves inside instrumented code.
+    static ENTERED: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct CapturedTrace {
+    inner: Arc<Mutex<Captured>>,
+}
+
+#[derive(Default)]
+struct Captured {
+    spans: Vec<CapturedSpan>,
+    events: Vec<Vec<(String, String)>>,
+}
+
+struct CapturedSpan {
+    metadata: &'static Metadata<'static>,
+    name: &'static str,
+    fields: Vec<(String, String)>,
+}
+
+impl CapturedTrace {
+    /// Install this subscriber for the current thread until the guard is dropped.
+    pub(crate) fn set_default(&self) -> tracing::subscriber::DefaultGuard {
+        tracing::subscriber::set_default(self.clone())
+    }
+
+    /// The recorded value of `field` on the first span named `name`.
+    pub(crate) fn span_field(&self, name: &str, field: &str) -> Option<String> {
+        let captured = self.inner.lock().unwrap();
+        let span = captured.spans.iter().find(|span| span.name == name)?;
+        span.fields
+            .iter()
+            .rev()
+            .find(|(key, _)| key == field)
+            .map(|(_, value)| value.clone())
+    }
+
+    pub(crate) fn span_names(&self) -> Vec<&'static str> {
+        let captured = self.inner.lock().unwrap();
+        captured.spans.iter().map(|span| span.name).collect()
+    }
+
+    /// Every recorded span and event field value, for leak checks.
+    pub(crate) fn all_values(&self) -> Vec<String> {
+        let captured = self.inner.lock().unwrap();
+        captured
+            .spans
+            .iter()
+            .flat_map(|span| span.fields.iter())
+            .chain(captured.events.iter().flatten())
+            .map(|(_, value)| value.clone())
+            .collect()
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_owned(), value.to_owned()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push((field.name().to_owned(), format!("{value:?}")));
+    }
+}
+
+impl Subscriber for CapturedTrace {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let mut fields = Vec::new();
+        attributes.record(&mut FieldVisitor(&mut fields));
+        let mut captured = self.inner.lock().unwrap();
+        captured.spans.push(CapturedSpan {
+            metadata: attributes.metadata(),
+            name: attributes.metadata().name(),
+            fields,
+        });
+        Id::from_u64(captured.spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut captured = self.inner.lock().unwrap();
+        let span = &mut captured.spans[span.into_u64() as usize - 1];
+        values.record(&mut FieldVisitor(&mut span.fields));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Vec::new();
+        event.record(&mut FieldVisitor(&mut fields));
+        self.inner.lock().unwrap().events.push(fields);
+    }
+
+    fn enter(&self, span: &Id) {
+        ENTERED.with(|entered| entered.borrow_mut().push(span.into_u64()));
+    }
+
+    fn exit(&self, span: &Id) {
+        ENTERED.with(|entered| {
+            let mut entered = entered.borrow_mut();
+            if let Some(position) = entered.iter().rposition(|id| *id == span.into_u64()) {
+                entered.remove(position);
+            }
+        });
+    }
+
+    fn current_span(&self) -> Current {
+        let Some(id) = ENTERED.with(|entered| entered.borrow().last().copied()) else {
+            return Current::none();
+        };
+        let metadata = self.inner.lock().unwrap().spans[id as usize - 1].metadata;
+        Current::new(Id::from_u64(id), metadata)
+    }
+}
@@ -3,6 +3,7 @@ use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use snowflake_connector_rs::{
     Result, Statement,
     bind::{Binary, BindType, Integer, RawBind, Time, TimestampLtz, TimestampNtz, TimestampTz},
+    decode::Vector,
 };
 
 use super::common;
@@ -383,3 +384,24 @@ async fn test_bind_parameters_timestamp_tz_round_trips_control_and_extreme_offse
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_bind_parameters_vectors_round_trip_through_json_text() -> Result<()> {
+    let session = common::default_session().await?;
+
+    let embedding = vec![0.5_f32, -1.25, 0.125, 42.0];
+    let statement = Statement::new(
+        "SELECT PARSE_JSON(?)::ARRAY::VECTOR(FLOAT, 4) AS f, PARSE_JSON(?)::ARRAY::VECTOR(INT, 3) AS i",
+    )
+    .bind(Vector::from(embedding.clone()))
+    .bind(Vector::from(vec![1, -2, i32::MAX]));
+    let table = session.query(statement).await?.collect_table().await?;
+
+    let (floats, ints) = table
+        .rows::<(Vector<f32>, Vector<i32>)>()?
+        .next()
+        .unwrap()?;
+    assert_eq!(floats.as_slice(), embedding.as_slice());
+    assert_eq!(ints.as_slice(), &[1, -2, i32::MAX]);
+    Ok(())
+}
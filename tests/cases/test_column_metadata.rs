@@ -16,6 +16,7 @@ async fn column_type_metadata_maps_declared_types() -> Result<()> {
         CAST(NULL AS NUMBER(10,2))      AS num_10_2,
         CAST(NULL AS INT)               AS int_col,
         CAST(NULL AS FLOAT)             AS float_col,
+        CAST(NULL AS DECFLOAT)          AS decfloat_col,
         CAST(NULL AS VARCHAR(255))      AS vc_255,
         CAST(NULL AS CHAR(10))          AS char_10,
         CAST(NULL AS BOOLEAN)           AS bool_col,
@@ -57,6 +58,7 @@ async fn column_type_metadata_maps_declared_types() -> Result<()> {
         }
     ));
     assert!(matches!(ty(schema, "FLOAT_COL"), ColumnType::Real));
+    assert!(matches!(ty(schema, "DECFLOAT_COL"), ColumnType::DecFloat));
     assert!(matches!(
         ty(schema, "VC_255"),
         ColumnType::Text {
@@ -99,8 +101,11 @@ async fn column_type_metadata_maps_declared_types() -> Result<()> {
         }
     ));
     assert!(matches!(ty(schema, "VARIANT_COL"), ColumnType::Variant));
-    assert!(matches!(ty(schema, "OBJECT_COL"), ColumnType::Object));
-    assert!(matches!(ty(schema, "ARRAY_COL"), ColumnType::Array));
+    assert!(matches!(
+        ty(schema, "OBJECT_COL"),
+        ColumnType::Object { .. }
+    ));
+    assert!(matches!(ty(schema, "ARRAY_COL"), ColumnType::Array { .. }));
 
     Ok(())
 }
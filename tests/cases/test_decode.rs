@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
 use snowflake_connector_rs::{
-    BinaryValue, CellValue, ColumnType, Result, VectorValue,
+    BinaryValue, CellValue, ColumnType, Result, VectorElementType, VectorValue,
     decode::{Json, Vector},
     error::SchemaError,
 };
@@ -243,8 +243,8 @@ async fn test_decode() -> Result<()> {
         .collect_table()
         .await?;
     let columns = table.schema().columns();
-    assert!(matches!(columns[0].ty(), ColumnType::Object));
-    assert!(matches!(columns[1].ty(), ColumnType::Object));
+    assert!(matches!(columns[0].ty(), ColumnType::Object { .. }));
+    assert!(matches!(columns[1].ty(), ColumnType::Object { .. }));
     let (geog, geom) = table.rows().next().unwrap()?;
     assert_eq!(
         geog,
@@ -347,9 +347,9 @@ async fn test_decode() -> Result<()> {
         other => panic!("expected Decode error, got: {other:?}"),
     }
 
-    // VECTOR: reported as `ColumnType::Vector` with no element-type or dimension metadata; the element type is
-    // recovered from the payload. `Vector<i32>` reads VECTOR(INT); `Vector<f32>` reads VECTOR(FLOAT) and restores
-    // Snowflake's lowercase `inf` / `-inf` / `nan` tokens.
+    // VECTOR: reported as `ColumnType::Vector`, with the element type and dimension when the metadata carries them;
+    // otherwise the element type is recovered from the payload. `Vector<i32>` reads VECTOR(INT); `Vector<f32>` reads
+    // VECTOR(FLOAT) and restores Snowflake's lowercase `inf` / `-inf` / `nan` tokens.
     let table = session
         .query(
             "SELECT
@@ -360,11 +360,28 @@ async fn test_decode() -> Result<()> {
         .await?
         .collect_table()
         .await?;
-    for column in table.schema().columns() {
-        assert_eq!(column.ty(), &ColumnType::Vector);
+    let columns = table.schema().columns();
+    assert_eq!(columns.len(), 3);
+    for (column, expected) in columns.iter().zip([
+        VectorElementType::Int,
+        VectorElementType::Float,
+        VectorElementType::Float,
+    ]) {
+        assert!(
+            matches!(
+                column.ty(),
+                ColumnType::Vector {
+                    element: Some(element),
+                    dimension: Some(3),
+                    ..
+                } if *element == expected
+            ),
+            "unexpected column type: {}",
+            column.ty()
+        );
     }
 
-    // Dynamic path: element type inferred from the payload.
+    // Dynamic path: element type taken from the column metadata.
     let row = table.dynamic_rows()?.next().unwrap()?;
     match row.value("VEC_INT").unwrap() {
         CellValue::Vector(VectorValue::Int(v)) => assert_eq!(v.as_slice(), &[1, 2, 3]),
@@ -387,26 +404,25 @@ async fn test_decode() -> Result<()> {
     assert!(nf[1].is_infinite() && nf[1].is_sign_negative());
     assert!(nf[2].is_nan());
 
-    // Element-type mismatch is a per-cell decode error: the INT column rejects float tokens, the FLOAT column
-    // rejects integer tokens. The first offending column drives the row error.
-    let err = table
-        .rows::<(Vector<f32>, Vector<f32>, Vector<f32>)>()?
-        .next()
-        .unwrap()
-        .expect_err("VECTOR(INT) must not decode as Vector<f32>");
-    assert!(
-        err.as_cell_decode_error().is_some(),
-        "unexpected error: {err:?}"
-    );
-    let err = table
-        .rows::<(Vector<i32>, Vector<i32>, Vector<i32>)>()?
-        .next()
-        .unwrap()
-        .expect_err("VECTOR(FLOAT) must not decode as Vector<i32>");
-    assert!(
-        err.as_cell_decode_error().is_some(),
-        "unexpected error: {err:?}"
-    );
+    // Element-type mismatch fails the plan.
+    for err in [
+        table
+            .rows::<(Vector<f32>, Vector<f32>, Vector<f32>)>()
+            .err()
+            .expect("VECTOR(INT) must not plan as Vector<f32>"),
+        table
+            .rows::<(Vector<i32>, Vector<i32>, Vector<i32>)>()
+            .err()
+            .expect("VECTOR(FLOAT) must not plan as Vector<i32>"),
+    ] {
+        assert!(
+            matches!(
+                err.as_schema_error(),
+                Some(SchemaError::IncompatibleColumnType(_))
+            ),
+            "unexpected error: {err:?}"
+        );
+    }
 
     // SQL NULL vector decodes to `None`.
     let table = session
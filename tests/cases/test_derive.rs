@@ -1,6 +1,8 @@
 use chrono::NaiveDateTime;
 
-use snowflake_connector_rs::{DecimalValue, FromRow, Result, error::SchemaError};
+use snowflake_connector_rs::{
+    DecimalValue, FromCell, FromRow, IntoBinds, Result, Statement, error::SchemaError,
+};
 
 use super::common;
 
@@ -72,6 +74,54 @@ struct NamedByPosition {
     label: String,
 }
 
+#[derive(Debug, FromRow, PartialEq)]
+struct AuditColumns {
+    created_by: String,
+}
+
+#[derive(Debug, FromRow, PartialEq)]
+struct AttributedRow {
+    #[snowflake(try_from = "i64")]
+    id: u32,
+    #[snowflake(flatten)]
+    audit: AuditColumns,
+    #[snowflake(default)]
+    nickname: String,
+    #[snowflake(default)]
+    region: String,
+    #[snowflake(skip)]
+    cached: Vec<u8>,
+}
+
+#[derive(Debug, Clone, FromRow, IntoBinds, PartialEq)]
+#[snowflake(rename_all = "none")]
+struct NoteRow {
+    id: i64,
+    note: Option<String>,
+}
+
+#[derive(Debug, FromRow, PartialEq)]
+#[snowflake(lookup = "identifier", rename_all = "none")]
+struct IdentifierLookupRow {
+    #[snowflake(rename = "\"order_id\"")]
+    order_id: i64,
+    customer_id: i64,
+}
+
+#[derive(Debug, FromCell, PartialEq)]
+#[snowflake(case_insensitive)]
+enum OrderStatus {
+    Open = 1,
+    InReview = 2,
+}
+
+#[derive(Debug, FromRow, PartialEq)]
+struct OrderRow {
+    status: OrderStatus,
+    status_code: OrderStatus,
+    previous: Option<OrderStatus>,
+}
+
 #[tokio::test]
 async fn derive_named_lookup_variants_decode_expected_rows() -> Result<()> {
     let session = common::fresh_session().await?;
@@ -157,6 +207,20 @@ async fn derive_named_lookup_variants_decode_expected_rows() -> Result<()> {
 
     assert_eq!(renamed, vec![QuotedAliasRow { v: 1 }]);
 
+    let identifiers = session
+        .query_as(r#"SELECT 7 AS "order_id", 8 AS customer_id"#)
+        .await?
+        .collect::<Vec<IdentifierLookupRow>>()
+        .await?;
+
+    assert_eq!(
+        identifiers,
+        vec![IdentifierLookupRow {
+            order_id: 7,
+            customer_id: 8,
+        }]
+    );
+
     Ok(())
 }
 
@@ -277,3 +341,67 @@ async fn derive_nondefault_lookup_modes_cover_metadata_and_named_position_paths(
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn derive_into_binds_round_trips_through_from_row() -> Result<()> {
+    let session = common::fresh_session().await?;
+
+    let row = NoteRow {
+        id: 3,
+        note: Some("bound".to_string()),
+    };
+    let rows = session
+        .query_as(Statement::new(r#"SELECT :id AS "id", :note AS "note""#).bind_struct(&row))
+        .await?
+        .collect::<Vec<NoteRow>>()
+        .await?;
+
+    assert_eq!(rows, vec![row]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn derive_field_attributes_fill_defaults_and_flatten() -> Result<()> {
+    let session = common::fresh_session().await?;
+
+    let rows = session
+        .query_as("SELECT 5 AS id, 'admin' AS created_by, NULL::STRING AS nickname")
+        .await?
+        .collect::<Vec<AttributedRow>>()
+        .await?;
+
+    assert_eq!(
+        rows,
+        vec![AttributedRow {
+            id: 5,
+            audit: AuditColumns {
+                created_by: "admin".to_string(),
+            },
+            nickname: String::new(),
+            region: String::new(),
+            cached: Vec::new(),
+        }]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn derive_from_cell_enum_decodes_text_and_discriminants() -> Result<()> {
+    let session = common::fresh_session().await?;
+
+    let rows = session
+        .query_as("SELECT 'in_review' AS status, 1 AS status_code, NULL::STRING AS previous")
+        .await?
+        .collect::<Vec<OrderRow>>()
+        .await?;
+
+    assert_eq!(
+        rows,
+        vec![OrderRow {
+            status: OrderStatus::InReview,
+            status_code: OrderStatus::Open,
+            previous: None,
+        }]
+    );
+    Ok(())
+}
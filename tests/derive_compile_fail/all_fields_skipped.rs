@@ -1,8 +1,9 @@
 use snowflake_connector_rs::FromRow;
 
 #[derive(FromRow)]
-struct Bad<'a> {
-    value: &'a str,
+struct Bad {
+    #[snowflake(skip)]
+    x: i64,
 }
 
 fn main() {}
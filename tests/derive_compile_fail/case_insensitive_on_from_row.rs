@@ -0,0 +1,9 @@
+use snowflake_connector_rs::FromRow;
+
+#[derive(FromRow)]
+#[snowflake(case_insensitive)]
+struct Bad {
+    value: String,
+}
+
+fn main() {}
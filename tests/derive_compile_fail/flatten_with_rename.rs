@@ -0,0 +1,14 @@
+use snowflake_connector_rs::FromRow;
+
+#[derive(FromRow)]
+struct Inner {
+    id: i64,
+}
+
+#[derive(FromRow)]
+struct Bad {
+    #[snowflake(flatten, rename = "INNER")]
+    inner: Inner,
+}
+
+fn main() {}
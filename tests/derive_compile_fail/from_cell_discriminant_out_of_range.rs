@@ -0,0 +1,10 @@
+use snowflake_connector_rs::FromCell;
+
+#[derive(FromCell)]
+#[repr(u64)]
+enum Bad {
+    Small = 1,
+    Huge = u64::MAX,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use snowflake_connector_rs::FromCell;
+
+#[derive(FromCell)]
+#[snowflake(case_insensitive, rename_all = "none")]
+enum Bad {
+    Open,
+    #[snowflake(rename = "OPEN")]
+    Reopened,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use snowflake_connector_rs::FromCell;
+
+#[derive(FromCell)]
+struct Bad {
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use snowflake_connector_rs::FromCell;
+
+#[derive(FromCell)]
+enum Bad {
+    Open,
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use snowflake_connector_rs::IntoBinds;
+
+#[derive(IntoBinds)]
+enum Bad {
+    A,
+}
+
+fn main() {}
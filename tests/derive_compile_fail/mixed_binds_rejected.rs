@@ -0,0 +1,10 @@
+use snowflake_connector_rs::{IntoBinds, Statement};
+
+#[derive(IntoBinds)]
+struct Named {
+    id: i64,
+}
+
+fn main() {
+    let _ = Statement::new("SELECT ?, :id").bind(1_i64).bind_struct(&Named { id: 2 });
+}
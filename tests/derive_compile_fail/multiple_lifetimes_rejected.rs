@@ -0,0 +1,9 @@
+use snowflake_connector_rs::FromRow;
+
+#[derive(FromRow)]
+struct Bad<'a, 'b> {
+    name: &'a str,
+    note: &'b str,
+}
+
+fn main() {}
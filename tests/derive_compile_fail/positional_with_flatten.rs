@@ -0,0 +1,16 @@
+use snowflake_connector_rs::FromRow;
+
+#[derive(FromRow)]
+struct Inner {
+    id: i64,
+}
+
+#[derive(FromRow)]
+#[snowflake(positional)]
+struct Bad {
+    name: String,
+    #[snowflake(flatten)]
+    inner: Inner,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use snowflake_connector_rs::FromRow;
+
+#[derive(FromRow)]
+#[snowflake(positional, lookup = "identifier")]
+struct Bad {
+    id: i64,
+}
+
+fn main() {}
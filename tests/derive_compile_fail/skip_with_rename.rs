@@ -0,0 +1,9 @@
+use snowflake_connector_rs::IntoBinds;
+
+#[derive(IntoBinds)]
+struct Bad {
+    #[snowflake(skip, rename = "A")]
+    x: i64,
+}
+
+fn main() {}
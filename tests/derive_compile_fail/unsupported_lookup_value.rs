@@ -0,0 +1,9 @@
+use snowflake_connector_rs::FromRow;
+
+#[derive(FromRow)]
+#[snowflake(lookup = "fuzzy")]
+struct Bad {
+    id: i64,
+}
+
+fn main() {}
@@ -2,6 +2,7 @@ use snowflake_connector_rs::FromRow;
 
 #[derive(FromRow)]
 struct Bad<T> {
+    #[snowflake(try_from = "i64")]
     value: T,
 }
 
@@ -0,0 +1,36 @@
+use snowflake_connector_rs::{FromRow, FromRowRef, ResultTable};
+
+#[derive(FromRow)]
+pub struct Audit {
+    pub created_by: String,
+}
+
+#[derive(FromRow)]
+pub struct Named<'a> {
+    pub id: i64,
+    pub name: &'a str,
+    pub nickname: Option<&'a str>,
+    #[snowflake(default)]
+    pub note: &'a str,
+    #[snowflake(flatten)]
+    pub audit: Audit,
+}
+
+#[derive(FromRow)]
+pub struct Pair<'a, T>(pub &'a str, pub T);
+
+fn assert_from_row_ref<'a, T: FromRowRef<'a>>() {}
+
+fn names(table: &ResultTable) -> Vec<&str> {
+    table
+        .rows_ref::<Named<'_>>()
+        .unwrap()
+        .map(|row| row.unwrap().name)
+        .collect()
+}
+
+fn main() {
+    assert_from_row_ref::<Named<'_>>();
+    assert_from_row_ref::<Pair<'_, i64>>();
+    let _ = names;
+}
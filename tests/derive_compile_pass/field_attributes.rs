@@ -0,0 +1,53 @@
+use snowflake_connector_rs::{
+    CellPlanContext, FromRow,
+    decode::{CellDecodeResult, PlanBuildResult},
+};
+
+mod trimmed {
+    use super::*;
+
+    pub type Plan = ();
+
+    pub fn build_plan(_ctx: CellPlanContext<'_>) -> PlanBuildResult<Plan> {
+        Ok(())
+    }
+
+    pub fn from_cell_with_plan(raw: Option<&str>, _plan: &Plan) -> CellDecodeResult<String> {
+        Ok(raw.unwrap_or_default().trim().to_string())
+    }
+}
+
+pub struct UserId(i64);
+
+impl TryFrom<i64> for UserId {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        u32::try_from(value).map(|_| UserId(value))
+    }
+}
+
+#[derive(FromRow)]
+pub struct Audit {
+    pub created_by: String,
+}
+
+#[derive(FromRow)]
+pub struct User {
+    #[snowflake(try_from = "i64")]
+    pub id: UserId,
+    #[snowflake(with = "trimmed")]
+    pub name: String,
+    #[snowflake(flatten)]
+    pub audit: Audit,
+    #[snowflake(default, rename = "NICK")]
+    pub nickname: String,
+    #[snowflake(skip)]
+    pub marker: std::marker::PhantomData<u8>,
+}
+
+fn assert_from_row<T: FromRow>() {}
+
+fn main() {
+    assert_from_row::<User>();
+}
@@ -0,0 +1,35 @@
+use snowflake_connector_rs::{FromCell, FromRow};
+
+#[derive(FromCell)]
+#[snowflake(case_insensitive)]
+pub enum OrderStatus {
+    Open,
+    InReview,
+    #[snowflake(rename = "done")]
+    Closed = 9,
+}
+
+#[derive(FromCell)]
+#[repr(u8)]
+#[snowflake(rename_all = "none")]
+pub enum Tier {
+    Gold = 1,
+    Silver = 2,
+}
+
+#[derive(FromRow)]
+pub struct OrderRow {
+    pub id: i64,
+    pub status: OrderStatus,
+    pub tier: Option<Tier>,
+}
+
+fn assert_from_cell<T: FromCell>() {}
+
+fn assert_from_row<T: FromRow>() {}
+
+fn main() {
+    assert_from_cell::<OrderStatus>();
+    assert_from_cell::<Tier>();
+    assert_from_row::<OrderRow>();
+}
@@ -0,0 +1,37 @@
+use snowflake_connector_rs::{FromCell, FromRow, FromRowRef};
+
+#[derive(FromRow)]
+pub struct Page<T> {
+    pub total: i64,
+    #[snowflake(flatten)]
+    pub item: T,
+}
+
+#[derive(FromRow)]
+pub struct Tagged<V: Clone, const N: usize> {
+    pub value: V,
+    pub values: Option<V>,
+    #[snowflake(default)]
+    pub fallback: V,
+    #[snowflake(skip)]
+    pub marker: [u8; N],
+}
+
+#[derive(FromRow)]
+pub struct Item {
+    pub name: String,
+}
+
+fn assert_from_row<T: FromRow>() {}
+
+fn assert_from_row_ref<'a, T: FromRowRef<'a>>() {}
+
+fn assert_generic<V: FromCell + Clone + Default>() {
+    assert_from_row::<Tagged<V, 4>>();
+}
+
+fn main() {
+    assert_from_row::<Page<Item>>();
+    assert_from_row_ref::<Page<Item>>();
+    assert_generic::<i64>();
+}
@@ -0,0 +1,36 @@
+use snowflake_connector_rs::{IntoBinds, IntoStatement, Statement};
+
+#[derive(IntoBinds)]
+pub struct NewUser {
+    pub id: i64,
+    #[snowflake(rename = "user_name")]
+    pub name: String,
+    #[snowflake(skip)]
+    pub cached: Vec<u8>,
+}
+
+#[derive(IntoBinds)]
+#[snowflake(positional)]
+pub struct PositionalUser {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+#[derive(IntoBinds)]
+pub struct Pair(pub i64, pub bool);
+
+fn assert_into_statement<S: IntoStatement>(_: S) {}
+
+fn main() {
+    let user = NewUser {
+        id: 1,
+        name: "alice".to_string(),
+        cached: Vec::new(),
+    };
+    assert_into_statement(Statement::new("SELECT :id, :user_name").bind_struct(&user));
+    assert_into_statement(
+        Statement::new("SELECT ?, ?, ?, ?")
+            .bind_struct(&PositionalUser { id: 1, name: None })
+            .bind_struct(&Pair(2, true)),
+    );
+}
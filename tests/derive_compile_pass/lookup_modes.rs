@@ -0,0 +1,25 @@
+use snowflake_connector_rs::FromRow;
+
+#[derive(FromRow)]
+#[snowflake(lookup = "case_insensitive")]
+pub struct Loose {
+    pub id: i64,
+}
+
+#[derive(FromRow)]
+#[snowflake(lookup = "identifier", rename_all = "none")]
+pub struct Dbt<'a> {
+    #[snowflake(rename = "\"order_id\"")]
+    pub order_id: i64,
+    pub état: &'a str,
+    #[snowflake(flatten)]
+    pub loose: Loose,
+}
+
+#[derive(FromRow)]
+#[snowflake(lookup = "exact")]
+pub struct Exact {
+    pub id: i64,
+}
+
+fn main() {}